@@ -0,0 +1,153 @@
+//! Human-readable output for Paladin Governance accounts.
+
+use {
+    paladin_governance_client::state::{Config, Proposal, ProposalTransaction, ProposalVote},
+    solana_sdk::pubkey::Pubkey,
+    std::num::NonZeroU64,
+};
+
+// Thresholds are stored with a scaling factor of `1e9`.
+fn threshold(value: u32) -> String {
+    format!("{}%", value as f64 / 10_000_000.0)
+}
+
+fn timestamp(value: Option<NonZeroU64>) -> String {
+    value.map_or("-".to_string(), |value| value.to_string())
+}
+
+/// Print a governance config.
+pub fn print_governance_config(address: &Pubkey, config: &Config) {
+    println!("Governance config: {}", address);
+    println!("  Stake config: {}", config.stake_config_address);
+    println!("  Proposal count: {}", config.proposal_count);
+    println!(
+        "  Proposal minimum stake: {}",
+        config.proposal_minimum_stake
+    );
+    println!(
+        "  Acceptance threshold: {}",
+        threshold(config.proposal_acceptance_threshold)
+    );
+    println!(
+        "  Rejection threshold: {}",
+        threshold(config.proposal_rejection_threshold)
+    );
+    println!("  Quorum threshold: {}", threshold(config.quorum_threshold));
+    println!("  Voting period: {}s", config.voting_period_seconds);
+    println!("  Cooldown period: {}s", config.cooldown_period_seconds);
+    println!("  Execution window: {}s", config.execution_window_seconds);
+    let bounds = &config.period_bounds;
+    println!(
+        "  Voting period bounds: {}s-{}s",
+        bounds.min_voting_period_seconds, bounds.max_voting_period_seconds
+    );
+    println!(
+        "  Cooldown period bounds: {}s-{}s",
+        bounds.min_cooldown_period_seconds, bounds.max_cooldown_period_seconds
+    );
+    println!(
+        "  Execution window bounds: {}s-{}s",
+        bounds.min_execution_window_seconds, bounds.max_execution_window_seconds
+    );
+    println!(
+        "  Veto authority: {}",
+        Option::<Pubkey>::from(config.veto_authority)
+            .map_or("-".to_string(), |authority| authority.to_string())
+    );
+}
+
+/// Print a one-line summary of a proposal, as listed.
+pub fn print_proposal_summary(index: u64, address: &Pubkey, proposal: &Proposal) {
+    println!(
+        "{:>5}  {}  {:<12}  {}",
+        index,
+        address,
+        format!("{:?}", proposal.status),
+        proposal.title_str()
+    );
+}
+
+/// Print a proposal.
+pub fn print_proposal(address: &Pubkey, proposal: &Proposal) {
+    println!("Proposal: {}", address);
+    println!("  Title: {}", proposal.title_str());
+    println!("  URI: {}", proposal.uri_str());
+    println!(
+        "  Content hash: {}",
+        proposal
+            .content_hash
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>()
+    );
+    println!("  Author: {}", proposal.author);
+    println!("  Status: {:?}", proposal.status);
+    println!("  Created: {}", proposal.creation_timestamp);
+    println!(
+        "  Voting started: {}",
+        timestamp(proposal.voting_start_timestamp)
+    );
+    println!(
+        "  Cooldown started: {}",
+        timestamp(proposal.cooldown_timestamp)
+    );
+    println!("  Executed: {}", timestamp(proposal.execution_timestamp));
+    println!("  Total stake: {}", proposal.total_stake);
+    println!("  Stake for: {}", proposal.stake_for);
+    println!("  Stake against: {}", proposal.stake_against);
+    println!("  Stake abstained: {}", proposal.stake_abstained);
+}
+
+/// Print a proposal's transaction.
+pub fn print_proposal_transaction(address: &Pubkey, proposal_transaction: &ProposalTransaction) {
+    println!("Proposal transaction: {}", address);
+    if proposal_transaction.instructions.is_empty() {
+        println!("  No instructions");
+    }
+    for (index, instruction) in proposal_transaction.instructions.iter().enumerate() {
+        println!(
+            "  Instruction {}{}",
+            index,
+            if instruction.executed {
+                " (executed)"
+            } else {
+                ""
+            }
+        );
+        println!("    Program: {}", instruction.program_id);
+        for meta in &instruction.accounts {
+            println!(
+                "    Account: {} {}{}",
+                meta.pubkey,
+                if meta.is_writable { "w" } else { "-" },
+                if meta.is_signer { "s" } else { "-" },
+            );
+        }
+        println!(
+            "    Data: {}",
+            instruction
+                .data
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect::<String>()
+        );
+    }
+    if let Some(instruction_slot) = &proposal_transaction.instruction_slot {
+        println!(
+            "  Instruction slot: {}/{} bytes written",
+            instruction_slot.data.len(),
+            instruction_slot.instruction_len
+        );
+    }
+}
+
+/// Print a proposal vote.
+pub fn print_proposal_vote(address: &Pubkey, proposal_vote: &ProposalVote) {
+    println!("Proposal vote: {}", address);
+    println!("  Proposal: {}", proposal_vote.proposal_address);
+    println!("  Stake account: {}", proposal_vote.stake_address);
+    println!("  Authority: {}", proposal_vote.authority_address);
+    println!("  Election: {:?}", proposal_vote.election);
+    println!("  Stake: {}", proposal_vote.stake);
+    println!("  Voted: {}", timestamp(proposal_vote.vote_timestamp));
+}
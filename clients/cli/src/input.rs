@@ -0,0 +1,185 @@
+//! Parsers for command-line arguments.
+
+use {
+    base64::{prelude::BASE64_STANDARD, Engine},
+    paladin_governance_client::state::ProposalVoteElection,
+    serde::Deserialize,
+    solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+    },
+    std::str::FromStr,
+};
+
+/// An instruction account, as accepted in JSON form.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonAccountMeta {
+    pubkey: String,
+    is_signer: bool,
+    is_writable: bool,
+}
+
+/// An instruction, as accepted in JSON form. Instruction data is base64
+/// encoded.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonInstruction {
+    program_id: String,
+    accounts: Vec<JsonAccountMeta>,
+    data: String,
+}
+
+fn parse_pubkey(value: &str) -> Result<Pubkey, String> {
+    Pubkey::from_str(value).map_err(|err| format!("invalid pubkey `{}`: {}", value, err))
+}
+
+/// Parse an instruction, given either as a JSON object or as the base64
+/// encoding of a bincode-serialized instruction.
+///
+/// JSON instructions take the form:
+///
+/// ```json
+/// {
+///   "programId": "<pubkey>",
+///   "accounts": [{ "pubkey": "<pubkey>", "isSigner": false, "isWritable": true }],
+///   "data": "<base64>"
+/// }
+/// ```
+pub fn parse_instruction(value: &str) -> Result<Instruction, String> {
+    let value = value.trim();
+    if value.starts_with('{') {
+        let json: JsonInstruction = serde_json::from_str(value)
+            .map_err(|err| format!("invalid JSON instruction: {}", err))?;
+        let accounts = json
+            .accounts
+            .iter()
+            .map(|meta| {
+                Ok(AccountMeta {
+                    pubkey: parse_pubkey(&meta.pubkey)?,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let data = BASE64_STANDARD
+            .decode(&json.data)
+            .map_err(|err| format!("invalid instruction data: {}", err))?;
+        Ok(Instruction {
+            program_id: parse_pubkey(&json.program_id)?,
+            accounts,
+            data,
+        })
+    } else {
+        let bytes = BASE64_STANDARD
+            .decode(value)
+            .map_err(|err| format!("invalid base64 instruction: {}", err))?;
+        bincode::deserialize(&bytes).map_err(|err| format!("invalid instruction: {}", err))
+    }
+}
+
+/// Parse a 32-byte content hash, given as hex.
+pub fn parse_content_hash(value: &str) -> Result<[u8; 32], String> {
+    let value = value.trim_start_matches("0x");
+    if value.len() != 64 || !value.is_ascii() {
+        return Err("content hash must be 32 bytes of hex".to_string());
+    }
+    let mut content_hash = [0; 32];
+    for (i, byte) in content_hash.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&value[i * 2..i * 2 + 2], 16)
+            .map_err(|_| format!("invalid hex in content hash `{}`", value))?;
+    }
+    Ok(content_hash)
+}
+
+/// Parse a vote election, `for`, `against` or `did-not-vote`.
+pub fn parse_election(value: &str) -> Result<ProposalVoteElection, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "for" => Ok(ProposalVoteElection::For),
+        "against" => Ok(ProposalVoteElection::Against),
+        "did-not-vote" => Ok(ProposalVoteElection::DidNotVote),
+        _ => Err(format!(
+            "invalid election `{}`, expected `for`, `against` or `did-not-vote`",
+            value
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_instruction_json() {
+        let program_id = Pubkey::new_unique();
+        let writable = Pubkey::new_unique();
+        let signer = Pubkey::new_unique();
+
+        let json = format!(
+            r#"{{
+                "programId": "{}",
+                "accounts": [
+                    {{ "pubkey": "{}", "isSigner": false, "isWritable": true }},
+                    {{ "pubkey": "{}", "isSigner": true, "isWritable": false }}
+                ],
+                "data": "AQID"
+            }}"#,
+            program_id, writable, signer,
+        );
+
+        assert_eq!(
+            parse_instruction(&json).unwrap(),
+            Instruction::new_with_bytes(
+                program_id,
+                &[1, 2, 3],
+                vec![
+                    AccountMeta::new(writable, false),
+                    AccountMeta::new_readonly(signer, true),
+                ],
+            ),
+        );
+    }
+
+    #[test]
+    fn test_parse_instruction_base64() {
+        let instruction = Instruction::new_with_bytes(
+            Pubkey::new_unique(),
+            &[4, 5, 6],
+            vec![
+                AccountMeta::new(Pubkey::new_unique(), true),
+                AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            ],
+        );
+        let encoded = BASE64_STANDARD.encode(bincode::serialize(&instruction).unwrap());
+
+        assert_eq!(parse_instruction(&encoded).unwrap(), instruction);
+    }
+
+    #[test]
+    fn test_parse_instruction_invalid() {
+        assert!(parse_instruction("not base64!").is_err());
+        assert!(parse_instruction(r#"{ "programId": "invalid" }"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_content_hash() {
+        let hex = "0x".to_string() + &"ab".repeat(32);
+        assert_eq!(parse_content_hash(&hex).unwrap(), [0xab; 32]);
+        assert!(parse_content_hash("abcd").is_err());
+        assert!(parse_content_hash(&"zz".repeat(32)).is_err());
+    }
+
+    #[test]
+    fn test_parse_election() {
+        assert_eq!(parse_election("for").unwrap(), ProposalVoteElection::For);
+        assert_eq!(
+            parse_election("Against").unwrap(),
+            ProposalVoteElection::Against
+        );
+        assert_eq!(
+            parse_election("did-not-vote").unwrap(),
+            ProposalVoteElection::DidNotVote
+        );
+        assert!(parse_election("abstain").is_err());
+    }
+}
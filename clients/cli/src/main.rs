@@ -0,0 +1,1228 @@
+//! Paladin Governance command-line tool.
+
+mod display;
+mod input;
+
+use {
+    clap::{Parser, Subcommand},
+    paladin_governance_client::{
+        accounts::{
+            fetch_account, fetch_governance_config, fetch_proposal, fetch_proposal_transaction,
+            fetch_proposal_vote, fetch_vote_delegation,
+        },
+        error::PaladinGovernanceClientError,
+        instruction::{
+            close_proposal_transaction, deposit_to_treasury, deposit_tokens_to_treasury,
+            initialize_governance, initialize_treasury, initialize_treasury_token_account,
+            migrate_account, migrate_proposal_transaction_account, migrate_proposal_vote_account,
+            migrate_voting_proposal_account, remove_instruction, revoke_vote_delegation,
+            set_vote_delegation, sync_vote_weight, transfer_from_treasury,
+            transfer_tokens_from_treasury, veto_proposal,
+        },
+        pda::{
+            get_governance_address, get_proposal_address, get_proposal_transaction_address,
+            get_proposal_vote_address, get_treasury_address, get_vote_delegation_address,
+        },
+        state::{
+            Config, PeriodBounds, ProposalVoteElection, DEFAULT_MAX_COOLDOWN_PERIOD_SECONDS,
+            DEFAULT_MAX_EXECUTION_WINDOW_SECONDS, DEFAULT_MAX_VOTING_PERIOD_SECONDS,
+            DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS, DEFAULT_MIN_EXECUTION_WINDOW_SECONDS,
+            DEFAULT_MIN_VOTING_PERIOD_SECONDS,
+        },
+        transactions::{
+            begin_voting_transaction, cancel_proposal_transaction, close_proposal_instructions,
+            close_proposal_vote_transaction, create_proposal_transaction,
+            finalize_proposal_transaction, process_instruction_transaction,
+            push_instruction_transaction, switch_vote_transaction, vote_transaction,
+            withdraw_vote_transaction, write_proposal_instruction_instructions,
+        },
+    },
+    solana_client::nonblocking::rpc_client::RpcClient,
+    solana_sdk::{
+        commitment_config::CommitmentConfig,
+        instruction::Instruction,
+        pubkey::Pubkey,
+        signature::{read_keypair_file, Keypair, Signature},
+        signer::Signer,
+        system_instruction,
+        transaction::Transaction,
+    },
+    std::{error::Error, num::NonZeroU64},
+};
+
+type CommandResult = Result<(), Box<dyn Error>>;
+
+#[derive(Parser)]
+#[command(name = "paladin-governance", version, about)]
+struct Cli {
+    /// URL of the Solana JSON RPC endpoint.
+    #[arg(
+        long,
+        short = 'u',
+        global = true,
+        default_value = "http://127.0.0.1:8899"
+    )]
+    url: String,
+    /// Path to the keypair paying for transactions. Defaults to the Solana CLI
+    /// default keypair.
+    #[arg(long, short = 'k', global = true)]
+    keypair: Option<String>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Initialize the governance config for a Paladin stake config.
+    InitGovernance {
+        /// The Paladin stake config address.
+        #[arg(long)]
+        stake_config: Pubkey,
+        /// Keypair of the stake config authority or the program's upgrade
+        /// authority. Defaults to the payer.
+        #[arg(long)]
+        authority: Option<String>,
+        /// Cooldown period, in seconds.
+        #[arg(long)]
+        cooldown_period: u64,
+        /// Execution window, in seconds.
+        #[arg(long)]
+        execution_window: u64,
+        /// Proposal acceptance threshold, scaled by `1e9`.
+        #[arg(long)]
+        acceptance_threshold: u32,
+        /// Minimum stake required to create a proposal.
+        #[arg(long)]
+        minimum_stake: u64,
+        /// Proposal rejection threshold, scaled by `1e9`.
+        #[arg(long)]
+        rejection_threshold: u32,
+        /// Quorum threshold, scaled by `1e9`. Zero disables the quorum.
+        #[arg(long, default_value_t = 0)]
+        quorum_threshold: u32,
+        /// Voting period, in seconds.
+        #[arg(long)]
+        voting_period: u64,
+        /// Authority permitted to veto proposals.
+        #[arg(long)]
+        veto_authority: Option<Pubkey>,
+        /// Minimum cooldown period the governance config may set, in seconds.
+        #[arg(long, default_value_t = DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS)]
+        min_cooldown_period: u64,
+        /// Maximum cooldown period the governance config may set, in seconds.
+        #[arg(long, default_value_t = DEFAULT_MAX_COOLDOWN_PERIOD_SECONDS)]
+        max_cooldown_period: u64,
+        /// Minimum execution window the governance config may set, in
+        /// seconds.
+        #[arg(long, default_value_t = DEFAULT_MIN_EXECUTION_WINDOW_SECONDS)]
+        min_execution_window: u64,
+        /// Maximum execution window the governance config may set, in
+        /// seconds.
+        #[arg(long, default_value_t = DEFAULT_MAX_EXECUTION_WINDOW_SECONDS)]
+        max_execution_window: u64,
+        /// Minimum voting period the governance config may set, in seconds.
+        #[arg(long, default_value_t = DEFAULT_MIN_VOTING_PERIOD_SECONDS)]
+        min_voting_period: u64,
+        /// Maximum voting period the governance config may set, in seconds.
+        #[arg(long, default_value_t = DEFAULT_MAX_VOTING_PERIOD_SECONDS)]
+        max_voting_period: u64,
+    },
+    /// Create a proposal.
+    Create {
+        /// The Paladin stake config address.
+        #[arg(long)]
+        stake_config: Pubkey,
+        /// The author's Paladin stake account.
+        #[arg(long)]
+        stake: Pubkey,
+        /// Keypair of the stake account's authority. Defaults to the payer.
+        #[arg(long)]
+        stake_authority: Option<String>,
+        /// The proposal title.
+        #[arg(long)]
+        title: String,
+        /// URI of the off-chain proposal description document.
+        #[arg(long)]
+        uri: String,
+        /// Hex-encoded hash of the off-chain proposal description document.
+        #[arg(long, value_parser = input::parse_content_hash)]
+        content_hash: [u8; 32],
+    },
+    /// Push an instruction onto a proposal's transaction.
+    PushInstruction {
+        /// The proposal address.
+        #[arg(long)]
+        proposal: Pubkey,
+        /// Keypair of the proposal author's stake authority. Defaults to the
+        /// payer.
+        #[arg(long)]
+        stake_authority: Option<String>,
+        /// The instruction, as JSON or as base64-encoded bincode.
+        #[arg(long, value_parser = input::parse_instruction)]
+        instruction: Instruction,
+        /// Write the instruction through the proposal's instruction slot, in
+        /// chunks of at most this many bytes, for instructions too large to
+        /// push in a single transaction.
+        #[arg(long)]
+        chunk_size: Option<usize>,
+    },
+    /// Remove an instruction from a proposal's transaction.
+    Remove {
+        /// The proposal address.
+        #[arg(long)]
+        proposal: Pubkey,
+        /// Keypair of the proposal author's stake authority. Defaults to the
+        /// payer.
+        #[arg(long)]
+        stake_authority: Option<String>,
+        /// Index of the instruction to remove.
+        #[arg(long)]
+        index: u32,
+    },
+    /// Begin voting on a proposal.
+    BeginVoting {
+        /// The proposal address.
+        #[arg(long)]
+        proposal: Pubkey,
+        /// The author's Paladin stake account.
+        #[arg(long)]
+        stake: Pubkey,
+        /// Keypair of the stake account's authority. Defaults to the payer.
+        #[arg(long)]
+        stake_authority: Option<String>,
+    },
+    /// Vote on a proposal.
+    Vote {
+        /// The proposal address.
+        #[arg(long)]
+        proposal: Pubkey,
+        /// The voting Paladin stake account.
+        #[arg(long)]
+        stake: Pubkey,
+        /// Keypair of the stake account's authority, or its vote delegate
+        /// with `--delegate`. Defaults to the payer.
+        #[arg(long)]
+        voter: Option<String>,
+        /// Vote as the stake account's vote delegate.
+        #[arg(long)]
+        delegate: bool,
+        /// The election, `for`, `against` or `did-not-vote`.
+        #[arg(long, value_parser = input::parse_election)]
+        election: ProposalVoteElection,
+    },
+    /// Switch an existing vote on a proposal.
+    SwitchVote {
+        /// The proposal address.
+        #[arg(long)]
+        proposal: Pubkey,
+        /// The voting Paladin stake account.
+        #[arg(long)]
+        stake: Pubkey,
+        /// Keypair of the stake account's authority, or its vote delegate
+        /// with `--delegate`. Defaults to the payer.
+        #[arg(long)]
+        voter: Option<String>,
+        /// Vote as the stake account's vote delegate.
+        #[arg(long)]
+        delegate: bool,
+        /// The new election, `for`, `against` or `did-not-vote`.
+        #[arg(long, value_parser = input::parse_election)]
+        election: ProposalVoteElection,
+    },
+    /// Execute an instruction of an accepted proposal.
+    Process {
+        /// The proposal address.
+        #[arg(long)]
+        proposal: Pubkey,
+        /// Index of the instruction to execute.
+        #[arg(long)]
+        index: u32,
+    },
+    /// Finalize a proposal once its voting period has ended.
+    Finalize {
+        /// The proposal address.
+        #[arg(long)]
+        proposal: Pubkey,
+    },
+    /// Cancel a proposal.
+    Cancel {
+        /// The proposal address.
+        #[arg(long)]
+        proposal: Pubkey,
+        /// Keypair of the proposal author's stake authority. Defaults to the
+        /// payer.
+        #[arg(long)]
+        stake_authority: Option<String>,
+    },
+    /// Withdraw a vote on a proposal, returning its rent to the payer that
+    /// funded it.
+    WithdrawVote {
+        /// The proposal address.
+        #[arg(long)]
+        proposal: Pubkey,
+        /// The voting Paladin stake account.
+        #[arg(long)]
+        stake: Pubkey,
+        /// Keypair of the stake account's authority, or its vote delegate
+        /// with `--delegate`. Defaults to the payer.
+        #[arg(long)]
+        voter: Option<String>,
+        /// Withdraw as the stake account's vote delegate.
+        #[arg(long)]
+        delegate: bool,
+    },
+    /// Sync a vote's weight with its stake account's current stake.
+    SyncVoteWeight {
+        /// The proposal address.
+        #[arg(long)]
+        proposal: Pubkey,
+        /// The voting Paladin stake account.
+        #[arg(long)]
+        stake: Pubkey,
+    },
+    /// Veto a proposal, as the governance config's veto authority.
+    Veto {
+        /// The proposal address.
+        #[arg(long)]
+        proposal: Pubkey,
+        /// Keypair of the veto authority. Defaults to the payer.
+        #[arg(long)]
+        veto_authority: Option<String>,
+    },
+    /// Close a terminated proposal, closing its transaction account first if
+    /// it is still open.
+    CloseProposal {
+        /// The proposal address.
+        #[arg(long)]
+        proposal: Pubkey,
+        /// Keypair of the proposal author's stake authority. Defaults to the
+        /// payer.
+        #[arg(long)]
+        stake_authority: Option<String>,
+    },
+    /// Close a terminated proposal's transaction account.
+    CloseTransaction {
+        /// The proposal address.
+        #[arg(long)]
+        proposal: Pubkey,
+        /// Keypair of the proposal author's stake authority. Defaults to the
+        /// payer.
+        #[arg(long)]
+        stake_authority: Option<String>,
+    },
+    /// Close a stake account's vote on a terminated proposal, returning its
+    /// rent to the payer that funded it.
+    CloseVote {
+        /// The proposal address.
+        #[arg(long)]
+        proposal: Pubkey,
+        /// The voting Paladin stake account.
+        #[arg(long)]
+        stake: Pubkey,
+        /// Keypair of the vote's payer or voting authority, or of the stake
+        /// account's authority for a vote not yet migrated. Defaults to the
+        /// payer.
+        #[arg(long)]
+        authority: Option<String>,
+    },
+    /// Delegate a stake account's votes under a governance config.
+    SetDelegation {
+        /// The Paladin stake config address.
+        #[arg(long)]
+        stake_config: Pubkey,
+        /// The delegating Paladin stake account.
+        #[arg(long)]
+        stake: Pubkey,
+        /// Keypair of the stake account's authority. Defaults to the payer.
+        #[arg(long)]
+        stake_authority: Option<String>,
+        /// The vote delegate.
+        #[arg(long)]
+        delegate: Pubkey,
+        /// Unix timestamp at which the delegation expires. Defaults to no
+        /// expiry.
+        #[arg(long)]
+        expiry: Option<NonZeroU64>,
+    },
+    /// Revoke a stake account's vote delegation under a governance config.
+    RevokeDelegation {
+        /// The Paladin stake config address.
+        #[arg(long)]
+        stake_config: Pubkey,
+        /// The delegating Paladin stake account.
+        #[arg(long)]
+        stake: Pubkey,
+        /// Keypair of the stake account's authority. Defaults to the payer.
+        #[arg(long)]
+        stake_authority: Option<String>,
+    },
+    /// Initialize the governance treasury, and optionally its token account
+    /// for a mint.
+    InitTreasury {
+        /// The Paladin stake config address.
+        #[arg(long)]
+        stake_config: Pubkey,
+        /// Mint of the treasury token account to create.
+        #[arg(long)]
+        mint: Option<Pubkey>,
+    },
+    /// Deposit lamports, or tokens of a mint, into the governance treasury.
+    DepositToTreasury {
+        /// The Paladin stake config address.
+        #[arg(long)]
+        stake_config: Pubkey,
+        /// Amount of lamports, or of tokens with `--mint`, to deposit.
+        #[arg(long)]
+        amount: u64,
+        /// Mint of the tokens to deposit.
+        #[arg(long, requires = "source")]
+        mint: Option<Pubkey>,
+        /// Token account the tokens are deposited from.
+        #[arg(long, requires = "mint")]
+        source: Option<Pubkey>,
+        /// Keypair of the depositor, or of the source token account's owner
+        /// with `--mint`. Defaults to the payer.
+        #[arg(long)]
+        depositor: Option<String>,
+    },
+    /// Push a transfer of lamports, or tokens of a mint, out of the
+    /// governance treasury onto a proposal's transaction.
+    TransferFromTreasury {
+        /// The proposal address.
+        #[arg(long)]
+        proposal: Pubkey,
+        /// Keypair of the proposal author's stake authority. Defaults to the
+        /// payer.
+        #[arg(long)]
+        stake_authority: Option<String>,
+        /// The destination account, or token account with `--mint`.
+        #[arg(long)]
+        destination: Pubkey,
+        /// Amount of lamports, or of tokens with `--mint`, to transfer.
+        #[arg(long)]
+        amount: u64,
+        /// Mint of the tokens to transfer.
+        #[arg(long)]
+        mint: Option<Pubkey>,
+    },
+    /// Migrate an account to the current layout version.
+    ///
+    /// Proposal votes, proposal transactions and proposals in the voting stage
+    /// laid out prior to versioning also require their stake account,
+    /// proposal or stake config, respectively.
+    MigrateAccount {
+        /// The address of the account to migrate.
+        address: Pubkey,
+        /// The stake account of a proposal vote or vote delegation.
+        #[arg(long, conflicts_with_all = ["proposal", "stake_config"])]
+        stake: Option<Pubkey>,
+        /// The proposal of a proposal transaction.
+        #[arg(long, conflicts_with = "stake_config")]
+        proposal: Option<Pubkey>,
+        /// The stake config of a proposal in the voting stage.
+        #[arg(long)]
+        stake_config: Option<Pubkey>,
+    },
+    /// Show a governance config.
+    ShowGovernance {
+        /// The Paladin stake config address.
+        #[arg(long)]
+        stake_config: Pubkey,
+    },
+    /// Show a proposal and its transaction.
+    ShowProposal {
+        /// The proposal address.
+        proposal: Pubkey,
+    },
+    /// Show a stake account's vote on a proposal.
+    ShowVote {
+        /// The proposal address.
+        #[arg(long)]
+        proposal: Pubkey,
+        /// The voting Paladin stake account.
+        #[arg(long)]
+        stake: Pubkey,
+    },
+    /// List the proposals created under a governance config.
+    List {
+        /// The Paladin stake config address.
+        #[arg(long)]
+        stake_config: Pubkey,
+    },
+}
+
+fn load_keypair(path: Option<&str>) -> Result<Keypair, Box<dyn Error>> {
+    let path = match path {
+        Some(path) => path.to_string(),
+        None => {
+            let home = std::env::var("HOME").map_err(|_| "HOME is not set")?;
+            format!("{}/.config/solana/id.json", home)
+        }
+    };
+    read_keypair_file(&path)
+        .map_err(|err| format!("failed to read keypair {}: {}", path, err).into())
+}
+
+// Signers not given explicitly default to the payer.
+fn load_signer(path: Option<&str>, payer: &Keypair) -> Result<Keypair, Box<dyn Error>> {
+    match path {
+        Some(_) => load_keypair(path),
+        None => Ok(payer.insecure_clone()),
+    }
+}
+
+async fn send(
+    rpc_client: &RpcClient,
+    payer: &Keypair,
+    signers: &[&dyn Signer],
+    instructions: &[Instruction],
+) -> Result<Signature, Box<dyn Error>> {
+    let recent_blockhash = rpc_client.get_latest_blockhash().await?;
+    let mut all_signers: Vec<&dyn Signer> = vec![payer];
+    all_signers.extend_from_slice(signers);
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&payer.pubkey()),
+        &all_signers,
+        recent_blockhash,
+    );
+    send_transaction(rpc_client, &transaction).await
+}
+
+async fn send_transaction(
+    rpc_client: &RpcClient,
+    transaction: &Transaction,
+) -> Result<Signature, Box<dyn Error>> {
+    let signature = rpc_client.send_and_confirm_transaction(transaction).await?;
+    println!("Signature: {}", signature);
+    Ok(signature)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn command_init_governance(
+    rpc_client: &RpcClient,
+    payer: &Keypair,
+    stake_config: &Pubkey,
+    authority: &Keypair,
+    cooldown_period_seconds: u64,
+    execution_window_seconds: u64,
+    proposal_acceptance_threshold: u32,
+    proposal_minimum_stake: u64,
+    proposal_rejection_threshold: u32,
+    quorum_threshold: u32,
+    veto_authority: Option<&Pubkey>,
+    voting_period_seconds: u64,
+    period_bounds: PeriodBounds,
+) -> CommandResult {
+    let governance_config = get_governance_address(stake_config);
+    let rent_exempt_lamports = rpc_client
+        .get_minimum_balance_for_rent_exemption(std::mem::size_of::<Config>())
+        .await?;
+
+    // The governance config account must be pre-funded.
+    let instructions = [
+        system_instruction::transfer(&payer.pubkey(), &governance_config, rent_exempt_lamports),
+        initialize_governance(
+            &governance_config,
+            stake_config,
+            &authority.pubkey(),
+            cooldown_period_seconds,
+            execution_window_seconds,
+            proposal_acceptance_threshold,
+            proposal_minimum_stake,
+            proposal_rejection_threshold,
+            quorum_threshold,
+            veto_authority,
+            voting_period_seconds,
+            period_bounds,
+        ),
+    ];
+    send(rpc_client, payer, &[authority], &instructions).await?;
+    println!("Governance config: {}", governance_config);
+    Ok(())
+}
+
+// The stake config of a proposal's governance config, as recorded on the
+// proposal.
+async fn fetch_proposal_stake_config(
+    rpc_client: &RpcClient,
+    proposal_address: &Pubkey,
+) -> Result<Pubkey, Box<dyn Error>> {
+    let proposal = fetch_proposal(rpc_client, proposal_address).await?;
+    Ok(proposal.governance_config.stake_config_address)
+}
+
+// Token accounts are owned by the token program that owns their mint.
+async fn fetch_token_program_id(
+    rpc_client: &RpcClient,
+    mint: &Pubkey,
+) -> Result<Pubkey, Box<dyn Error>> {
+    Ok(rpc_client.get_account(mint).await?.owner)
+}
+
+async fn command_switch_vote(
+    rpc_client: &RpcClient,
+    payer: &Keypair,
+    proposal: &Pubkey,
+    stake: &Pubkey,
+    voter: &Keypair,
+    delegate: bool,
+    election: ProposalVoteElection,
+) -> CommandResult {
+    let stake_config = fetch_proposal_stake_config(rpc_client, proposal).await?;
+    let governance_config = get_governance_address(&stake_config);
+    let transaction = switch_vote_transaction(
+        rpc_client,
+        payer,
+        voter,
+        stake,
+        &stake_config,
+        proposal,
+        delegate.then_some(&governance_config),
+        election,
+    )
+    .await?;
+    send_transaction(rpc_client, &transaction).await?;
+    Ok(())
+}
+
+async fn command_withdraw_vote(
+    rpc_client: &RpcClient,
+    payer: &Keypair,
+    proposal: &Pubkey,
+    stake: &Pubkey,
+    voter: &Keypair,
+    delegate: bool,
+) -> CommandResult {
+    let stake_config = fetch_proposal_stake_config(rpc_client, proposal).await?;
+    let governance_config = get_governance_address(&stake_config);
+    let transaction = withdraw_vote_transaction(
+        rpc_client,
+        payer,
+        voter,
+        stake,
+        &stake_config,
+        proposal,
+        delegate.then_some(&governance_config),
+    )
+    .await?;
+    send_transaction(rpc_client, &transaction).await?;
+    Ok(())
+}
+
+async fn command_close_proposal(
+    rpc_client: &RpcClient,
+    payer: &Keypair,
+    proposal: &Pubkey,
+    stake_authority: &Keypair,
+) -> CommandResult {
+    let [close_transaction, close] =
+        close_proposal_instructions(&stake_authority.pubkey(), proposal);
+
+    // The proposal transaction account must be closed before the proposal.
+    let proposal_transaction = get_proposal_transaction_address(proposal);
+    let instructions = match fetch_account(rpc_client, &proposal_transaction).await {
+        Ok(_) => vec![close_transaction, close],
+        Err(PaladinGovernanceClientError::AccountNotFound(_)) => vec![close],
+        Err(err) => return Err(err.into()),
+    };
+    send(rpc_client, payer, &[stake_authority], &instructions).await?;
+    Ok(())
+}
+
+async fn command_init_treasury(
+    rpc_client: &RpcClient,
+    payer: &Keypair,
+    stake_config: &Pubkey,
+    mint: Option<&Pubkey>,
+) -> CommandResult {
+    let governance_config = get_governance_address(stake_config);
+    let treasury = get_treasury_address(stake_config);
+
+    let instruction = match mint {
+        Some(mint) => initialize_treasury_token_account(
+            &payer.pubkey(),
+            &treasury,
+            &governance_config,
+            mint,
+            &fetch_token_program_id(rpc_client, mint).await?,
+        ),
+        None => initialize_treasury(&payer.pubkey(), &treasury, &governance_config),
+    };
+    send(rpc_client, payer, &[], &[instruction]).await?;
+    println!("Treasury: {}", treasury);
+    Ok(())
+}
+
+async fn command_deposit_to_treasury(
+    rpc_client: &RpcClient,
+    payer: &Keypair,
+    stake_config: &Pubkey,
+    depositor: &Keypair,
+    amount: u64,
+    token_accounts: Option<(&Pubkey, &Pubkey)>,
+) -> CommandResult {
+    let governance_config = get_governance_address(stake_config);
+    let treasury = get_treasury_address(stake_config);
+
+    let instruction = match token_accounts {
+        Some((mint, source)) => deposit_tokens_to_treasury(
+            &depositor.pubkey(),
+            source,
+            &treasury,
+            &governance_config,
+            mint,
+            &fetch_token_program_id(rpc_client, mint).await?,
+            amount,
+        ),
+        None => deposit_to_treasury(&depositor.pubkey(), &treasury, &governance_config, amount),
+    };
+    send(rpc_client, payer, &[depositor], &[instruction]).await?;
+    Ok(())
+}
+
+async fn command_transfer_from_treasury(
+    rpc_client: &RpcClient,
+    payer: &Keypair,
+    proposal: &Pubkey,
+    stake_authority: &Keypair,
+    destination: &Pubkey,
+    amount: u64,
+    mint: Option<&Pubkey>,
+) -> CommandResult {
+    let stake_config = fetch_proposal_stake_config(rpc_client, proposal).await?;
+    let governance_config = get_governance_address(&stake_config);
+    let proposal_transaction = get_proposal_transaction_address(proposal);
+    let treasury = get_treasury_address(&stake_config);
+
+    // The transfer is signed for by the proposal transaction account once the
+    // proposal is processed.
+    let instruction = match mint {
+        Some(mint) => transfer_tokens_from_treasury(
+            &governance_config,
+            proposal,
+            &proposal_transaction,
+            &treasury,
+            destination,
+            mint,
+            &fetch_token_program_id(rpc_client, mint).await?,
+            amount,
+        ),
+        None => transfer_from_treasury(
+            &governance_config,
+            proposal,
+            &proposal_transaction,
+            &treasury,
+            destination,
+            amount,
+        ),
+    };
+    let transaction =
+        push_instruction_transaction(rpc_client, payer, stake_authority, proposal, &instruction)
+            .await?;
+    send_transaction(rpc_client, &transaction).await?;
+    Ok(())
+}
+
+fn migrate_account_instruction(
+    payer: &Pubkey,
+    address: &Pubkey,
+    stake: Option<&Pubkey>,
+    proposal: Option<&Pubkey>,
+    stake_config: Option<&Pubkey>,
+) -> Instruction {
+    match (stake, proposal, stake_config) {
+        (Some(stake), _, _) => migrate_proposal_vote_account(address, payer, stake),
+        (_, Some(proposal), _) => migrate_proposal_transaction_account(address, payer, proposal),
+        (_, _, Some(stake_config)) => migrate_voting_proposal_account(address, payer, stake_config),
+        _ => migrate_account(address, payer),
+    }
+}
+
+async fn command_show_proposal(rpc_client: &RpcClient, proposal_address: &Pubkey) -> CommandResult {
+    let proposal = fetch_proposal(rpc_client, proposal_address).await?;
+    display::print_proposal(proposal_address, &proposal);
+
+    let proposal_transaction_address = get_proposal_transaction_address(proposal_address);
+    let proposal_transaction =
+        fetch_proposal_transaction(rpc_client, &proposal_transaction_address).await?;
+    display::print_proposal_transaction(&proposal_transaction_address, &proposal_transaction);
+    Ok(())
+}
+
+async fn command_list(rpc_client: &RpcClient, stake_config: &Pubkey) -> CommandResult {
+    let governance_config_address = get_governance_address(stake_config);
+    let governance_config = fetch_governance_config(rpc_client, &governance_config_address).await?;
+
+    for index in 0..governance_config.proposal_count {
+        let proposal_address = get_proposal_address(&governance_config_address, index);
+        match fetch_proposal(rpc_client, &proposal_address).await {
+            Ok(proposal) => display::print_proposal_summary(index, &proposal_address, &proposal),
+            // Closed proposals are skipped.
+            Err(PaladinGovernanceClientError::AccountNotFound(_)) => {}
+            Err(err) => return Err(err.into()),
+        }
+    }
+    Ok(())
+}
+
+async fn run(cli: Cli) -> CommandResult {
+    let rpc_client = RpcClient::new_with_commitment(cli.url, CommitmentConfig::confirmed());
+
+    // Read-only commands do not require a keypair.
+    match &cli.command {
+        Command::ShowGovernance { stake_config } => {
+            let governance_config_address = get_governance_address(stake_config);
+            let governance_config =
+                fetch_governance_config(&rpc_client, &governance_config_address).await?;
+            display::print_governance_config(&governance_config_address, &governance_config);
+            return Ok(());
+        }
+        Command::ShowProposal { proposal } => {
+            return command_show_proposal(&rpc_client, proposal).await;
+        }
+        Command::ShowVote { proposal, stake } => {
+            let proposal_vote = fetch_proposal_vote(&rpc_client, stake, proposal).await?;
+            display::print_proposal_vote(
+                &get_proposal_vote_address(stake, proposal),
+                &proposal_vote,
+            );
+            return Ok(());
+        }
+        Command::List { stake_config } => {
+            return command_list(&rpc_client, stake_config).await;
+        }
+        _ => {}
+    }
+
+    let payer = load_keypair(cli.keypair.as_deref())?;
+
+    match cli.command {
+        Command::InitGovernance {
+            stake_config,
+            authority,
+            cooldown_period,
+            execution_window,
+            acceptance_threshold,
+            minimum_stake,
+            rejection_threshold,
+            quorum_threshold,
+            voting_period,
+            veto_authority,
+            min_cooldown_period,
+            max_cooldown_period,
+            min_execution_window,
+            max_execution_window,
+            min_voting_period,
+            max_voting_period,
+        } => {
+            let authority = load_signer(authority.as_deref(), &payer)?;
+            let period_bounds = PeriodBounds {
+                min_cooldown_period_seconds: min_cooldown_period,
+                max_cooldown_period_seconds: max_cooldown_period,
+                min_execution_window_seconds: min_execution_window,
+                max_execution_window_seconds: max_execution_window,
+                min_voting_period_seconds: min_voting_period,
+                max_voting_period_seconds: max_voting_period,
+            };
+            command_init_governance(
+                &rpc_client,
+                &payer,
+                &stake_config,
+                &authority,
+                cooldown_period,
+                execution_window,
+                acceptance_threshold,
+                minimum_stake,
+                rejection_threshold,
+                quorum_threshold,
+                veto_authority.as_ref(),
+                voting_period,
+                period_bounds,
+            )
+            .await
+        }
+        Command::Create {
+            stake_config,
+            stake,
+            stake_authority,
+            title,
+            uri,
+            content_hash,
+        } => {
+            let stake_authority = load_signer(stake_authority.as_deref(), &payer)?;
+            let (proposal, transaction) = create_proposal_transaction(
+                &rpc_client,
+                &payer,
+                &stake_authority,
+                &stake,
+                &get_governance_address(&stake_config),
+                title,
+                uri,
+                content_hash,
+            )
+            .await?;
+            send_transaction(&rpc_client, &transaction).await?;
+            println!("Proposal: {}", proposal);
+            Ok(())
+        }
+        Command::PushInstruction {
+            proposal,
+            stake_authority,
+            instruction,
+            chunk_size: Some(chunk_size),
+        } => {
+            let stake_authority = load_signer(stake_authority.as_deref(), &payer)?;
+            let instructions = write_proposal_instruction_instructions(
+                &payer.pubkey(),
+                &stake_authority.pubkey(),
+                &proposal,
+                &instruction,
+                chunk_size,
+            )?;
+            for instruction in instructions {
+                send(&rpc_client, &payer, &[&stake_authority], &[instruction]).await?;
+            }
+            Ok(())
+        }
+        Command::PushInstruction {
+            proposal,
+            stake_authority,
+            instruction,
+            chunk_size: None,
+        } => {
+            let stake_authority = load_signer(stake_authority.as_deref(), &payer)?;
+            let transaction = push_instruction_transaction(
+                &rpc_client,
+                &payer,
+                &stake_authority,
+                &proposal,
+                &instruction,
+            )
+            .await?;
+            send_transaction(&rpc_client, &transaction).await?;
+            Ok(())
+        }
+        Command::Remove {
+            proposal,
+            stake_authority,
+            index,
+        } => {
+            let stake_authority = load_signer(stake_authority.as_deref(), &payer)?;
+            let instruction = remove_instruction(
+                &stake_authority.pubkey(),
+                &proposal,
+                &get_proposal_transaction_address(&proposal),
+                index,
+            );
+            send(&rpc_client, &payer, &[&stake_authority], &[instruction]).await?;
+            Ok(())
+        }
+        Command::BeginVoting {
+            proposal,
+            stake,
+            stake_authority,
+        } => {
+            let stake_authority = load_signer(stake_authority.as_deref(), &payer)?;
+            let stake_config = fetch_proposal_stake_config(&rpc_client, &proposal).await?;
+            let transaction = begin_voting_transaction(
+                &rpc_client,
+                &payer,
+                &stake_authority,
+                &stake,
+                &stake_config,
+                &proposal,
+            )
+            .await?;
+            send_transaction(&rpc_client, &transaction).await?;
+            Ok(())
+        }
+        Command::Vote {
+            proposal,
+            stake,
+            voter,
+            delegate,
+            election,
+        } => {
+            let voter = load_signer(voter.as_deref(), &payer)?;
+            let stake_config = fetch_proposal_stake_config(&rpc_client, &proposal).await?;
+            let governance_config = get_governance_address(&stake_config);
+            let transaction = vote_transaction(
+                &rpc_client,
+                &payer,
+                &voter,
+                &stake,
+                &stake_config,
+                &proposal,
+                delegate.then_some(&governance_config),
+                election,
+            )
+            .await?;
+            send_transaction(&rpc_client, &transaction).await?;
+            Ok(())
+        }
+        Command::SwitchVote {
+            proposal,
+            stake,
+            voter,
+            delegate,
+            election,
+        } => {
+            let voter = load_signer(voter.as_deref(), &payer)?;
+            command_switch_vote(
+                &rpc_client,
+                &payer,
+                &proposal,
+                &stake,
+                &voter,
+                delegate,
+                election,
+            )
+            .await
+        }
+        Command::Process { proposal, index } => {
+            let transaction =
+                process_instruction_transaction(&rpc_client, &payer, &proposal, index).await?;
+            send_transaction(&rpc_client, &transaction).await?;
+            Ok(())
+        }
+        Command::Finalize { proposal } => {
+            let transaction = finalize_proposal_transaction(&rpc_client, &payer, &proposal).await?;
+            send_transaction(&rpc_client, &transaction).await?;
+            Ok(())
+        }
+        Command::Cancel {
+            proposal,
+            stake_authority,
+        } => {
+            let stake_authority = load_signer(stake_authority.as_deref(), &payer)?;
+            let transaction =
+                cancel_proposal_transaction(&rpc_client, &payer, &stake_authority, &proposal)
+                    .await?;
+            send_transaction(&rpc_client, &transaction).await?;
+            Ok(())
+        }
+        Command::WithdrawVote {
+            proposal,
+            stake,
+            voter,
+            delegate,
+        } => {
+            let voter = load_signer(voter.as_deref(), &payer)?;
+            command_withdraw_vote(&rpc_client, &payer, &proposal, &stake, &voter, delegate).await
+        }
+        Command::SyncVoteWeight { proposal, stake } => {
+            let stake_config = fetch_proposal_stake_config(&rpc_client, &proposal).await?;
+            let instruction = sync_vote_weight(
+                &stake,
+                &stake_config,
+                &get_proposal_vote_address(&stake, &proposal),
+                &proposal,
+            );
+            send(&rpc_client, &payer, &[], &[instruction]).await?;
+            Ok(())
+        }
+        Command::Veto {
+            proposal,
+            veto_authority,
+        } => {
+            let veto_authority = load_signer(veto_authority.as_deref(), &payer)?;
+            let stake_config = fetch_proposal_stake_config(&rpc_client, &proposal).await?;
+            let instruction = veto_proposal(
+                &veto_authority.pubkey(),
+                &get_governance_address(&stake_config),
+                &proposal,
+            );
+            send(&rpc_client, &payer, &[&veto_authority], &[instruction]).await?;
+            Ok(())
+        }
+        Command::CloseProposal {
+            proposal,
+            stake_authority,
+        } => {
+            let stake_authority = load_signer(stake_authority.as_deref(), &payer)?;
+            command_close_proposal(&rpc_client, &payer, &proposal, &stake_authority).await
+        }
+        Command::CloseTransaction {
+            proposal,
+            stake_authority,
+        } => {
+            let stake_authority = load_signer(stake_authority.as_deref(), &payer)?;
+            let instruction = close_proposal_transaction(
+                &stake_authority.pubkey(),
+                &proposal,
+                &get_proposal_transaction_address(&proposal),
+            );
+            send(&rpc_client, &payer, &[&stake_authority], &[instruction]).await?;
+            Ok(())
+        }
+        Command::CloseVote {
+            proposal,
+            stake,
+            authority,
+        } => {
+            let authority = load_signer(authority.as_deref(), &payer)?;
+            let transaction =
+                close_proposal_vote_transaction(&rpc_client, &payer, &authority, &stake, &proposal)
+                    .await?;
+            send_transaction(&rpc_client, &transaction).await?;
+            Ok(())
+        }
+        Command::SetDelegation {
+            stake_config,
+            stake,
+            stake_authority,
+            delegate,
+            expiry,
+        } => {
+            let stake_authority = load_signer(stake_authority.as_deref(), &payer)?;
+            let governance_config = get_governance_address(&stake_config);
+            let instruction = set_vote_delegation(
+                &stake_authority.pubkey(),
+                &stake,
+                &stake_config,
+                &governance_config,
+                &get_vote_delegation_address(&stake, &governance_config),
+                &payer.pubkey(),
+                &delegate,
+                expiry,
+            );
+            send(&rpc_client, &payer, &[&stake_authority], &[instruction]).await?;
+            Ok(())
+        }
+        Command::RevokeDelegation {
+            stake_config,
+            stake,
+            stake_authority,
+        } => {
+            let stake_authority = load_signer(stake_authority.as_deref(), &payer)?;
+            let governance_config = get_governance_address(&stake_config);
+            let vote_delegation_address = get_vote_delegation_address(&stake, &governance_config);
+            // The delegation's rent is refunded to the payer that funded it.
+            let vote_delegation =
+                fetch_vote_delegation(&rpc_client, &vote_delegation_address).await?;
+            let instruction = revoke_vote_delegation(
+                &stake_authority.pubkey(),
+                &stake,
+                &stake_config,
+                &vote_delegation_address,
+                &vote_delegation.payer_address,
+            );
+            send(&rpc_client, &payer, &[&stake_authority], &[instruction]).await?;
+            Ok(())
+        }
+        Command::InitTreasury { stake_config, mint } => {
+            command_init_treasury(&rpc_client, &payer, &stake_config, mint.as_ref()).await
+        }
+        Command::DepositToTreasury {
+            stake_config,
+            amount,
+            mint,
+            source,
+            depositor,
+        } => {
+            let depositor = load_signer(depositor.as_deref(), &payer)?;
+            command_deposit_to_treasury(
+                &rpc_client,
+                &payer,
+                &stake_config,
+                &depositor,
+                amount,
+                mint.as_ref().zip(source.as_ref()),
+            )
+            .await
+        }
+        Command::TransferFromTreasury {
+            proposal,
+            stake_authority,
+            destination,
+            amount,
+            mint,
+        } => {
+            let stake_authority = load_signer(stake_authority.as_deref(), &payer)?;
+            command_transfer_from_treasury(
+                &rpc_client,
+                &payer,
+                &proposal,
+                &stake_authority,
+                &destination,
+                amount,
+                mint.as_ref(),
+            )
+            .await
+        }
+        Command::MigrateAccount {
+            address,
+            stake,
+            proposal,
+            stake_config,
+        } => {
+            let instruction = migrate_account_instruction(
+                &payer.pubkey(),
+                &address,
+                stake.as_ref(),
+                proposal.as_ref(),
+                stake_config.as_ref(),
+            );
+            send(&rpc_client, &payer, &[], &[instruction]).await?;
+            Ok(())
+        }
+        Command::ShowGovernance { .. }
+        | Command::ShowProposal { .. }
+        | Command::ShowVote { .. }
+        | Command::List { .. } => unreachable!(),
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    if let Err(err) = run(Cli::parse()).await {
+        eprintln!("Error: {}", err);
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, clap::CommandFactory};
+
+    #[test]
+    fn test_cli() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_parse_vote() {
+        let proposal = Pubkey::new_unique();
+        let stake = Pubkey::new_unique();
+        let cli = Cli::try_parse_from([
+            "paladin-governance",
+            "vote",
+            "--proposal",
+            &proposal.to_string(),
+            "--stake",
+            &stake.to_string(),
+            "--election",
+            "against",
+            "--delegate",
+        ])
+        .unwrap();
+
+        match cli.command {
+            Command::Vote {
+                proposal: parsed_proposal,
+                stake: parsed_stake,
+                voter,
+                delegate,
+                election,
+            } => {
+                assert_eq!(parsed_proposal, proposal);
+                assert_eq!(parsed_stake, stake);
+                assert_eq!(voter, None);
+                assert!(delegate);
+                assert_eq!(election, ProposalVoteElection::Against);
+            }
+            _ => panic!("expected vote command"),
+        }
+    }
+}
@@ -0,0 +1,814 @@
+//! Round trips of each command family against a local test validator.
+//!
+//! The program must first be built to the deploy directory, as with
+//! `pnpm programs:build`.
+
+#![cfg(feature = "test-sbf")]
+
+use {
+    bytemuck::Pod,
+    paladin_governance_client::{
+        accounts::{
+            fetch_account, fetch_governance_config, fetch_proposal, fetch_proposal_transaction,
+            fetch_proposal_vote, fetch_vote_delegation,
+        },
+        error::PaladinGovernanceClientError,
+        instruction::transfer_from_treasury,
+        pda::{
+            get_governance_address, get_proposal_address, get_proposal_transaction_address,
+            get_proposal_vote_address, get_treasury_address, get_vote_delegation_address,
+        },
+        state::{
+            Config, Proposal, ProposalInstruction, ProposalStatus, ProposalTransaction,
+            ProposalVote, ProposalVoteElection, ProposalVoteV0,
+        },
+    },
+    paladin_stake_program::state::{find_stake_pda, Config as StakeConfig, Stake},
+    solana_client::nonblocking::rpc_client::RpcClient,
+    solana_sdk::{
+        account::{Account, AccountSharedData},
+        commitment_config::CommitmentConfig,
+        pubkey::Pubkey,
+        rent::Rent,
+        signature::{write_keypair_file, Keypair},
+        signer::Signer,
+    },
+    solana_test_validator::{TestValidator, TestValidatorGenesis},
+    spl_discriminator::SplDiscriminate,
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+    std::{
+        num::NonZeroU64,
+        path::PathBuf,
+        time::{SystemTime, UNIX_EPOCH},
+    },
+    tokio::process::Command,
+};
+
+const TOTAL_STAKE: u64 = 1_000_000;
+const STAKE: u64 = 100;
+
+struct TestEnv {
+    validator: TestValidator,
+    rpc_client: RpcClient,
+    payer: Keypair,
+    payer_path: PathBuf,
+    stake_config: Pubkey,
+    governance_config: Pubkey,
+    stake: Pubkey,
+}
+
+impl TestEnv {
+    // Start a validator with a stake config, a stake account of the payer's
+    // and, unless the governance config is to be initialized by the test, a
+    // governance config with the payer as veto authority. Accounts are
+    // injected at genesis, given a function of the environment's payer, stake
+    // config and stake account.
+    async fn start<F>(with_governance: bool, accounts: F) -> Self
+    where
+        F: FnOnce(&Pubkey, &Pubkey, &Pubkey) -> Vec<(Pubkey, AccountSharedData)>,
+    {
+        let payer = Keypair::new();
+        let stake_config = Pubkey::new_unique();
+        let governance_config = get_governance_address(&stake_config);
+        let validator_vote = Pubkey::new_unique();
+        let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+
+        let mut genesis = TestValidatorGenesis::default();
+        genesis
+            .add_program(
+                "paladin_governance_program",
+                paladin_governance_client::id(),
+            )
+            .add_account(
+                payer.pubkey(),
+                AccountSharedData::new(100_000_000_000, 0, &solana_sdk::system_program::id()),
+            )
+            .add_account(stake_config, stake_config_account(&payer.pubkey()))
+            .add_account(
+                stake,
+                stake_account(&payer.pubkey(), &validator_vote, STAKE),
+            )
+            .add_accounts(accounts(&payer.pubkey(), &stake_config, &stake));
+        if with_governance {
+            genesis.add_account(
+                governance_config,
+                pod_account(&governance_config_state(&stake_config, &payer.pubkey())),
+            );
+        }
+        let (validator, _) = genesis.start_async().await;
+
+        let payer_path = std::env::temp_dir().join(format!("{}.json", payer.pubkey()));
+        write_keypair_file(&payer, &payer_path).unwrap();
+
+        let rpc_client =
+            RpcClient::new_with_commitment(validator.rpc_url(), CommitmentConfig::confirmed());
+
+        Self {
+            validator,
+            rpc_client,
+            payer,
+            payer_path,
+            stake_config,
+            governance_config,
+            stake,
+        }
+    }
+
+    // Run the command-line tool, paid for by the payer, returning its output
+    // once it succeeds.
+    async fn run(&self, args: &[&str]) -> String {
+        let output = Command::new(env!("CARGO_BIN_EXE_paladin-governance"))
+            .arg("--url")
+            .arg(self.validator.rpc_url())
+            .arg("--keypair")
+            .arg(&self.payer_path)
+            .args(args)
+            .output()
+            .await
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "`{}` failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8(output.stdout).unwrap()
+    }
+
+    async fn is_closed(&self, address: &Pubkey) -> bool {
+        matches!(
+            fetch_account(&self.rpc_client, address).await,
+            Err(PaladinGovernanceClientError::AccountNotFound(_))
+        )
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+fn account(data: Vec<u8>, owner: &Pubkey) -> AccountSharedData {
+    AccountSharedData::from(Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner: *owner,
+        ..Account::default()
+    })
+}
+
+fn pod_account<T: Pod>(state: &T) -> AccountSharedData {
+    account(
+        bytemuck::bytes_of(state).to_vec(),
+        &paladin_governance_client::id(),
+    )
+}
+
+fn stake_config_account(authority: &Pubkey) -> AccountSharedData {
+    let state = StakeConfig {
+        discriminator: StakeConfig::SPL_DISCRIMINATOR.into(),
+        authority: OptionalNonZeroPubkey(*authority),
+        token_amount_delegated: TOTAL_STAKE,
+        ..Default::default()
+    };
+    account(
+        bytemuck::bytes_of(&state).to_vec(),
+        &paladin_stake_program::id(),
+    )
+}
+
+fn stake_account(authority: &Pubkey, validator_vote: &Pubkey, amount: u64) -> AccountSharedData {
+    let mut state = Stake::new(*authority, *validator_vote);
+    state.amount = amount;
+    account(
+        bytemuck::bytes_of(&state).to_vec(),
+        &paladin_stake_program::id(),
+    )
+}
+
+fn governance_config_state(stake_config: &Pubkey, veto_authority: &Pubkey) -> Config {
+    Config::new(
+        /* cooldown_period_seconds */ 60 * 60,
+        /* execution_window_seconds */ 24 * 60 * 60,
+        /* proposal_acceptance_threshold */ 500_000_000,
+        /* proposal_minimum_stake */ STAKE,
+        /* proposal_rejection_threshold */ 500_000_000,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        stake_config,
+        Some(veto_authority),
+        /* voting_period_seconds */ 24 * 60 * 60,
+    )
+}
+
+// A proposal authored by the payer, under the environment's governance
+// config.
+fn proposal_state(
+    payer: &Pubkey,
+    stake_config: &Pubkey,
+    status: ProposalStatus,
+    voting_start_timestamp: Option<u64>,
+) -> Proposal {
+    let mut state = Proposal::new(
+        payer,
+        now() as i64,
+        governance_config_state(stake_config, payer),
+    );
+    state.status = status;
+    state.total_stake = TOTAL_STAKE;
+    state.voting_start_timestamp = voting_start_timestamp.and_then(NonZeroU64::new);
+    state
+}
+
+fn proposal_accounts(
+    proposal: &Pubkey,
+    state: &Proposal,
+    proposal_transaction: &ProposalTransaction,
+) -> Vec<(Pubkey, AccountSharedData)> {
+    vec![
+        (*proposal, pod_account(state)),
+        (
+            get_proposal_transaction_address(proposal),
+            account(
+                borsh::to_vec(proposal_transaction).unwrap(),
+                &paladin_governance_client::id(),
+            ),
+        ),
+    ]
+}
+
+#[tokio::test]
+async fn test_governance() {
+    let env = TestEnv::start(false, |_, _, _| vec![]).await;
+    let stake_config = env.stake_config.to_string();
+    let veto_authority = Pubkey::new_unique();
+
+    env.run(&[
+        "init-governance",
+        "--stake-config",
+        &stake_config,
+        "--cooldown-period",
+        "3600",
+        "--execution-window",
+        "86400",
+        "--acceptance-threshold",
+        "500000000",
+        "--minimum-stake",
+        "100",
+        "--rejection-threshold",
+        "400000000",
+        "--quorum-threshold",
+        "200000000",
+        "--voting-period",
+        "172800",
+        "--veto-authority",
+        &veto_authority.to_string(),
+        "--min-voting-period",
+        "60",
+    ])
+    .await;
+
+    let governance_config = fetch_governance_config(&env.rpc_client, &env.governance_config)
+        .await
+        .unwrap();
+    let mut expected_config = Config::new(
+        3600,
+        86400,
+        500_000_000,
+        100,
+        400_000_000,
+        200_000_000,
+        governance_config.signer_bump_seed,
+        &env.stake_config,
+        Some(&veto_authority),
+        172800,
+    );
+    expected_config.period_bounds.min_voting_period_seconds = 60;
+    assert_eq!(governance_config, expected_config);
+
+    let output = env
+        .run(&["show-governance", "--stake-config", &stake_config])
+        .await;
+    assert!(output.contains(&env.governance_config.to_string()));
+}
+
+#[tokio::test]
+async fn test_proposal() {
+    let env = TestEnv::start(true, |_, _, _| vec![]).await;
+    let stake_config = env.stake_config.to_string();
+    let stake = env.stake.to_string();
+    let proposal = get_proposal_address(&env.governance_config, 0);
+    let proposal_transaction = get_proposal_transaction_address(&proposal);
+
+    // Create a proposal, and write two instructions to it, the second in
+    // chunks through its instruction slot.
+    let output = env
+        .run(&[
+            "create",
+            "--stake-config",
+            &stake_config,
+            "--stake",
+            &stake,
+            "--title",
+            "Title",
+            "--uri",
+            "https://example.com/proposal.md",
+            "--content-hash",
+            &"ab".repeat(32),
+        ])
+        .await;
+    assert!(output.contains(&proposal.to_string()));
+
+    let instruction = format!(
+        r#"{{
+            "programId": "{}",
+            "accounts": [{{ "pubkey": "{}", "isSigner": false, "isWritable": true }}],
+            "data": "{}"
+        }}"#,
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        "AQID".repeat(100),
+    );
+    env.run(&[
+        "push-instruction",
+        "--proposal",
+        &proposal.to_string(),
+        "--instruction",
+        &instruction,
+    ])
+    .await;
+    env.run(&[
+        "push-instruction",
+        "--proposal",
+        &proposal.to_string(),
+        "--instruction",
+        &instruction,
+        "--chunk-size",
+        "100",
+    ])
+    .await;
+
+    let proposal_transaction_state =
+        fetch_proposal_transaction(&env.rpc_client, &proposal_transaction)
+            .await
+            .unwrap();
+    assert_eq!(proposal_transaction_state.instructions.len(), 2);
+    assert_eq!(
+        proposal_transaction_state.instructions[0],
+        proposal_transaction_state.instructions[1]
+    );
+    assert_eq!(proposal_transaction_state.instruction_slot, None);
+
+    // Remove the first instruction.
+    env.run(&[
+        "remove",
+        "--proposal",
+        &proposal.to_string(),
+        "--index",
+        "0",
+    ])
+    .await;
+
+    let proposal_transaction_state =
+        fetch_proposal_transaction(&env.rpc_client, &proposal_transaction)
+            .await
+            .unwrap();
+    assert_eq!(proposal_transaction_state.instructions.len(), 1);
+
+    let output = env.run(&["show-proposal", &proposal.to_string()]).await;
+    assert!(output.contains("Title"));
+
+    // Begin voting.
+    env.run(&[
+        "begin-voting",
+        "--proposal",
+        &proposal.to_string(),
+        "--stake",
+        &stake,
+    ])
+    .await;
+
+    let proposal_state = fetch_proposal(&env.rpc_client, &proposal).await.unwrap();
+    assert_eq!(proposal_state.status, ProposalStatus::Voting);
+    assert_eq!(proposal_state.total_stake, TOTAL_STAKE);
+
+    // Cancel the proposal, and list it.
+    env.run(&["cancel", "--proposal", &proposal.to_string()])
+        .await;
+
+    let proposal_state = fetch_proposal(&env.rpc_client, &proposal).await.unwrap();
+    assert_eq!(proposal_state.status, ProposalStatus::Cancelled);
+
+    let output = env.run(&["list", "--stake-config", &stake_config]).await;
+    assert!(output.contains(&proposal.to_string()));
+    assert!(output.contains("Cancelled"));
+}
+
+#[tokio::test]
+async fn test_vote() {
+    let proposal = Pubkey::new_unique();
+    let env = TestEnv::start(true, |payer, stake_config, _| {
+        let state = proposal_state(payer, stake_config, ProposalStatus::Voting, Some(now()));
+        proposal_accounts(&proposal, &state, &ProposalTransaction::default())
+    })
+    .await;
+    let proposal_arg = proposal.to_string();
+    let stake = env.stake.to_string();
+    let proposal_vote = get_proposal_vote_address(&env.stake, &proposal);
+
+    // Vote, then switch the vote.
+    env.run(&[
+        "vote",
+        "--proposal",
+        &proposal_arg,
+        "--stake",
+        &stake,
+        "--election",
+        "for",
+    ])
+    .await;
+
+    let proposal_state = fetch_proposal(&env.rpc_client, &proposal).await.unwrap();
+    assert_eq!(proposal_state.stake_for, STAKE);
+
+    env.run(&[
+        "switch-vote",
+        "--proposal",
+        &proposal_arg,
+        "--stake",
+        &stake,
+        "--election",
+        "against",
+    ])
+    .await;
+
+    let proposal_vote_state = fetch_proposal_vote(&env.rpc_client, &env.stake, &proposal)
+        .await
+        .unwrap();
+    assert_eq!(proposal_vote_state.election, ProposalVoteElection::Against);
+    assert_eq!(proposal_vote_state.payer_address, env.payer.pubkey());
+
+    let output = env
+        .run(&["show-vote", "--proposal", &proposal_arg, "--stake", &stake])
+        .await;
+    assert!(output.contains(&proposal_vote.to_string()));
+
+    // Sync the vote's weight with the unchanged stake.
+    env.run(&[
+        "sync-vote-weight",
+        "--proposal",
+        &proposal_arg,
+        "--stake",
+        &stake,
+    ])
+    .await;
+
+    let proposal_state = fetch_proposal(&env.rpc_client, &proposal).await.unwrap();
+    assert_eq!(proposal_state.stake_for, 0);
+    assert_eq!(proposal_state.stake_against, STAKE);
+
+    // Withdraw the vote.
+    env.run(&[
+        "withdraw-vote",
+        "--proposal",
+        &proposal_arg,
+        "--stake",
+        &stake,
+    ])
+    .await;
+
+    assert!(env.is_closed(&proposal_vote).await);
+    let proposal_state = fetch_proposal(&env.rpc_client, &proposal).await.unwrap();
+    assert_eq!(proposal_state.stake_against, 0);
+
+    // Vote again without an election, veto the proposal, then close the vote.
+    env.run(&[
+        "vote",
+        "--proposal",
+        &proposal_arg,
+        "--stake",
+        &stake,
+        "--election",
+        "did-not-vote",
+    ])
+    .await;
+
+    let proposal_state = fetch_proposal(&env.rpc_client, &proposal).await.unwrap();
+    assert_eq!(proposal_state.stake_abstained, STAKE);
+
+    env.run(&["veto", "--proposal", &proposal_arg]).await;
+
+    let proposal_state = fetch_proposal(&env.rpc_client, &proposal).await.unwrap();
+    assert_eq!(proposal_state.status, ProposalStatus::Vetoed);
+
+    env.run(&["close-vote", "--proposal", &proposal_arg, "--stake", &stake])
+        .await;
+
+    assert!(env.is_closed(&proposal_vote).await);
+}
+
+#[tokio::test]
+async fn test_delegation() {
+    let proposal = Pubkey::new_unique();
+    let env = TestEnv::start(true, |payer, stake_config, _| {
+        let state = proposal_state(payer, stake_config, ProposalStatus::Voting, Some(now()));
+        proposal_accounts(&proposal, &state, &ProposalTransaction::default())
+    })
+    .await;
+    let stake_config = env.stake_config.to_string();
+    let stake = env.stake.to_string();
+    let vote_delegation = get_vote_delegation_address(&env.stake, &env.governance_config);
+
+    let delegate = Keypair::new();
+    let delegate_path = std::env::temp_dir().join(format!("{}.json", delegate.pubkey()));
+    write_keypair_file(&delegate, &delegate_path).unwrap();
+
+    // Delegate the stake account's votes.
+    env.run(&[
+        "set-delegation",
+        "--stake-config",
+        &stake_config,
+        "--stake",
+        &stake,
+        "--delegate",
+        &delegate.pubkey().to_string(),
+    ])
+    .await;
+
+    let vote_delegation_state = fetch_vote_delegation(&env.rpc_client, &vote_delegation)
+        .await
+        .unwrap();
+    assert_eq!(vote_delegation_state.delegate, delegate.pubkey());
+    assert_eq!(vote_delegation_state.expiry_timestamp, None);
+    assert_eq!(vote_delegation_state.payer_address, env.payer.pubkey());
+
+    // Vote as the delegate.
+    env.run(&[
+        "vote",
+        "--proposal",
+        &proposal.to_string(),
+        "--stake",
+        &stake,
+        "--voter",
+        delegate_path.to_str().unwrap(),
+        "--delegate",
+        "--election",
+        "for",
+    ])
+    .await;
+
+    let proposal_vote_state = fetch_proposal_vote(&env.rpc_client, &env.stake, &proposal)
+        .await
+        .unwrap();
+    assert_eq!(proposal_vote_state.authority_address, delegate.pubkey());
+    assert_eq!(proposal_vote_state.payer_address, env.payer.pubkey());
+
+    // Revoke the delegation.
+    env.run(&[
+        "revoke-delegation",
+        "--stake-config",
+        &stake_config,
+        "--stake",
+        &stake,
+    ])
+    .await;
+
+    assert!(env.is_closed(&vote_delegation).await);
+}
+
+#[tokio::test]
+async fn test_finalize_and_close() {
+    let accepted_proposal = Pubkey::new_unique();
+    let cancelled_proposal = Pubkey::new_unique();
+    let env = TestEnv::start(true, |payer, stake_config, stake| {
+        let accepted = proposal_state(payer, stake_config, ProposalStatus::Accepted, None);
+        let cancelled = proposal_state(payer, stake_config, ProposalStatus::Cancelled, None);
+        let mut accounts = proposal_accounts(
+            &accepted_proposal,
+            &accepted,
+            &ProposalTransaction::default(),
+        );
+        accounts.extend(proposal_accounts(
+            &cancelled_proposal,
+            &cancelled,
+            &ProposalTransaction::default(),
+        ));
+        accounts.push((
+            get_proposal_vote_address(stake, &cancelled_proposal),
+            pod_account(&ProposalVote::new(
+                &cancelled_proposal,
+                STAKE,
+                stake,
+                payer,
+                payer,
+                None,
+                ProposalVoteElection::For,
+            )),
+        ));
+        accounts
+    })
+    .await;
+
+    // An accepted proposal without instructions is processed once finalized,
+    // then closed along with its transaction account.
+    env.run(&["finalize", "--proposal", &accepted_proposal.to_string()])
+        .await;
+
+    let proposal_state = fetch_proposal(&env.rpc_client, &accepted_proposal)
+        .await
+        .unwrap();
+    assert_eq!(proposal_state.status, ProposalStatus::Processed);
+
+    env.run(&[
+        "close-proposal",
+        "--proposal",
+        &accepted_proposal.to_string(),
+    ])
+    .await;
+
+    assert!(env.is_closed(&accepted_proposal).await);
+    assert!(
+        env.is_closed(&get_proposal_transaction_address(&accepted_proposal))
+            .await
+    );
+
+    // Close a cancelled proposal's transaction account, its vote, then the
+    // proposal itself.
+    env.run(&[
+        "close-transaction",
+        "--proposal",
+        &cancelled_proposal.to_string(),
+    ])
+    .await;
+
+    assert!(
+        env.is_closed(&get_proposal_transaction_address(&cancelled_proposal))
+            .await
+    );
+    assert!(!env.is_closed(&cancelled_proposal).await);
+
+    env.run(&[
+        "close-vote",
+        "--proposal",
+        &cancelled_proposal.to_string(),
+        "--stake",
+        &env.stake.to_string(),
+    ])
+    .await;
+
+    assert!(
+        env.is_closed(&get_proposal_vote_address(&env.stake, &cancelled_proposal))
+            .await
+    );
+
+    env.run(&[
+        "close-proposal",
+        "--proposal",
+        &cancelled_proposal.to_string(),
+    ])
+    .await;
+
+    assert!(env.is_closed(&cancelled_proposal).await);
+}
+
+#[tokio::test]
+async fn test_treasury() {
+    let draft_proposal = Pubkey::new_unique();
+    let accepted_proposal = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+    let env = TestEnv::start(true, |payer, stake_config, _| {
+        let draft = proposal_state(payer, stake_config, ProposalStatus::Draft, None);
+        let mut accepted = proposal_state(payer, stake_config, ProposalStatus::Accepted, None);
+        accepted.stake_for = TOTAL_STAKE;
+
+        let transfer = transfer_from_treasury(
+            &get_governance_address(stake_config),
+            &accepted_proposal,
+            &get_proposal_transaction_address(&accepted_proposal),
+            &get_treasury_address(stake_config),
+            &destination,
+            1_000_000_000,
+        );
+
+        let mut accounts =
+            proposal_accounts(&draft_proposal, &draft, &ProposalTransaction::default());
+        accounts.extend(proposal_accounts(
+            &accepted_proposal,
+            &accepted,
+            &ProposalTransaction::new(vec![ProposalInstruction::from(&transfer)]),
+        ));
+        accounts
+    })
+    .await;
+    let stake_config = env.stake_config.to_string();
+    let treasury = get_treasury_address(&env.stake_config);
+
+    // Initialize and fund the treasury.
+    env.run(&["init-treasury", "--stake-config", &stake_config])
+        .await;
+    let initial_lamports = env.rpc_client.get_balance(&treasury).await.unwrap();
+    assert!(initial_lamports > 0);
+
+    env.run(&[
+        "deposit-to-treasury",
+        "--stake-config",
+        &stake_config,
+        "--amount",
+        "2000000000",
+    ])
+    .await;
+    assert_eq!(
+        env.rpc_client.get_balance(&treasury).await.unwrap(),
+        initial_lamports + 2_000_000_000
+    );
+
+    // Push a transfer out of the treasury onto a draft proposal.
+    env.run(&[
+        "transfer-from-treasury",
+        "--proposal",
+        &draft_proposal.to_string(),
+        "--destination",
+        &destination.to_string(),
+        "--amount",
+        "1000000000",
+    ])
+    .await;
+
+    let proposal_transaction_state = fetch_proposal_transaction(
+        &env.rpc_client,
+        &get_proposal_transaction_address(&draft_proposal),
+    )
+    .await
+    .unwrap();
+    assert_eq!(proposal_transaction_state.instructions.len(), 1);
+    assert_eq!(
+        proposal_transaction_state.instructions[0].program_id,
+        paladin_governance_client::id()
+    );
+
+    // Process the same transfer on an accepted proposal.
+    env.run(&[
+        "process",
+        "--proposal",
+        &accepted_proposal.to_string(),
+        "--index",
+        "0",
+    ])
+    .await;
+
+    assert_eq!(
+        env.rpc_client.get_balance(&destination).await.unwrap(),
+        1_000_000_000
+    );
+    let proposal_state = fetch_proposal(&env.rpc_client, &accepted_proposal)
+        .await
+        .unwrap();
+    assert_eq!(proposal_state.status, ProposalStatus::Processed);
+}
+
+#[tokio::test]
+async fn test_migrate_account() {
+    let proposal = Pubkey::new_unique();
+    let env = TestEnv::start(true, |payer, _, stake| {
+        vec![(
+            get_proposal_vote_address(stake, &proposal),
+            pod_account(&ProposalVoteV0::new(
+                &proposal,
+                STAKE,
+                payer,
+                ProposalVoteElection::For,
+            )),
+        )]
+    })
+    .await;
+    let proposal_vote = get_proposal_vote_address(&env.stake, &proposal);
+
+    env.run(&[
+        "migrate-account",
+        &proposal_vote.to_string(),
+        "--stake",
+        &env.stake.to_string(),
+    ])
+    .await;
+
+    let account = fetch_account(&env.rpc_client, &proposal_vote)
+        .await
+        .unwrap();
+    assert_eq!(account.data.len(), std::mem::size_of::<ProposalVote>());
+    assert_eq!(
+        fetch_proposal_vote(&env.rpc_client, &env.stake, &proposal)
+            .await
+            .unwrap(),
+        ProposalVote::new(
+            &proposal,
+            STAKE,
+            &env.stake,
+            &env.payer.pubkey(),
+            &env.payer.pubkey(),
+            None,
+            ProposalVoteElection::For,
+        )
+    );
+}
@@ -0,0 +1,584 @@
+//! Fetchers for Paladin Governance accounts.
+
+use {
+    crate::{error::PaladinGovernanceClientError, pda::get_proposal_vote_address},
+    borsh::BorshDeserialize,
+    bytemuck::Pod,
+    paladin_governance_program::state::{
+        deserialize_account, AccountType, Config, ConfigV0, ConfigV1, Proposal,
+        ProposalTransaction, ProposalTransactionV0, ProposalTransactionV1, ProposalV0, ProposalV1,
+        ProposalVote, ProposalVoteV0, ProposalVoteV1, ProposalVoteV2, VoteDelegation,
+    },
+    solana_client::nonblocking::rpc_client::RpcClient,
+    solana_sdk::{account::Account, pubkey::Pubkey},
+    spl_discriminator::SplDiscriminate,
+};
+
+/// Fetch an account owned by the Paladin Governance program.
+pub async fn fetch_account(
+    rpc_client: &RpcClient,
+    address: &Pubkey,
+) -> Result<Account, PaladinGovernanceClientError> {
+    let account = rpc_client
+        .get_account_with_commitment(address, rpc_client.commitment())
+        .await?
+        .value
+        .ok_or(PaladinGovernanceClientError::AccountNotFound(*address))?;
+
+    if account.owner != crate::id() {
+        return Err(PaladinGovernanceClientError::InvalidAccountOwner(*address));
+    }
+
+    Ok(account)
+}
+
+async fn fetch_account_data(
+    rpc_client: &RpcClient,
+    address: &Pubkey,
+) -> Result<Vec<u8>, PaladinGovernanceClientError> {
+    fetch_account(rpc_client, address)
+        .await
+        .map(|account| account.data)
+}
+
+// RPC account data carries no alignment guarantees, so `Pod` state is read
+// unaligned.
+fn decode_pod<T: Pod>(address: &Pubkey, data: &[u8]) -> Result<T, PaladinGovernanceClientError> {
+    bytemuck::try_pod_read_unaligned::<T>(data)
+        .map_err(|_| PaladinGovernanceClientError::InvalidAccountData(*address))
+}
+
+// Decodes `Pod` state, ensuring the data is of the expected account type and
+// current layout version.
+pub(crate) fn decode_account<T: AccountType + Pod>(
+    address: &Pubkey,
+    data: &[u8],
+) -> Result<T, PaladinGovernanceClientError> {
+    if !data.starts_with(T::SPL_DISCRIMINATOR_SLICE) {
+        return Err(PaladinGovernanceClientError::InvalidAccountData(*address));
+    }
+    let state = decode_pod::<T>(address, data)?;
+    if state.version() != T::VERSION {
+        return Err(PaladinGovernanceClientError::InvalidAccountData(*address));
+    }
+    Ok(state)
+}
+
+/// Fetch and decode a governance config account.
+///
+/// Governance configs which have not yet been migrated to the current version
+/// are converted, as `MigrateAccount` would.
+pub async fn fetch_governance_config(
+    rpc_client: &RpcClient,
+    governance_config_address: &Pubkey,
+) -> Result<Config, PaladinGovernanceClientError> {
+    let data = fetch_account_data(rpc_client, governance_config_address).await?;
+    match data.len() {
+        len if len == std::mem::size_of::<ConfigV0>() => {
+            let v0 = decode_pod::<ConfigV0>(governance_config_address, &data)?;
+            Ok(Config::from_v0(&v0))
+        }
+        len if len == std::mem::size_of::<ConfigV1>()
+            && data.starts_with(Config::SPL_DISCRIMINATOR_SLICE) =>
+        {
+            let v1 = decode_pod::<ConfigV1>(governance_config_address, &data)?;
+            Ok(Config::from_v1(&v1))
+        }
+        _ => decode_account(governance_config_address, &data),
+    }
+}
+
+/// Fetch and decode a proposal account.
+///
+/// Proposals which have not yet been migrated to the current version are
+/// converted, as `MigrateAccount` would. Proposals migrated from the
+/// unversioned layout carry no total stake, which `MigrateAccount` only
+/// records for a proposal in the voting stage.
+pub async fn fetch_proposal(
+    rpc_client: &RpcClient,
+    proposal_address: &Pubkey,
+) -> Result<Proposal, PaladinGovernanceClientError> {
+    let data = fetch_account_data(rpc_client, proposal_address).await?;
+    if !data.starts_with(Proposal::SPL_DISCRIMINATOR_SLICE) {
+        return Err(PaladinGovernanceClientError::InvalidAccountData(
+            *proposal_address,
+        ));
+    }
+    match data.len() {
+        len if len == std::mem::size_of::<ProposalV0>() => {
+            let v0 = decode_pod::<ProposalV0>(proposal_address, &data)?;
+            Ok(Proposal::from_v0(&v0, 0))
+        }
+        len if len == std::mem::size_of::<ProposalV1>() => {
+            let v1 = decode_pod::<ProposalV1>(proposal_address, &data)?;
+            Ok(Proposal::from_v1(&v1))
+        }
+        _ => decode_account(proposal_address, &data),
+    }
+}
+
+/// Fetch and decode a proposal transaction account.
+///
+/// Proposal transactions which have not yet been migrated to the current
+/// version are converted, as `MigrateAccount` would.
+pub async fn fetch_proposal_transaction(
+    rpc_client: &RpcClient,
+    proposal_transaction_address: &Pubkey,
+) -> Result<ProposalTransaction, PaladinGovernanceClientError> {
+    let data = fetch_account_data(rpc_client, proposal_transaction_address).await?;
+    let proposal_transaction = if !ProposalTransaction::is_legacy_layout(&data) {
+        deserialize_account(&data).ok()
+    } else if data.starts_with(ProposalTransaction::SPL_DISCRIMINATOR_SLICE) {
+        ProposalTransactionV1::try_from_slice(&data)
+            .ok()
+            .map(ProposalTransaction::from_v1)
+    } else {
+        ProposalTransactionV0::try_from_slice(&data)
+            .ok()
+            .map(ProposalTransaction::from_v0)
+    };
+    proposal_transaction.ok_or(PaladinGovernanceClientError::InvalidAccountData(
+        *proposal_transaction_address,
+    ))
+}
+
+/// Fetch and decode a stake account's vote on a proposal, deriving the
+/// proposal vote address.
+///
+/// Votes which have not yet been migrated to the current version are
+/// converted, as `MigrateAccount` would. Votes migrated from the unversioned
+/// layout carry no vote timestamp.
+pub async fn fetch_proposal_vote(
+    rpc_client: &RpcClient,
+    stake_address: &Pubkey,
+    proposal_address: &Pubkey,
+) -> Result<ProposalVote, PaladinGovernanceClientError> {
+    let proposal_vote_address = get_proposal_vote_address(stake_address, proposal_address);
+    let data = fetch_account_data(rpc_client, &proposal_vote_address).await?;
+    match data.len() {
+        len if len == std::mem::size_of::<ProposalVoteV0>() => {
+            let v0 = decode_pod::<ProposalVoteV0>(&proposal_vote_address, &data)?;
+            Ok(ProposalVote::from_v0(&v0, stake_address))
+        }
+        len if len == std::mem::size_of::<ProposalVoteV1>() => {
+            let v1 = decode_pod::<ProposalVoteV1>(&proposal_vote_address, &data)?;
+            Ok(ProposalVote::from_v1(&v1))
+        }
+        len if len == std::mem::size_of::<ProposalVoteV2>() => {
+            let v2 = decode_pod::<ProposalVoteV2>(&proposal_vote_address, &data)?;
+            Ok(ProposalVote::from_v2(&v2))
+        }
+        _ => decode_account(&proposal_vote_address, &data),
+    }
+}
+
+/// Fetch and decode a vote delegation account.
+pub async fn fetch_vote_delegation(
+    rpc_client: &RpcClient,
+    vote_delegation_address: &Pubkey,
+) -> Result<VoteDelegation, PaladinGovernanceClientError> {
+    let data = fetch_account_data(rpc_client, vote_delegation_address).await?;
+    decode_account(vote_delegation_address, &data)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        base64::{prelude::BASE64_STANDARD, Engine},
+        bytemuck::Zeroable,
+        paladin_governance_program::state::{ProposalInstruction, ProposalVoteElection},
+        serde_json::json,
+        solana_client::{mock_sender::Mocks, rpc_request::RpcRequest},
+        spl_discriminator::SplDiscriminate,
+        std::num::NonZeroU64,
+    };
+
+    // Mock an RPC client serving the given account, or no account, for every
+    // account request.
+    fn mock_rpc_client(owner: &Pubkey, data: Option<&[u8]>) -> RpcClient {
+        let value = data.map(|data| {
+            json!({
+                "lamports": 1_000_000,
+                "data": [BASE64_STANDARD.encode(data), "base64"],
+                "owner": owner.to_string(),
+                "executable": false,
+                "rentEpoch": 0,
+                "space": data.len(),
+            })
+        });
+        let mut mocks = Mocks::default();
+        mocks.insert(
+            RpcRequest::GetAccountInfo,
+            json!({ "context": { "slot": 1 }, "value": value }),
+        );
+        RpcClient::new_mock_with_mocks("succeeds".to_string(), mocks)
+    }
+
+    #[tokio::test]
+    async fn test_fetch_account_not_found() {
+        let address = Pubkey::new_unique();
+        let rpc_client = mock_rpc_client(&crate::id(), None);
+
+        assert!(matches!(
+            fetch_account(&rpc_client, &address).await,
+            Err(PaladinGovernanceClientError::AccountNotFound(a)) if a == address
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_account_invalid_owner() {
+        let address = Pubkey::new_unique();
+        let rpc_client = mock_rpc_client(&Pubkey::new_unique(), Some(&[0; 8]));
+
+        assert!(matches!(
+            fetch_account(&rpc_client, &address).await,
+            Err(PaladinGovernanceClientError::InvalidAccountOwner(a)) if a == address
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_governance_config() {
+        let address = Pubkey::new_unique();
+        let config = Config::new(
+            100,
+            200,
+            500_000_000,
+            1_000,
+            500_000_000,
+            0,
+            255,
+            &Pubkey::new_unique(),
+            None,
+            300,
+        );
+        let rpc_client = mock_rpc_client(&crate::id(), Some(bytemuck::bytes_of(&config)));
+
+        assert_eq!(
+            fetch_governance_config(&rpc_client, &address)
+                .await
+                .unwrap(),
+            config
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_governance_config_incorrect_account_type() {
+        let address = Pubkey::new_unique();
+        let vote_delegation = VoteDelegation::new(
+            &Pubkey::new_unique(),
+            None,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+        );
+        let rpc_client = mock_rpc_client(&crate::id(), Some(bytemuck::bytes_of(&vote_delegation)));
+
+        assert!(matches!(
+            fetch_governance_config(&rpc_client, &address).await,
+            Err(PaladinGovernanceClientError::InvalidAccountData(a)) if a == address
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_governance_config_v0() {
+        let address = Pubkey::new_unique();
+        let stake_config = Pubkey::new_unique();
+        let mut v0 = ConfigV0::zeroed();
+        v0.cooldown_period_seconds = 100;
+        v0.proposal_acceptance_threshold = 500_000_000;
+        v0.proposal_rejection_threshold = 500_000_000;
+        v0.signer_bump_seed = 255;
+        v0.stake_config_address = stake_config;
+        v0.voting_period_seconds = 300;
+        let rpc_client = mock_rpc_client(&crate::id(), Some(bytemuck::bytes_of(&v0)));
+
+        let config = fetch_governance_config(&rpc_client, &address)
+            .await
+            .unwrap();
+        assert_eq!(config, Config::from_v0(&v0));
+        assert_eq!(config.version, Config::VERSION);
+        assert_eq!(config.stake_config_address, stake_config);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_governance_config_v1() {
+        let address = Pubkey::new_unique();
+        let mut v1 = ConfigV1::zeroed();
+        v1.discriminator = Config::SPL_DISCRIMINATOR.into();
+        v1.cooldown_period_seconds = 100;
+        v1.proposal_acceptance_threshold = 500_000_000;
+        v1.proposal_rejection_threshold = 500_000_000;
+        v1.signer_bump_seed = 255;
+        v1.version = 1;
+        v1.stake_config_address = Pubkey::new_unique();
+        v1.voting_period_seconds = 300;
+        v1.proposal_count = 7;
+        v1.execution_window_seconds = 200;
+        let rpc_client = mock_rpc_client(&crate::id(), Some(bytemuck::bytes_of(&v1)));
+
+        let config = fetch_governance_config(&rpc_client, &address)
+            .await
+            .unwrap();
+        assert_eq!(config, Config::from_v1(&v1));
+        assert_eq!(config.version, Config::VERSION);
+        assert_eq!(config.proposal_count, 7);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_proposal_v0() {
+        let address = Pubkey::new_unique();
+        let author = Pubkey::new_unique();
+        let mut v0 = ProposalV0::zeroed();
+        v0.discriminator = Proposal::SPL_DISCRIMINATOR.into();
+        v0.author = author;
+        v0.creation_timestamp = 10;
+        v0.governance_config.cooldown_period_seconds = 100;
+        v0.governance_config.voting_period_seconds = 300;
+        v0.stake_for = 1_000;
+        v0.voting_start_timestamp = NonZeroU64::new(20);
+        let rpc_client = mock_rpc_client(&crate::id(), Some(bytemuck::bytes_of(&v0)));
+
+        let proposal = fetch_proposal(&rpc_client, &address).await.unwrap();
+        assert_eq!(proposal, Proposal::from_v0(&v0, 0));
+        assert_eq!(proposal.version, Proposal::VERSION);
+        assert_eq!(proposal.author, author);
+        assert_eq!(proposal.stake_for, 1_000);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_proposal_v1() {
+        let address = Pubkey::new_unique();
+        let author = Pubkey::new_unique();
+        let mut v1 = ProposalV1::zeroed();
+        v1.discriminator = Proposal::SPL_DISCRIMINATOR.into();
+        v1.author = author;
+        v1.creation_timestamp = 10;
+        v1.governance_config.discriminator = Config::SPL_DISCRIMINATOR.into();
+        v1.governance_config.version = 1;
+        v1.governance_config.voting_period_seconds = 300;
+        v1.stake_for = 1_000;
+        v1.version = 1;
+        v1.total_stake = 5_000;
+        v1.voting_start_timestamp = NonZeroU64::new(20);
+        let rpc_client = mock_rpc_client(&crate::id(), Some(bytemuck::bytes_of(&v1)));
+
+        let proposal = fetch_proposal(&rpc_client, &address).await.unwrap();
+        assert_eq!(proposal, Proposal::from_v1(&v1));
+        assert_eq!(proposal.version, Proposal::VERSION);
+        assert_eq!(proposal.total_stake, 5_000);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_proposal_incorrect_account_type() {
+        let address = Pubkey::new_unique();
+        let vote = ProposalVote::new(
+            &Pubkey::new_unique(),
+            1_000,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            None,
+            ProposalVoteElection::For,
+        );
+        let rpc_client = mock_rpc_client(&crate::id(), Some(bytemuck::bytes_of(&vote)));
+
+        assert!(matches!(
+            fetch_proposal(&rpc_client, &address).await,
+            Err(PaladinGovernanceClientError::InvalidAccountData(a)) if a == address
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_proposal_transaction() {
+        let address = Pubkey::new_unique();
+        let proposal_transaction = ProposalTransaction::new(vec![ProposalInstruction::new(
+            &Pubkey::new_unique(),
+            vec![],
+            vec![1, 2, 3],
+        )]);
+        let data = borsh::to_vec(&proposal_transaction).unwrap();
+        let rpc_client = mock_rpc_client(&crate::id(), Some(&data));
+
+        assert_eq!(
+            fetch_proposal_transaction(&rpc_client, &address)
+                .await
+                .unwrap(),
+            proposal_transaction
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_proposal_transaction_v0() {
+        let address = Pubkey::new_unique();
+        let instructions = vec![ProposalInstruction::new(
+            &Pubkey::new_unique(),
+            vec![],
+            vec![1, 2, 3],
+        )];
+        let data = borsh::to_vec(&ProposalTransactionV0 {
+            instructions: instructions.clone(),
+        })
+        .unwrap();
+        let rpc_client = mock_rpc_client(&crate::id(), Some(&data));
+
+        assert_eq!(
+            fetch_proposal_transaction(&rpc_client, &address)
+                .await
+                .unwrap(),
+            ProposalTransaction::new(instructions)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_proposal_transaction_v1() {
+        let address = Pubkey::new_unique();
+        let instructions = vec![ProposalInstruction::new(
+            &Pubkey::new_unique(),
+            vec![],
+            vec![1, 2, 3],
+        )];
+        let data = borsh::to_vec(&ProposalTransactionV1 {
+            discriminator: ProposalTransaction::SPL_DISCRIMINATOR.into(),
+            version: 1,
+            instructions: instructions.clone(),
+        })
+        .unwrap();
+        let rpc_client = mock_rpc_client(&crate::id(), Some(&data));
+
+        assert_eq!(
+            fetch_proposal_transaction(&rpc_client, &address)
+                .await
+                .unwrap(),
+            ProposalTransaction::new(instructions)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_vote_delegation() {
+        let address = Pubkey::new_unique();
+        let vote_delegation = VoteDelegation::new(
+            &Pubkey::new_unique(),
+            NonZeroU64::new(1_000),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+        );
+        let rpc_client = mock_rpc_client(&crate::id(), Some(bytemuck::bytes_of(&vote_delegation)));
+
+        assert_eq!(
+            fetch_vote_delegation(&rpc_client, &address).await.unwrap(),
+            vote_delegation
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_proposal_vote() {
+        let stake = Pubkey::new_unique();
+        let proposal = Pubkey::new_unique();
+        let proposal_vote = ProposalVote::new(
+            &proposal,
+            1_000,
+            &stake,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            NonZeroU64::new(10),
+            ProposalVoteElection::For,
+        );
+        let rpc_client = mock_rpc_client(&crate::id(), Some(bytemuck::bytes_of(&proposal_vote)));
+
+        assert_eq!(
+            fetch_proposal_vote(&rpc_client, &stake, &proposal)
+                .await
+                .unwrap(),
+            proposal_vote
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_proposal_vote_v0() {
+        let stake = Pubkey::new_unique();
+        let proposal = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let v0 = ProposalVoteV0::new(&proposal, 1_000, &authority, ProposalVoteElection::Against);
+        let rpc_client = mock_rpc_client(&crate::id(), Some(bytemuck::bytes_of(&v0)));
+
+        assert_eq!(
+            fetch_proposal_vote(&rpc_client, &stake, &proposal)
+                .await
+                .unwrap(),
+            ProposalVote::new(
+                &proposal,
+                1_000,
+                &stake,
+                &authority,
+                &authority,
+                None,
+                ProposalVoteElection::Against,
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_proposal_vote_v1() {
+        let stake = Pubkey::new_unique();
+        let proposal = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let mut v1 = ProposalVoteV1::zeroed();
+        v1.proposal_address = proposal;
+        v1.stake = 1_000;
+        v1.stake_address = stake;
+        v1.authority_address = authority;
+        v1.vote_timestamp = NonZeroU64::new(10);
+        v1.election = ProposalVoteElection::For;
+        v1.version = 1;
+        let rpc_client = mock_rpc_client(&crate::id(), Some(bytemuck::bytes_of(&v1)));
+
+        assert_eq!(
+            fetch_proposal_vote(&rpc_client, &stake, &proposal)
+                .await
+                .unwrap(),
+            ProposalVote::new(
+                &proposal,
+                1_000,
+                &stake,
+                &authority,
+                &authority,
+                NonZeroU64::new(10),
+                ProposalVoteElection::For,
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_proposal_vote_v2() {
+        let stake = Pubkey::new_unique();
+        let proposal = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let mut v2 = ProposalVoteV2::zeroed();
+        v2.discriminator = ProposalVote::SPL_DISCRIMINATOR.into();
+        v2.proposal_address = proposal;
+        v2.stake = 1_000;
+        v2.stake_address = stake;
+        v2.authority_address = authority;
+        v2.vote_timestamp = NonZeroU64::new(10);
+        v2.election = ProposalVoteElection::Against;
+        v2.version = 2;
+        let rpc_client = mock_rpc_client(&crate::id(), Some(bytemuck::bytes_of(&v2)));
+
+        assert_eq!(
+            fetch_proposal_vote(&rpc_client, &stake, &proposal)
+                .await
+                .unwrap(),
+            ProposalVote::new(
+                &proposal,
+                1_000,
+                &stake,
+                &authority,
+                &authority,
+                NonZeroU64::new(10),
+                ProposalVoteElection::Against,
+            )
+        );
+    }
+}
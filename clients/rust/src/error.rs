@@ -0,0 +1,32 @@
+//! Client error types.
+
+use {solana_client::client_error::ClientError, solana_sdk::pubkey::Pubkey, thiserror::Error};
+
+/// Errors that may be returned by the Paladin Governance client.
+#[derive(Debug, Error)]
+pub enum PaladinGovernanceClientError {
+    /// RPC request failed.
+    #[error("RPC request failed: {0}")]
+    Rpc(#[from] ClientError),
+    /// Account not found.
+    #[error("Account {0} not found.")]
+    AccountNotFound(Pubkey),
+    /// Account is not owned by the Paladin Governance program.
+    #[error("Account {0} is not owned by the Paladin Governance program.")]
+    InvalidAccountOwner(Pubkey),
+    /// Account data could not be decoded.
+    #[error("Account {0} data could not be decoded.")]
+    InvalidAccountData(Pubkey),
+    /// Instruction could not be serialized.
+    #[error("Instruction could not be serialized: {0}")]
+    InstructionSerialization(#[from] std::io::Error),
+    /// Instruction chunk length must be non-zero.
+    #[error("Instruction chunk length must be greater than zero.")]
+    InvalidChunkLength,
+    /// Proposal transaction has no instruction at the given index.
+    #[error("Proposal transaction has no instruction at index {0}.")]
+    InstructionNotFound(u32),
+    /// Serialized instruction exceeds the instruction slot's maximum length.
+    #[error("Serialized instruction of {0} bytes exceeds the instruction slot's maximum length.")]
+    InstructionTooLong(usize),
+}
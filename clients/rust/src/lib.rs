@@ -0,0 +1,11 @@
+//! Rust client for the Paladin Governance program.
+//!
+//! Wraps the program's instruction builders and account state with address
+//! derivation, RPC account fetchers and complete transaction builders.
+
+pub mod accounts;
+pub mod error;
+pub mod pda;
+pub mod transactions;
+
+pub use paladin_governance_program::{id, instruction, state, ID};
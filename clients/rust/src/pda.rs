@@ -0,0 +1,37 @@
+//! Program-derived addresses for Paladin Governance accounts.
+
+use {paladin_governance_program::state, solana_sdk::pubkey::Pubkey};
+
+/// Derive the address of the treasury for a Paladin stake config.
+pub fn get_treasury_address(stake_config_address: &Pubkey) -> Pubkey {
+    state::get_treasury_address(stake_config_address, &crate::id())
+}
+
+/// Derive the address of the governance config for a Paladin stake config.
+pub fn get_governance_address(stake_config_address: &Pubkey) -> Pubkey {
+    state::get_governance_address(stake_config_address, &crate::id())
+}
+
+/// Derive the address of a governance config's proposal by index.
+pub fn get_proposal_address(governance_config_address: &Pubkey, proposal_index: u64) -> Pubkey {
+    state::get_proposal_address(governance_config_address, proposal_index, &crate::id())
+}
+
+/// Derive the address of a proposal's transaction account.
+pub fn get_proposal_transaction_address(proposal_address: &Pubkey) -> Pubkey {
+    state::get_proposal_transaction_address(proposal_address, &crate::id())
+}
+
+/// Derive the address of a stake account's vote on a proposal.
+pub fn get_proposal_vote_address(stake_address: &Pubkey, proposal_address: &Pubkey) -> Pubkey {
+    state::get_proposal_vote_address(stake_address, proposal_address, &crate::id())
+}
+
+/// Derive the address of a stake account's vote delegation for a governance
+/// config.
+pub fn get_vote_delegation_address(
+    stake_address: &Pubkey,
+    governance_config_address: &Pubkey,
+) -> Pubkey {
+    state::get_vote_delegation_address(stake_address, governance_config_address, &crate::id())
+}
@@ -0,0 +1,931 @@
+//! Complete transaction builders for the Paladin Governance proposal
+//! lifecycle: creating and writing proposals, voting, and processing,
+//! finalizing and closing them.
+//!
+//! The program funds the accounts it creates, and any growth of the proposal
+//! transaction account, from the payer. The payer signs each transaction as
+//! its fee payer.
+//!
+//! Instructions outside the proposal lifecycle, such as governance and
+//! treasury administration, are built with the program's
+//! [instruction](../instruction/index.html) builders.
+
+use {
+    crate::{
+        accounts::{
+            decode_account, fetch_account, fetch_governance_config, fetch_proposal_transaction,
+            fetch_proposal_vote,
+        },
+        error::PaladinGovernanceClientError,
+        pda::{
+            get_proposal_address, get_proposal_transaction_address, get_proposal_vote_address,
+            get_vote_delegation_address,
+        },
+    },
+    paladin_governance_program::{
+        instruction::{
+            begin_voting, cancel_proposal, close_proposal, close_proposal_transaction,
+            close_proposal_vote, create_instruction_slot, create_proposal,
+            finalize_instruction_slot, finalize_proposal, process_instruction, push_instruction,
+            switch_vote, switch_vote_as_delegate, vote, vote_as_delegate, withdraw_vote,
+            withdraw_vote_as_delegate, write_instruction_slot,
+        },
+        state::{
+            ProposalAccountMeta, ProposalInstruction, ProposalVote, ProposalVoteElection,
+            MAX_INSTRUCTION_SLOT_LEN,
+        },
+    },
+    solana_client::nonblocking::rpc_client::RpcClient,
+    solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        signer::Signer,
+        transaction::Transaction,
+    },
+};
+
+// Sign a transaction of the given instructions with the payer as its fee
+// payer, alongside any other required signers.
+async fn new_signed_transaction(
+    rpc_client: &RpcClient,
+    payer: &dyn Signer,
+    signers: &[&dyn Signer],
+    instructions: &[Instruction],
+) -> Result<Transaction, PaladinGovernanceClientError> {
+    let mut all_signers = vec![payer];
+    all_signers.extend_from_slice(signers);
+
+    let recent_blockhash = rpc_client.get_latest_blockhash().await?;
+    Ok(Transaction::new_signed_with_payer(
+        instructions,
+        Some(&payer.pubkey()),
+        &all_signers,
+        recent_blockhash,
+    ))
+}
+
+/// Instruction to create a governance config's proposal at the given index,
+/// deriving the proposal and proposal transaction addresses.
+#[allow(clippy::too_many_arguments)]
+pub fn create_proposal_instruction(
+    payer_address: &Pubkey,
+    stake_authority_address: &Pubkey,
+    stake_address: &Pubkey,
+    governance_config_address: &Pubkey,
+    proposal_index: u64,
+    title: String,
+    uri: String,
+    content_hash: [u8; 32],
+) -> Instruction {
+    let proposal_address = get_proposal_address(governance_config_address, proposal_index);
+    let proposal_transaction_address = get_proposal_transaction_address(&proposal_address);
+
+    create_proposal(
+        stake_authority_address,
+        stake_address,
+        &proposal_address,
+        &proposal_transaction_address,
+        governance_config_address,
+        payer_address,
+        title,
+        uri,
+        content_hash,
+    )
+}
+
+/// Build a transaction creating the governance config's next proposal,
+/// returning the new proposal's address alongside the signed transaction.
+#[allow(clippy::too_many_arguments)]
+pub async fn create_proposal_transaction(
+    rpc_client: &RpcClient,
+    payer: &dyn Signer,
+    stake_authority: &dyn Signer,
+    stake_address: &Pubkey,
+    governance_config_address: &Pubkey,
+    title: String,
+    uri: String,
+    content_hash: [u8; 32],
+) -> Result<(Pubkey, Transaction), PaladinGovernanceClientError> {
+    let governance_config = fetch_governance_config(rpc_client, governance_config_address).await?;
+    let proposal_index = governance_config.proposal_count;
+
+    let instruction = create_proposal_instruction(
+        &payer.pubkey(),
+        &stake_authority.pubkey(),
+        stake_address,
+        governance_config_address,
+        proposal_index,
+        title,
+        uri,
+        content_hash,
+    );
+
+    let transaction =
+        new_signed_transaction(rpc_client, payer, &[stake_authority], &[instruction]).await?;
+
+    Ok((
+        get_proposal_address(governance_config_address, proposal_index),
+        transaction,
+    ))
+}
+
+/// Instruction to push an instruction onto a proposal's transaction,
+/// deriving the proposal transaction address.
+pub fn push_proposal_instruction(
+    payer_address: &Pubkey,
+    stake_authority_address: &Pubkey,
+    proposal_address: &Pubkey,
+    instruction: &Instruction,
+) -> Instruction {
+    push_instruction(
+        stake_authority_address,
+        proposal_address,
+        &get_proposal_transaction_address(proposal_address),
+        payer_address,
+        &instruction.program_id,
+        instruction
+            .accounts
+            .iter()
+            .map(ProposalAccountMeta::from)
+            .collect(),
+        instruction.data.clone(),
+    )
+}
+
+/// Build a transaction pushing an instruction onto a proposal's transaction.
+pub async fn push_instruction_transaction(
+    rpc_client: &RpcClient,
+    payer: &dyn Signer,
+    stake_authority: &dyn Signer,
+    proposal_address: &Pubkey,
+    instruction: &Instruction,
+) -> Result<Transaction, PaladinGovernanceClientError> {
+    let instruction = push_proposal_instruction(
+        &payer.pubkey(),
+        &stake_authority.pubkey(),
+        proposal_address,
+        instruction,
+    );
+
+    new_signed_transaction(rpc_client, payer, &[stake_authority], &[instruction]).await
+}
+
+/// Instructions to write an instruction onto a proposal's transaction
+/// through its instruction slot, in chunks of at most the given length,
+/// deriving the proposal transaction address.
+///
+/// For instructions too large to push in a single transaction. Each returned
+/// instruction is meant to be sent, in order, in its own transaction. The
+/// chunk length must be non-zero, and the serialized instruction may not
+/// exceed the instruction slot's maximum length.
+pub fn write_proposal_instruction_instructions(
+    payer_address: &Pubkey,
+    stake_authority_address: &Pubkey,
+    proposal_address: &Pubkey,
+    instruction: &Instruction,
+    chunk_len: usize,
+) -> Result<Vec<Instruction>, PaladinGovernanceClientError> {
+    if chunk_len == 0 {
+        return Err(PaladinGovernanceClientError::InvalidChunkLength);
+    }
+
+    let proposal_transaction_address = get_proposal_transaction_address(proposal_address);
+    let data = borsh::to_vec(&ProposalInstruction::from(instruction))?;
+    if data.len() > MAX_INSTRUCTION_SLOT_LEN as usize {
+        return Err(PaladinGovernanceClientError::InstructionTooLong(data.len()));
+    }
+
+    let mut instructions = vec![create_instruction_slot(
+        stake_authority_address,
+        proposal_address,
+        &proposal_transaction_address,
+        payer_address,
+        data.len() as u32,
+    )];
+    for (index, chunk) in data.chunks(chunk_len).enumerate() {
+        instructions.push(write_instruction_slot(
+            stake_authority_address,
+            proposal_address,
+            &proposal_transaction_address,
+            payer_address,
+            (index * chunk_len) as u32,
+            chunk.to_vec(),
+        ));
+    }
+    instructions.push(finalize_instruction_slot(
+        stake_authority_address,
+        proposal_address,
+        &proposal_transaction_address,
+    ));
+    Ok(instructions)
+}
+
+/// Build a transaction beginning voting on a proposal.
+pub async fn begin_voting_transaction(
+    rpc_client: &RpcClient,
+    payer: &dyn Signer,
+    stake_authority: &dyn Signer,
+    stake_address: &Pubkey,
+    stake_config_address: &Pubkey,
+    proposal_address: &Pubkey,
+) -> Result<Transaction, PaladinGovernanceClientError> {
+    let instruction = begin_voting(
+        &stake_authority.pubkey(),
+        proposal_address,
+        stake_config_address,
+        stake_address,
+        &get_proposal_transaction_address(proposal_address),
+    );
+
+    new_signed_transaction(rpc_client, payer, &[stake_authority], &[instruction]).await
+}
+
+/// Build a transaction cancelling a proposal.
+pub async fn cancel_proposal_transaction(
+    rpc_client: &RpcClient,
+    payer: &dyn Signer,
+    stake_authority: &dyn Signer,
+    proposal_address: &Pubkey,
+) -> Result<Transaction, PaladinGovernanceClientError> {
+    let instruction = cancel_proposal(&stake_authority.pubkey(), proposal_address);
+
+    new_signed_transaction(rpc_client, payer, &[stake_authority], &[instruction]).await
+}
+
+/// Instruction to vote on a proposal, deriving the proposal vote address.
+///
+/// If a governance config address is provided, the vote is cast by the stake
+/// account's vote delegate for that governance config, rather than by its
+/// authority.
+pub fn vote_instruction(
+    payer_address: &Pubkey,
+    voter_address: &Pubkey,
+    stake_address: &Pubkey,
+    stake_config_address: &Pubkey,
+    proposal_address: &Pubkey,
+    delegated_governance_config_address: Option<&Pubkey>,
+    election: ProposalVoteElection,
+) -> Instruction {
+    let proposal_vote_address = get_proposal_vote_address(stake_address, proposal_address);
+
+    match delegated_governance_config_address {
+        Some(governance_config_address) => vote_as_delegate(
+            voter_address,
+            stake_address,
+            stake_config_address,
+            &proposal_vote_address,
+            proposal_address,
+            payer_address,
+            &get_vote_delegation_address(stake_address, governance_config_address),
+            election,
+        ),
+        None => vote(
+            voter_address,
+            stake_address,
+            stake_config_address,
+            &proposal_vote_address,
+            proposal_address,
+            payer_address,
+            election,
+        ),
+    }
+}
+
+/// Build a transaction voting on a proposal.
+///
+/// If a governance config address is provided, the vote is cast by the stake
+/// account's vote delegate for that governance config, rather than by its
+/// authority.
+#[allow(clippy::too_many_arguments)]
+pub async fn vote_transaction(
+    rpc_client: &RpcClient,
+    payer: &dyn Signer,
+    voter: &dyn Signer,
+    stake_address: &Pubkey,
+    stake_config_address: &Pubkey,
+    proposal_address: &Pubkey,
+    delegated_governance_config_address: Option<&Pubkey>,
+    election: ProposalVoteElection,
+) -> Result<Transaction, PaladinGovernanceClientError> {
+    let instruction = vote_instruction(
+        &payer.pubkey(),
+        &voter.pubkey(),
+        stake_address,
+        stake_config_address,
+        proposal_address,
+        delegated_governance_config_address,
+        election,
+    );
+
+    new_signed_transaction(rpc_client, payer, &[voter], &[instruction]).await
+}
+
+/// Instruction to switch a vote on a proposal, deriving the proposal vote
+/// address.
+///
+/// If a governance config address is provided, the vote is switched by the
+/// stake account's vote delegate for that governance config, rather than by
+/// its authority.
+pub fn switch_vote_instruction(
+    voter_address: &Pubkey,
+    stake_address: &Pubkey,
+    stake_config_address: &Pubkey,
+    proposal_address: &Pubkey,
+    delegated_governance_config_address: Option<&Pubkey>,
+    new_election: ProposalVoteElection,
+) -> Instruction {
+    let proposal_vote_address = get_proposal_vote_address(stake_address, proposal_address);
+
+    match delegated_governance_config_address {
+        Some(governance_config_address) => switch_vote_as_delegate(
+            voter_address,
+            stake_address,
+            stake_config_address,
+            &proposal_vote_address,
+            proposal_address,
+            &get_vote_delegation_address(stake_address, governance_config_address),
+            new_election,
+        ),
+        None => switch_vote(
+            voter_address,
+            stake_address,
+            stake_config_address,
+            &proposal_vote_address,
+            proposal_address,
+            new_election,
+        ),
+    }
+}
+
+/// Build a transaction switching a vote on a proposal.
+///
+/// If a governance config address is provided, the vote is switched by the
+/// stake account's vote delegate for that governance config, rather than by
+/// its authority.
+#[allow(clippy::too_many_arguments)]
+pub async fn switch_vote_transaction(
+    rpc_client: &RpcClient,
+    payer: &dyn Signer,
+    voter: &dyn Signer,
+    stake_address: &Pubkey,
+    stake_config_address: &Pubkey,
+    proposal_address: &Pubkey,
+    delegated_governance_config_address: Option<&Pubkey>,
+    new_election: ProposalVoteElection,
+) -> Result<Transaction, PaladinGovernanceClientError> {
+    let instruction = switch_vote_instruction(
+        &voter.pubkey(),
+        stake_address,
+        stake_config_address,
+        proposal_address,
+        delegated_governance_config_address,
+        new_election,
+    );
+
+    new_signed_transaction(rpc_client, payer, &[voter], &[instruction]).await
+}
+
+/// Instruction to withdraw a vote on a proposal, deriving the proposal vote
+/// address. The vote's rent is returned to the payer that funded it.
+///
+/// If a governance config address is provided, the vote is withdrawn by the
+/// stake account's vote delegate for that governance config, rather than by
+/// its authority.
+pub fn withdraw_vote_instruction(
+    voter_address: &Pubkey,
+    stake_address: &Pubkey,
+    stake_config_address: &Pubkey,
+    proposal_address: &Pubkey,
+    proposal_vote_payer_address: &Pubkey,
+    delegated_governance_config_address: Option<&Pubkey>,
+) -> Instruction {
+    let proposal_vote_address = get_proposal_vote_address(stake_address, proposal_address);
+
+    match delegated_governance_config_address {
+        Some(governance_config_address) => withdraw_vote_as_delegate(
+            voter_address,
+            stake_address,
+            stake_config_address,
+            &proposal_vote_address,
+            proposal_address,
+            proposal_vote_payer_address,
+            &get_vote_delegation_address(stake_address, governance_config_address),
+        ),
+        None => withdraw_vote(
+            voter_address,
+            stake_address,
+            stake_config_address,
+            &proposal_vote_address,
+            proposal_address,
+            proposal_vote_payer_address,
+        ),
+    }
+}
+
+/// Build a transaction withdrawing a vote on a proposal, fetching the vote
+/// to find the payer its rent is returned to.
+///
+/// If a governance config address is provided, the vote is withdrawn by the
+/// stake account's vote delegate for that governance config, rather than by
+/// its authority.
+pub async fn withdraw_vote_transaction(
+    rpc_client: &RpcClient,
+    payer: &dyn Signer,
+    voter: &dyn Signer,
+    stake_address: &Pubkey,
+    stake_config_address: &Pubkey,
+    proposal_address: &Pubkey,
+    delegated_governance_config_address: Option<&Pubkey>,
+) -> Result<Transaction, PaladinGovernanceClientError> {
+    let proposal_vote = fetch_proposal_vote(rpc_client, stake_address, proposal_address).await?;
+
+    let instruction = withdraw_vote_instruction(
+        &voter.pubkey(),
+        stake_address,
+        stake_config_address,
+        proposal_address,
+        &proposal_vote.payer_address,
+        delegated_governance_config_address,
+    );
+
+    new_signed_transaction(rpc_client, payer, &[voter], &[instruction]).await
+}
+
+/// Instruction to process one of a proposal's instructions, deriving the
+/// proposal transaction address.
+///
+/// The instruction's accounts are passed through unsigned, since any PDAs it
+/// requires are signed for by the program, followed by its program.
+pub fn process_proposal_instruction(
+    proposal_address: &Pubkey,
+    instruction_index: u32,
+    instruction: &ProposalInstruction,
+) -> Instruction {
+    let mut account_metas = instruction
+        .accounts
+        .iter()
+        .map(|meta| AccountMeta {
+            pubkey: meta.pubkey,
+            is_signer: false,
+            is_writable: meta.is_writable,
+        })
+        .collect::<Vec<_>>();
+    account_metas.push(AccountMeta::new_readonly(instruction.program_id, false));
+
+    process_instruction(
+        proposal_address,
+        &get_proposal_transaction_address(proposal_address),
+        &account_metas,
+        instruction_index,
+    )
+}
+
+/// Build a transaction processing one of a proposal's instructions, fetching
+/// the proposal transaction for the instruction's accounts.
+pub async fn process_instruction_transaction(
+    rpc_client: &RpcClient,
+    payer: &dyn Signer,
+    proposal_address: &Pubkey,
+    instruction_index: u32,
+) -> Result<Transaction, PaladinGovernanceClientError> {
+    let proposal_transaction_address = get_proposal_transaction_address(proposal_address);
+    let proposal_transaction =
+        fetch_proposal_transaction(rpc_client, &proposal_transaction_address).await?;
+    let instruction = proposal_transaction
+        .instructions
+        .get(instruction_index as usize)
+        .ok_or(PaladinGovernanceClientError::InstructionNotFound(
+            instruction_index,
+        ))?;
+
+    let instruction =
+        process_proposal_instruction(proposal_address, instruction_index, instruction);
+
+    new_signed_transaction(rpc_client, payer, &[], &[instruction]).await
+}
+
+/// Instruction to finalize a proposal, deriving the proposal transaction
+/// address.
+pub fn finalize_proposal_instruction(proposal_address: &Pubkey) -> Instruction {
+    finalize_proposal(
+        proposal_address,
+        &get_proposal_transaction_address(proposal_address),
+    )
+}
+
+/// Build a transaction finalizing a proposal.
+pub async fn finalize_proposal_transaction(
+    rpc_client: &RpcClient,
+    payer: &dyn Signer,
+    proposal_address: &Pubkey,
+) -> Result<Transaction, PaladinGovernanceClientError> {
+    let instruction = finalize_proposal_instruction(proposal_address);
+
+    new_signed_transaction(rpc_client, payer, &[], &[instruction]).await
+}
+
+/// Instructions to close a terminated proposal's transaction account and
+/// then the proposal itself, deriving the proposal transaction address.
+pub fn close_proposal_instructions(
+    stake_authority_address: &Pubkey,
+    proposal_address: &Pubkey,
+) -> [Instruction; 2] {
+    let proposal_transaction_address = get_proposal_transaction_address(proposal_address);
+
+    [
+        close_proposal_transaction(
+            stake_authority_address,
+            proposal_address,
+            &proposal_transaction_address,
+        ),
+        close_proposal(
+            stake_authority_address,
+            proposal_address,
+            &proposal_transaction_address,
+        ),
+    ]
+}
+
+/// Build a transaction closing a terminated proposal and its transaction
+/// account.
+pub async fn close_proposal_accounts_transaction(
+    rpc_client: &RpcClient,
+    payer: &dyn Signer,
+    stake_authority: &dyn Signer,
+    proposal_address: &Pubkey,
+) -> Result<Transaction, PaladinGovernanceClientError> {
+    let instructions = close_proposal_instructions(&stake_authority.pubkey(), proposal_address);
+
+    new_signed_transaction(rpc_client, payer, &[stake_authority], &instructions).await
+}
+
+/// Instruction to close a stake account's vote on a terminated proposal,
+/// deriving the proposal vote address. The vote's rent is returned to the
+/// payer that funded it.
+///
+/// The authority is the vote's payer or voting authority, or the stake
+/// authority for a vote not yet migrated to the current version.
+pub fn close_proposal_vote_instruction(
+    authority_address: &Pubkey,
+    stake_address: &Pubkey,
+    proposal_address: &Pubkey,
+    proposal_vote_payer_address: &Pubkey,
+) -> Instruction {
+    close_proposal_vote(
+        authority_address,
+        stake_address,
+        &get_proposal_vote_address(stake_address, proposal_address),
+        proposal_address,
+        proposal_vote_payer_address,
+    )
+}
+
+/// Build a transaction closing a stake account's vote on a terminated
+/// proposal, fetching the vote to find the payer its rent is returned to.
+///
+/// The authority must be the vote's payer or voting authority. Votes not yet
+/// migrated to the current version did not record their payer, so must be
+/// closed by the stake authority, to which their rent is returned.
+pub async fn close_proposal_vote_transaction(
+    rpc_client: &RpcClient,
+    payer: &dyn Signer,
+    authority: &dyn Signer,
+    stake_address: &Pubkey,
+    proposal_address: &Pubkey,
+) -> Result<Transaction, PaladinGovernanceClientError> {
+    let proposal_vote_address = get_proposal_vote_address(stake_address, proposal_address);
+    let account = fetch_account(rpc_client, &proposal_vote_address).await?;
+    let proposal_vote_payer_address = if account.data.len() == std::mem::size_of::<ProposalVote>() {
+        decode_account::<ProposalVote>(&proposal_vote_address, &account.data)?.payer_address
+    } else {
+        authority.pubkey()
+    };
+
+    let instruction = close_proposal_vote_instruction(
+        &authority.pubkey(),
+        stake_address,
+        proposal_address,
+        &proposal_vote_payer_address,
+    );
+
+    new_signed_transaction(rpc_client, payer, &[authority], &[instruction]).await
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*, paladin_governance_program::instruction::PaladinGovernanceInstruction,
+        solana_sdk::system_instruction,
+    };
+
+    #[test]
+    fn test_create_proposal_instruction() {
+        let payer = Pubkey::new_unique();
+        let stake_authority = Pubkey::new_unique();
+        let stake = Pubkey::new_unique();
+        let governance_config = Pubkey::new_unique();
+
+        let instruction = create_proposal_instruction(
+            &payer,
+            &stake_authority,
+            &stake,
+            &governance_config,
+            7,
+            "Title".to_string(),
+            "https://example.com/proposal.md".to_string(),
+            [1; 32],
+        );
+
+        let proposal = get_proposal_address(&governance_config, 7);
+        let proposal_transaction = get_proposal_transaction_address(&proposal);
+
+        assert_eq!(instruction.accounts[2].pubkey, proposal);
+        assert_eq!(instruction.accounts[3].pubkey, proposal_transaction);
+        assert_eq!(instruction.accounts[5].pubkey, payer);
+        assert!(instruction.accounts[5].is_signer);
+    }
+
+    #[test]
+    fn test_push_proposal_instruction() {
+        let payer = Pubkey::new_unique();
+        let stake_authority = Pubkey::new_unique();
+        let proposal = Pubkey::new_unique();
+
+        let instruction =
+            system_instruction::transfer(&Pubkey::new_unique(), &Pubkey::new_unique(), 1_000_000);
+
+        let push = push_proposal_instruction(&payer, &stake_authority, &proposal, &instruction);
+
+        assert_eq!(
+            push.accounts[2].pubkey,
+            get_proposal_transaction_address(&proposal)
+        );
+        assert_eq!(push.accounts[3].pubkey, payer);
+        assert_eq!(
+            PaladinGovernanceInstruction::unpack(&push.data).unwrap(),
+            PaladinGovernanceInstruction::PushInstruction {
+                instruction_program_id: instruction.program_id,
+                instruction_account_metas: ProposalInstruction::from(&instruction).accounts,
+                instruction_data: instruction.data.clone(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_write_proposal_instruction_instructions() {
+        let payer = Pubkey::new_unique();
+        let stake_authority = Pubkey::new_unique();
+        let proposal = Pubkey::new_unique();
+
+        let instruction = Instruction::new_with_bytes(
+            Pubkey::new_unique(),
+            &[9; 2_000],
+            vec![AccountMeta::new(Pubkey::new_unique(), false)],
+        );
+
+        let instructions = write_proposal_instruction_instructions(
+            &payer,
+            &stake_authority,
+            &proposal,
+            &instruction,
+            800,
+        )
+        .unwrap();
+
+        let data = borsh::to_vec(&ProposalInstruction::from(&instruction)).unwrap();
+        assert_eq!(instructions.len(), data.len().div_ceil(800) + 2);
+        assert_eq!(
+            PaladinGovernanceInstruction::unpack(&instructions[0].data).unwrap(),
+            PaladinGovernanceInstruction::CreateInstructionSlot {
+                instruction_len: data.len() as u32,
+            }
+        );
+        assert_eq!(
+            PaladinGovernanceInstruction::unpack(&instructions[instructions.len() - 1].data)
+                .unwrap(),
+            PaladinGovernanceInstruction::FinalizeInstructionSlot
+        );
+
+        // Assert the chunks reassemble the serialized instruction.
+        let mut written = Vec::new();
+        for write in &instructions[1..instructions.len() - 1] {
+            assert_eq!(
+                write.accounts[2].pubkey,
+                get_proposal_transaction_address(&proposal)
+            );
+            assert_eq!(write.accounts[3].pubkey, payer);
+            match PaladinGovernanceInstruction::unpack(&write.data).unwrap() {
+                PaladinGovernanceInstruction::WriteInstructionSlot { offset, data } => {
+                    assert_eq!(offset as usize, written.len());
+                    assert!(data.len() <= 800);
+                    written.extend_from_slice(&data);
+                }
+                _ => panic!("expected a WriteInstructionSlot instruction"),
+            }
+        }
+        assert_eq!(written, data);
+    }
+
+    #[test]
+    fn test_write_proposal_instruction_instructions_zero_chunk_len() {
+        let instruction = Instruction::new_with_bytes(Pubkey::new_unique(), &[9; 100], vec![]);
+
+        assert!(matches!(
+            write_proposal_instruction_instructions(
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &instruction,
+                0,
+            ),
+            Err(PaladinGovernanceClientError::InvalidChunkLength)
+        ));
+    }
+
+    #[test]
+    fn test_write_proposal_instruction_instructions_too_long() {
+        let instruction = Instruction::new_with_bytes(
+            Pubkey::new_unique(),
+            &vec![9; MAX_INSTRUCTION_SLOT_LEN as usize],
+            vec![],
+        );
+
+        assert!(matches!(
+            write_proposal_instruction_instructions(
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &instruction,
+                800,
+            ),
+            Err(PaladinGovernanceClientError::InstructionTooLong(_))
+        ));
+    }
+
+    #[test]
+    fn test_vote_instruction() {
+        let payer = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        let stake = Pubkey::new_unique();
+        let stake_config = Pubkey::new_unique();
+        let proposal = Pubkey::new_unique();
+        let governance_config = Pubkey::new_unique();
+
+        let instruction = vote_instruction(
+            &payer,
+            &delegate,
+            &stake,
+            &stake_config,
+            &proposal,
+            Some(&governance_config),
+            ProposalVoteElection::For,
+        );
+
+        assert_eq!(
+            instruction.accounts[3].pubkey,
+            get_proposal_vote_address(&stake, &proposal)
+        );
+        assert_eq!(instruction.accounts[5].pubkey, payer);
+        assert_eq!(
+            instruction.accounts[7].pubkey,
+            get_vote_delegation_address(&stake, &governance_config)
+        );
+    }
+
+    #[test]
+    fn test_switch_vote_instruction() {
+        let voter = Pubkey::new_unique();
+        let stake = Pubkey::new_unique();
+        let stake_config = Pubkey::new_unique();
+        let proposal = Pubkey::new_unique();
+
+        let instruction = switch_vote_instruction(
+            &voter,
+            &stake,
+            &stake_config,
+            &proposal,
+            None,
+            ProposalVoteElection::Against,
+        );
+
+        assert_eq!(
+            instruction.accounts[3].pubkey,
+            get_proposal_vote_address(&stake, &proposal)
+        );
+        assert_eq!(instruction.accounts.len(), 5);
+        assert_eq!(
+            PaladinGovernanceInstruction::unpack(&instruction.data).unwrap(),
+            PaladinGovernanceInstruction::SwitchVote {
+                new_election: ProposalVoteElection::Against,
+            }
+        );
+    }
+
+    #[test]
+    fn test_withdraw_vote_instruction() {
+        let delegate = Pubkey::new_unique();
+        let stake = Pubkey::new_unique();
+        let stake_config = Pubkey::new_unique();
+        let proposal = Pubkey::new_unique();
+        let proposal_vote_payer = Pubkey::new_unique();
+        let governance_config = Pubkey::new_unique();
+
+        let instruction = withdraw_vote_instruction(
+            &delegate,
+            &stake,
+            &stake_config,
+            &proposal,
+            &proposal_vote_payer,
+            Some(&governance_config),
+        );
+
+        assert_eq!(
+            instruction.accounts[3].pubkey,
+            get_proposal_vote_address(&stake, &proposal)
+        );
+        assert_eq!(instruction.accounts[5].pubkey, proposal_vote_payer);
+        assert_eq!(
+            instruction.accounts[6].pubkey,
+            get_vote_delegation_address(&stake, &governance_config)
+        );
+    }
+
+    #[test]
+    fn test_process_proposal_instruction() {
+        let proposal = Pubkey::new_unique();
+        let signer = Pubkey::new_unique();
+        let writable = Pubkey::new_unique();
+
+        let instruction = ProposalInstruction::from(&Instruction::new_with_bytes(
+            Pubkey::new_unique(),
+            &[1, 2, 3],
+            vec![
+                AccountMeta::new_readonly(signer, true),
+                AccountMeta::new(writable, false),
+            ],
+        ));
+
+        let process = process_proposal_instruction(&proposal, 2, &instruction);
+
+        assert_eq!(
+            process.accounts,
+            vec![
+                AccountMeta::new(proposal, false),
+                AccountMeta::new(get_proposal_transaction_address(&proposal), false),
+                AccountMeta::new_readonly(signer, false),
+                AccountMeta::new(writable, false),
+                AccountMeta::new_readonly(instruction.program_id, false),
+            ]
+        );
+        assert_eq!(
+            PaladinGovernanceInstruction::unpack(&process.data).unwrap(),
+            PaladinGovernanceInstruction::ProcessInstruction {
+                instruction_index: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_close_proposal_instructions() {
+        let stake_authority = Pubkey::new_unique();
+        let proposal = Pubkey::new_unique();
+
+        let [close_transaction, close] = close_proposal_instructions(&stake_authority, &proposal);
+
+        // The proposal transaction must be closed before the proposal.
+        assert_eq!(
+            PaladinGovernanceInstruction::unpack(&close_transaction.data).unwrap(),
+            PaladinGovernanceInstruction::CloseProposalTransaction
+        );
+        assert_eq!(
+            close_transaction.accounts[2].pubkey,
+            get_proposal_transaction_address(&proposal)
+        );
+        assert_eq!(
+            PaladinGovernanceInstruction::unpack(&close.data).unwrap(),
+            PaladinGovernanceInstruction::CloseProposal
+        );
+        assert_eq!(close.accounts[1].pubkey, proposal);
+    }
+
+    #[test]
+    fn test_close_proposal_vote_instruction() {
+        let stake_authority = Pubkey::new_unique();
+        let stake = Pubkey::new_unique();
+        let proposal = Pubkey::new_unique();
+        let proposal_vote_payer = Pubkey::new_unique();
+
+        let instruction = close_proposal_vote_instruction(
+            &stake_authority,
+            &stake,
+            &proposal,
+            &proposal_vote_payer,
+        );
+
+        assert_eq!(
+            instruction.accounts[2].pubkey,
+            get_proposal_vote_address(&stake, &proposal)
+        );
+        assert_eq!(instruction.accounts[4].pubkey, proposal_vote_payer);
+    }
+}
@@ -38,4 +38,97 @@ pub enum PaladinGovernanceError {
     /// Previous instruction has not been executed.
     #[error("Previous instruction has not been executed.")]
     PreviousInstructionHasNotBeenExecuted,
+    /// Proposal voting has not ended.
+    #[error("Proposal voting has not ended.")]
+    ProposalVotingNotEnded,
+    /// Proposal not in a terminal stage.
+    #[error("Proposal not in a terminal stage.")]
+    ProposalNotTerminal,
+    /// Proposal transaction not closed.
+    #[error("Proposal transaction not closed.")]
+    ProposalTransactionNotClosed,
+    /// Proposal title too long.
+    #[error("Proposal title too long.")]
+    ProposalTitleTooLong,
+    /// Proposal URI too long.
+    #[error("Proposal URI too long.")]
+    ProposalUriTooLong,
+    /// Incorrect proposal address.
+    #[error("Incorrect proposal address.")]
+    IncorrectProposalAddress,
+    /// Invalid proposal acceptance threshold.
+    #[error("Invalid proposal acceptance threshold.")]
+    InvalidProposalAcceptanceThreshold,
+    /// Invalid proposal rejection threshold.
+    #[error("Invalid proposal rejection threshold.")]
+    InvalidProposalRejectionThreshold,
+    /// Invalid cooldown period.
+    #[error("Invalid cooldown period.")]
+    InvalidCooldownPeriod,
+    /// Invalid voting period.
+    #[error("Invalid voting period.")]
+    InvalidVotingPeriod,
+    /// Incorrect program data address.
+    #[error("Incorrect program data address.")]
+    IncorrectProgramDataAddress,
+    /// Proposal minimum stake not met.
+    #[error("Proposal minimum stake not met.")]
+    ProposalMinimumStakeNotMet,
+    /// Invalid quorum threshold.
+    #[error("Invalid quorum threshold.")]
+    InvalidQuorumThreshold,
+    /// Invalid execution window.
+    #[error("Invalid execution window.")]
+    InvalidExecutionWindow,
+    /// Proposal execution window has ended.
+    #[error("Proposal execution window has ended.")]
+    ProposalExecutionWindowEnded,
+    /// Proposal execution window has not ended.
+    #[error("Proposal execution window has not ended.")]
+    ProposalExecutionWindowNotEnded,
+    /// Incorrect vote delegation address.
+    #[error("Incorrect vote delegation address.")]
+    IncorrectVoteDelegationAddress,
+    /// Vote delegation has expired.
+    #[error("Vote delegation has expired.")]
+    VoteDelegationExpired,
+    /// Invalid vote delegation expiry.
+    #[error("Invalid vote delegation expiry.")]
+    InvalidVoteDelegationExpiry,
+    /// Governance config has no veto authority.
+    #[error("Governance config has no veto authority.")]
+    VetoAuthorityNotSet,
+    /// Proposal is not in its cooldown period or accepted.
+    #[error("Proposal is not in its cooldown period or accepted.")]
+    ProposalNotVetoable,
+    /// Incorrect treasury address.
+    #[error("Incorrect treasury address.")]
+    IncorrectTreasuryAddress,
+    /// Incorrect treasury token account address.
+    #[error("Incorrect treasury token account address.")]
+    IncorrectTreasuryTokenAccountAddress,
+    /// Account layout version does not match the current version.
+    #[error("Account layout version does not match the current version.")]
+    AccountVersionMismatch,
+    /// Proposal transaction has no instruction slot.
+    #[error("Proposal transaction has no instruction slot.")]
+    InstructionSlotNotCreated,
+    /// Write exceeds the instruction slot's declared length.
+    #[error("Write exceeds the instruction slot's declared length.")]
+    InstructionSlotWriteOutOfBounds,
+    /// Instruction slot has not been written in full.
+    #[error("Instruction slot has not been written in full.")]
+    InstructionSlotIncomplete,
+    /// Incorrect proposal vote payer.
+    #[error("Incorrect proposal vote payer.")]
+    IncorrectProposalVotePayer,
+    /// Instruction slot length exceeds the maximum.
+    #[error("Instruction slot length exceeds the maximum.")]
+    InstructionSlotTooLong,
+    /// Incorrect vote delegation payer.
+    #[error("Incorrect vote delegation payer.")]
+    IncorrectVoteDelegationPayer,
+    /// Invalid governance period bounds.
+    #[error("Invalid governance period bounds.")]
+    InvalidPeriodBounds,
 }
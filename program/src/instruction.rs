@@ -1,14 +1,18 @@
 //! Program instruction types.
 
 use {
-    crate::state::{ProposalAccountMeta, ProposalVoteElection},
+    crate::state::{PeriodBounds, ProposalAccountMeta, ProposalVoteElection},
     borsh::{BorshDeserialize, BorshSerialize},
     solana_program::{
+        bpf_loader_upgradeable::get_program_data_address,
         instruction::{AccountMeta, Instruction},
         program_error::ProgramError,
         pubkey::Pubkey,
         system_program,
     },
+    spl_associated_token_account::get_associated_token_address_with_program_id,
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+    std::num::NonZeroU64,
 };
 
 /// Instructions supported by the Paladin Governance program.
@@ -16,9 +20,15 @@ use {
 pub enum PaladinGovernanceInstruction {
     /// Create a new governance proposal.
     ///
-    /// Expects an uninitialized proposal account with enough rent-exempt
-    /// lamports to store proposal state, owned by the Paladin Governance
-    /// program.
+    /// Expects an uninitialized proposal account, derived from the governance
+    /// config address and the governance config's current proposal count.
+    /// Increments the governance config's proposal count.
+    ///
+    /// The payer funds the rent-exempt lamports, if any, still required by
+    /// the proposal and proposal transaction accounts.
+    ///
+    /// The Paladin stake account must hold at least the governance config's
+    /// minimum proposal stake.
     ///
     /// Accounts expected by this instruction:
     ///
@@ -26,20 +36,33 @@ pub enum PaladinGovernanceInstruction {
     /// 1. `[ ]` Paladin stake account.
     /// 2. `[w]` Proposal account.
     /// 3. `[w]` Proposal transaction account.
-    /// 4. `[ ]` Governance config account.
-    /// 5. `[ ]` System program.
-    CreateProposal,
+    /// 4. `[w]` Governance config account.
+    /// 5. `[w, s]` Payer account.
+    /// 6. `[ ]` System program.
+    CreateProposal {
+        /// The proposal title.
+        title: String,
+        /// URI of the off-chain proposal description document.
+        uri: String,
+        /// Hash of the off-chain proposal description document.
+        content_hash: [u8; 32],
+    },
     /// Insert an instruction into a governance proposal.
     ///
     /// Expects an initialized proposal and proposal transaction account.
     ///
     /// Authority account provided must be the proposal creator.
     ///
+    /// The payer funds the additional rent-exempt lamports required by the
+    /// proposal transaction account's larger size.
+    ///
     /// Accounts expected by this instruction:
     ///
     /// 0. `[s]` Paladin stake authority account.
     /// 1. `[ ]` Proposal account.
     /// 2. `[w]` Proposal transaction account.
+    /// 3. `[w, s]` Payer account.
+    /// 4. `[ ]` System program.
     PushInstruction {
         /// The program ID to invoke.
         instruction_program_id: Pubkey,
@@ -52,9 +75,13 @@ pub enum PaladinGovernanceInstruction {
     ///
     /// Authority account provided must be the proposal creator.
     ///
+    /// Lamports no longer required for the proposal transaction account to
+    /// remain rent-exempt at its smaller size are returned to the proposal
+    /// creator.
+    ///
     /// Accounts expected by this instruction:
     ///
-    /// 0. `[s]` Paladin stake authority account.
+    /// 0. `[w, s]` Paladin stake authority account.
     /// 1. `[ ]` Proposal account.
     /// 2. `[w]` Proposal transaction account.
     RemoveInstruction {
@@ -74,24 +101,45 @@ pub enum PaladinGovernanceInstruction {
     ///
     /// Authority account provided must be the proposal creator.
     ///
+    /// Records the total stake delegated in the Paladin stake config, which
+    /// is used to calculate vote thresholds for the rest of the proposal's
+    /// lifetime.
+    ///
+    /// The proposal creator's stake must still meet the governance config's
+    /// minimum proposal stake.
+    ///
+    /// Any instruction slot not yet finalized is discarded, and the rent
+    /// lamports no longer required by the proposal transaction account are
+    /// returned to the proposal creator.
+    ///
     /// Accounts expected by this instruction:
     ///
-    /// 0. `[s]` Paladin stake authority account.
+    /// 0. `[w, s]` Paladin stake authority account.
     /// 1. `[w]` Proposal account.
+    /// 2. `[ ]` Paladin stake config account.
+    /// 3. `[ ]` Paladin stake account.
+    /// 4. `[w]` Proposal transaction account.
     BeginVoting,
     /// Vote on a governance proposal.
     ///
-    /// Expects an uninitialized proposal vote account with enough rent-exempt
-    /// lamports to store proposal vote state.
+    /// Expects an uninitialized proposal vote account. The payer funds the
+    /// rent-exempt lamports, if any, still required to store proposal vote
+    /// state.
+    ///
+    /// The vote may be signed by the stake account's authority or, if a vote
+    /// delegation account is provided, by the stake account's unexpired vote
+    /// delegate for the proposal's governance config.
     ///
     /// Accounts expected by this instruction:
     ///
-    /// 0. `[s]` Paladin stake authority account.
+    /// 0. `[s]` Paladin stake authority or vote delegate account.
     /// 1. `[ ]` Paladin stake account.
     /// 2. `[ ]` Paladin stake config account.
     /// 3. `[w]` Proposal vote account.
     /// 4. `[w]` Proposal account.
+    /// 5. `[w, s]` Payer account.
     /// 6. `[ ]` System program.
+    /// 7. `[ ]` (Optional) Vote delegation account.
     Vote {
         /// Proposal vote election.
         election: ProposalVoteElection,
@@ -99,20 +147,29 @@ pub enum PaladinGovernanceInstruction {
     /// Vote on a governance proposal.
     ///
     /// Expects an existing proposal vote account, representing a previously
-    /// cast proposal vote.
+    /// cast proposal vote. Proposal vote accounts created prior to versioning
+    /// must first be migrated with `MigrateAccount`.
     ///
     /// If the cast proposal vote results in >= 50% majority:
     ///
     /// * In favor: Begins the cooldown period.
     /// * Against: Terminates the proposal immediately.
     ///
+    /// If switching away from a vote in favor drops the proposal's support
+    /// below the acceptance threshold, any active cooldown period is cleared.
+    /// Should support cross the threshold again, a new cooldown period begins.
+    ///
+    /// As with `Vote`, may be signed by the stake account's vote delegate if a
+    /// vote delegation account is provided.
+    ///
     /// Accounts expected by this instruction:
     ///
-    /// 0. `[s]` Paladin stake authority account.
+    /// 0. `[s]` Paladin stake authority or vote delegate account.
     /// 1. `[ ]` Paladin stake account.
     /// 2. `[ ]` Paladin stake config account.
     /// 3. `[w]` Proposal vote account.
     /// 4. `[w]` Proposal account.
+    /// 5. `[ ]` (Optional) Vote delegation account.
     SwitchVote {
         /// New proposal vote election.
         new_election: ProposalVoteElection,
@@ -121,15 +178,18 @@ pub enum PaladinGovernanceInstruction {
     ///
     /// Given an accepted proposal and one of its instructions, executes it.
     /// If the proposal has been accepted, executes the instruction via CPI
-    /// and applies the governance treasury PDA signature, then marks the
-    /// instruction as executed.
+    /// and applies the governance treasury, governance config and proposal
+    /// transaction PDA signatures, then marks the instruction as executed.
+    /// Once every instruction has been executed, the proposal is marked as
+    /// processed and its execution timestamp is recorded.
     ///
     /// Note: Returns an error if the previous instruction in this proposal has
-    /// not been executed.
+    /// not been executed, or if the proposal's execution window, which begins
+    /// once its cooldown period has ended, has elapsed.
     ///
     /// Accounts expected by this instruction:
     ///
-    /// 0. `[ ]` Proposal account.
+    /// 0. `[w]` Proposal account.
     /// 1. `[w]` Proposal transaction account.
     /// 2..N.    Instruction accounts.
     ProcessInstruction {
@@ -142,56 +202,475 @@ pub enum PaladinGovernanceInstruction {
     /// constraints, including:
     ///
     /// * The cooldown period for proposal execution.
+    /// * The execution window for accepted proposals.
     /// * Minimum required majority threshold.
+    /// * Minimum required participation (quorum) threshold.
+    /// * The veto authority, if any.
+    /// * The minimum and maximum periods the governance config may set.
     ///
-    /// This instruction can only be invoked once.
+    /// Acceptance and rejection thresholds must be non-zero and no greater
+    /// than `1e9` (100%). The quorum threshold may be zero, which disables
+    /// the quorum requirement, but no greater than `1e9`. Each minimum period
+    /// must be non-zero and no greater than its maximum, and the cooldown
+    /// period, execution window and voting period must fall within them.
+    ///
+    /// This instruction can only be invoked once, and must be signed by
+    /// either the Paladin stake config authority or the program's upgrade
+    /// authority.
     ///
     /// Accounts expected by this instruction:
     ///
     /// 0. `[w]` Governance config account.
     /// 1. `[ ]` Paladin stake config account.
     /// 2. `[ ]` System program.
+    /// 3. `[s]` Paladin stake config authority or program upgrade authority.
+    /// 4. `[ ]` Program data account.
     InitializeGovernance {
         /// The cooldown period that begins when a proposal reaches the
         /// `proposal_acceptance_threshold` and upon its conclusion will execute
         /// the proposal's instruction.
         cooldown_period_seconds: u64,
+        /// The window, following the cooldown period, within which an
+        /// accepted proposal's instructions must be executed before it
+        /// expires.
+        execution_window_seconds: u64,
         /// The minimum required threshold of proposal acceptance to begin the
         /// cooldown period.
         proposal_acceptance_threshold: u32,
+        /// The minimum amount of stake required to create a proposal and to
+        /// begin voting on it.
+        proposal_minimum_stake: u64,
         /// The minimum required threshold of proposal rejection to terminate
         /// the proposal.
         proposal_rejection_threshold: u32,
+        /// The minimum required threshold of participation for a proposal to
+        /// be concluded once voting has ended. Zero disables the quorum.
+        quorum_threshold: u32,
+        /// The authority permitted to veto proposals. A `None` value means
+        /// proposals cannot be vetoed.
+        veto_authority: OptionalNonZeroPubkey,
         /// The voting period for proposals.
         voting_period_seconds: u64,
+        /// The minimum and maximum periods the governance config may set.
+        period_bounds: PeriodBounds,
     },
     /// Update the governance config.
     ///
     /// Allows modification of the governance config, including:
     ///
     /// * The cooldown period for proposal execution.
+    /// * The execution window for accepted proposals.
     /// * Minimum required majority threshold.
+    /// * Minimum required participation (quorum) threshold.
+    /// * The veto authority, if any.
+    ///
+    /// Acceptance and rejection thresholds must be non-zero and no greater
+    /// than `1e9` (100%). The quorum threshold may be zero, which disables
+    /// the quorum requirement, but no greater than `1e9`. The cooldown
+    /// period, execution window and voting period must fall within the
+    /// minimum and maximum periods set when the governance config was
+    /// initialized, which cannot be updated.
     ///
-    /// This instruction can only be executed from an accepted proposal.
+    /// This instruction can only be executed from an accepted proposal, via
+    /// the proposal's `ProcessInstruction` instruction, which signs for both
+    /// the governance config and the proposal transaction PDAs.
     ///
     /// Accounts expected by this instruction:
     ///
-    /// 0. `[w]` Governance config account.
+    /// 0. `[w, s]` Governance config account.
     /// 1. `[ ]` Proposal account.
+    /// 2. `[s]` Proposal transaction account.
     UpdateGovernance {
         /// The cooldown period that begins when a proposal reaches the
         /// `proposal_acceptance_threshold` and upon its conclusion will execute
         /// the proposal's instruction.
         cooldown_period_seconds: u64,
+        /// The window, following the cooldown period, within which an
+        /// accepted proposal's instructions must be executed before it
+        /// expires.
+        execution_window_seconds: u64,
         /// The minimum required threshold of proposal acceptance to begin the
         /// cooldown period.
         proposal_acceptance_threshold: u32,
+        /// The minimum amount of stake required to create a proposal and to
+        /// begin voting on it.
+        proposal_minimum_stake: u64,
         /// The minimum required threshold of proposal rejection to terminate
         /// the proposal.
         proposal_rejection_threshold: u32,
+        /// The minimum required threshold of participation for a proposal to
+        /// be concluded once voting has ended. Zero disables the quorum.
+        quorum_threshold: u32,
+        /// The authority permitted to veto proposals. A `None` value means
+        /// proposals cannot be vetoed.
+        veto_authority: OptionalNonZeroPubkey,
         /// The voting period for proposals.
         voting_period_seconds: u64,
     },
+    /// Finalize a governance proposal once its voting stage has concluded,
+    /// or expire an accepted proposal once its execution window has elapsed.
+    ///
+    /// Permissionless. Transitions a proposal in the voting stage to:
+    ///
+    /// * Accepted: If its cooldown period has ended.
+    /// * Expired: If its cooldown period and execution window have both ended.
+    /// * Rejected: If its voting period has ended without a cooldown period
+    ///   having begun.
+    /// * QuorumNotMet: If any of the above, but the stake for, against and
+    ///   abstained falls short of the governance config's quorum threshold.
+    ///
+    /// Transitions an accepted proposal to `Expired` if its execution window
+    /// has ended before all of its instructions were executed, or to
+    /// `Processed` if it has no instructions to execute.
+    ///
+    /// The proposal transaction account provided must be the one derived from
+    /// the proposal.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Proposal account.
+    /// 1. `[ ]` Proposal transaction account.
+    FinalizeProposal,
+    /// Close a terminated governance proposal, returning its rent-exempt
+    /// lamports to the proposal author.
+    ///
+    /// The proposal must be in a terminal stage (cancelled, rejected,
+    /// processed, quorum not met, expired or vetoed), and its proposal
+    /// transaction account must already be closed.
+    ///
+    /// Authority account provided must be the proposal creator.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w, s]` Paladin stake authority account.
+    /// 1. `[w]` Proposal account.
+    /// 2. `[ ]` Proposal transaction account.
+    CloseProposal,
+    /// Close a proposal vote account, returning its rent-exempt lamports to
+    /// the payer that funded it.
+    ///
+    /// The proposal must be in a terminal stage (cancelled, rejected,
+    /// processed, quorum not met, expired or vetoed), or must already be
+    /// closed.
+    ///
+    /// Authority account provided must be the payer or the voting authority
+    /// recorded in the proposal vote, and payer account provided must be the
+    /// recorded payer. The stake account is not loaded, so the vote may be
+    /// closed after the stake account has been closed or its authority has
+    /// changed.
+    ///
+    /// Proposal votes which have not been migrated to the current version did
+    /// not record their payer, so the authority account provided must be the
+    /// stake account's authority, and is also the payer account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[s]` Payer, voting authority or Paladin stake authority account.
+    /// 1. `[ ]` Paladin stake account.
+    /// 2. `[w]` Proposal vote account.
+    /// 3. `[ ]` Proposal account.
+    /// 4. `[w]` Payer account.
+    CloseProposalVote,
+    /// Close a terminated governance proposal's transaction account,
+    /// returning its rent-exempt lamports to the proposal author.
+    ///
+    /// The proposal must be in a terminal stage (cancelled, rejected,
+    /// processed, quorum not met, expired or vetoed).
+    ///
+    /// Authority account provided must be the proposal creator.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w, s]` Paladin stake authority account.
+    /// 1. `[ ]` Proposal account.
+    /// 2. `[w]` Proposal transaction account.
+    CloseProposalTransaction,
+    /// Delegate a stake account's governance voting power to another key.
+    ///
+    /// The delegate may then sign `Vote` and `SwitchVote` on behalf of the
+    /// stake account, for proposals under the provided governance config,
+    /// until the delegation expires or is revoked.
+    ///
+    /// Creates the vote delegation account if it is uninitialized, in which
+    /// case the payer funds the rent-exempt lamports, if any, still required
+    /// to store vote delegation state, and is recorded to be refunded when
+    /// the delegation is revoked. Otherwise, replaces the existing delegate
+    /// and expiry, keeping the recorded payer.
+    ///
+    /// Authority account provided must be the stake account's authority.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[s]` Paladin stake authority account.
+    /// 1. `[ ]` Paladin stake account.
+    /// 2. `[ ]` Paladin stake config account.
+    /// 3. `[ ]` Governance config account.
+    /// 4. `[w]` Vote delegation account.
+    /// 5. `[w, s]` Payer account.
+    /// 6. `[ ]` System program.
+    SetVoteDelegation {
+        /// The key authorized to vote on behalf of the stake account.
+        delegate: Pubkey,
+        /// Timestamp after which the delegation is no longer valid. Must be
+        /// in the future, if provided.
+        expiry_timestamp: Option<NonZeroU64>,
+    },
+    /// Revoke a stake account's vote delegation, closing the vote delegation
+    /// account and returning its rent-exempt lamports to the payer that
+    /// funded it.
+    ///
+    /// Authority account provided must be the stake account's authority.
+    /// Payer account provided must be the payer recorded in the vote
+    /// delegation.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[s]` Paladin stake authority account.
+    /// 1. `[ ]` Paladin stake account.
+    /// 2. `[ ]` Paladin stake config account.
+    /// 3. `[w]` Vote delegation account.
+    /// 4. `[w]` Payer account.
+    RevokeVoteDelegation,
+    /// Migrate a proposal vote account from the unversioned layout to the
+    /// current version, recording the stake account address alongside the
+    /// authority that cast the vote.
+    ///
+    /// The proposal vote account must hold enough rent-exempt lamports to
+    /// store the current version of proposal vote state.
+    ///
+    /// Deprecated: use `MigrateAccount`, which funds the account's new size
+    /// from a payer and migrates every previous proposal vote layout. Kept
+    /// only so existing transactions continue to be processed.
+    ///
+    /// This instruction is permissionless.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[ ]` Paladin stake account.
+    /// 1. `[w]` Proposal vote account.
+    MigrateProposalVote,
+    /// Recompute a proposal vote's weight from the current amount of its
+    /// stake account, adjusting the proposal's stake for the vote's election
+    /// accordingly.
+    ///
+    /// As with `Vote`, may begin the cooldown period or terminate the
+    /// proposal if the adjusted stake meets a threshold. As with
+    /// `SwitchVote`, clears any active cooldown period if support drops below
+    /// the acceptance threshold.
+    ///
+    /// This instruction is permissionless.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[ ]` Paladin stake account.
+    /// 1. `[ ]` Paladin stake config account.
+    /// 2. `[w]` Proposal vote account.
+    /// 3. `[w]` Proposal account.
+    SyncVoteWeight,
+    /// Withdraw a previously cast vote on a governance proposal, removing its
+    /// stake from the proposal and closing the proposal vote account.
+    ///
+    /// The proposal must be in the voting stage. The proposal vote account's
+    /// rent-exempt lamports are returned to the payer that funded it.
+    ///
+    /// As with `SwitchVote`, clears any active cooldown period if support
+    /// drops below the acceptance threshold.
+    ///
+    /// As with `Vote`, may be signed by the stake account's vote delegate if a
+    /// vote delegation account is provided.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[s]` Paladin stake authority or vote delegate account.
+    /// 1. `[ ]` Paladin stake account.
+    /// 2. `[ ]` Paladin stake config account.
+    /// 3. `[w]` Proposal vote account.
+    /// 4. `[w]` Proposal account.
+    /// 5. `[w]` Payer account.
+    /// 6. `[ ]` (Optional) Vote delegation account.
+    WithdrawVote,
+    /// Veto a governance proposal in its cooldown period, or one which has
+    /// been accepted but not fully processed.
+    ///
+    /// Authority account provided must be the governance config's veto
+    /// authority.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[s]` Veto authority account.
+    /// 1. `[ ]` Governance config account.
+    /// 2. `[w]` Proposal account.
+    VetoProposal,
+    /// Initialize the governance treasury.
+    ///
+    /// Tops up the treasury account to the rent-exempt minimum for a system
+    /// account, funded by the payer. If token accounts are provided, also
+    /// creates the treasury's associated token account for the mint, if it
+    /// does not already exist.
+    ///
+    /// This instruction is permissionless, and may be invoked more than once.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w, s]` Payer account.
+    /// 1. `[w]` Treasury account.
+    /// 2. `[ ]` Governance config account.
+    /// 3. `[ ]` System program.
+    /// 4. `[w]` (Optional) Treasury token account.
+    /// 5. `[ ]` (Optional) Token mint account.
+    /// 6. `[ ]` (Optional) Token program.
+    /// 7. `[ ]` (Optional) Associated token account program.
+    InitializeTreasury,
+    /// Deposit SOL or tokens into the governance treasury.
+    ///
+    /// If token accounts are provided, transfers tokens from the depositor's
+    /// token account to the treasury's associated token account for the mint.
+    /// Otherwise, transfers lamports from the depositor to the treasury.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w, s]` Depositor account.
+    /// 1. `[w]` Treasury account.
+    /// 2. `[ ]` Governance config account.
+    /// 3. `[ ]` System program.
+    /// 4. `[w]` (Optional) Depositor token account.
+    /// 5. `[w]` (Optional) Treasury token account.
+    /// 6. `[ ]` (Optional) Token mint account.
+    /// 7. `[ ]` (Optional) Token program.
+    DepositToTreasury {
+        /// The amount of lamports or tokens to deposit.
+        amount: u64,
+    },
+    /// Transfer SOL or tokens out of the governance treasury.
+    ///
+    /// If token accounts are provided, transfers tokens from the treasury's
+    /// associated token account for the mint to the destination token
+    /// account. Otherwise, transfers lamports from the treasury to the
+    /// destination.
+    ///
+    /// Can only be invoked through a proposal's `ProcessInstruction`
+    /// instruction, once the proposal has been accepted.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[ ]` Governance config account.
+    /// 1. `[ ]` Proposal account.
+    /// 2. `[s]` Proposal transaction account.
+    /// 3. `[w]` Treasury account.
+    /// 4. `[w]` Destination account, or destination token account.
+    /// 5. `[ ]` System program.
+    /// 6. `[w]` (Optional) Treasury token account.
+    /// 7. `[ ]` (Optional) Token mint account.
+    /// 8. `[ ]` (Optional) Token program.
+    TransferFromTreasury {
+        /// The amount of lamports or tokens to transfer.
+        amount: u64,
+    },
+    /// Migrate a governance config, proposal, proposal transaction, proposal
+    /// vote or vote delegation account from a previous layout to the current
+    /// version, reallocating it in place.
+    ///
+    /// The account's layout is detected from its discriminator and version,
+    /// or from its size, verified against its discriminator or the address
+    /// derived from its contents. The payer funds the rent-exemption of the
+    /// account's new size.
+    ///
+    /// Some previous layouts require a related account to be provided:
+    ///
+    /// * Proposal transactions in the unversioned layout are a bare list of
+    ///   instructions, so the proposal account is required to verify the
+    ///   address.
+    /// * Proposals in the unversioned layout did not record the total stake
+    ///   when voting began, so a proposal in the voting stage requires the
+    ///   Paladin stake config account, whose current delegated stake is
+    ///   recorded.
+    /// * Proposal votes in the unversioned layout did not record their stake
+    ///   account, which must then be provided.
+    /// * Vote delegations in the version 1 layout did not record their payer,
+    ///   so the delegating stake account is required, whose authority is
+    ///   recorded in its place.
+    ///
+    /// Proposal votes in layouts prior to version 3 did not record their
+    /// payer, so the voting authority is recorded in its place. Governance
+    /// configs, and the governance config recorded in proposals, in layouts
+    /// prior to version 2 had no period bounds, so take the default bounds.
+    ///
+    /// This instruction is permissionless.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Governance config, proposal, proposal transaction, proposal
+    ///    vote or vote delegation account.
+    /// 1. `[w, s]` Payer account.
+    /// 2. `[ ]` System program.
+    /// 3. `[ ]` (Optional) Proposal account, Paladin stake config account or
+    ///    Paladin stake account.
+    MigrateAccount,
+    /// Create an instruction slot on a governance proposal's transaction, for
+    /// an instruction too large to insert with `PushInstruction`.
+    ///
+    /// The slot holds a serialized
+    /// [ProposalInstruction](../state/struct.ProposalInstruction.html) of the
+    /// declared length, written in chunks with `WriteInstructionSlot` and
+    /// appended to the proposal's transaction with `FinalizeInstructionSlot`.
+    /// Any slot not yet finalized is discarded. The declared length may not
+    /// exceed
+    /// [MAX_INSTRUCTION_SLOT_LEN](../state/constant.MAX_INSTRUCTION_SLOT_LEN.
+    /// html).
+    ///
+    /// Authority account provided must be the proposal creator.
+    ///
+    /// The payer funds any additional rent-exempt lamports required by the
+    /// proposal transaction account's new size. Lamports no longer required
+    /// are returned to the proposal creator.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w, s]` Paladin stake authority account.
+    /// 1. `[ ]` Proposal account.
+    /// 2. `[w]` Proposal transaction account.
+    /// 3. `[w, s]` Payer account.
+    /// 4. `[ ]` System program.
+    CreateInstructionSlot {
+        /// The length of the serialized instruction.
+        instruction_len: u32,
+    },
+    /// Write a chunk of a serialized instruction to a governance proposal's
+    /// instruction slot, at the given offset.
+    ///
+    /// Authority account provided must be the proposal creator.
+    ///
+    /// The payer funds the additional rent-exempt lamports required by the
+    /// proposal transaction account's larger size.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[s]` Paladin stake authority account.
+    /// 1. `[ ]` Proposal account.
+    /// 2. `[w]` Proposal transaction account.
+    /// 3. `[w, s]` Payer account.
+    /// 4. `[ ]` System program.
+    WriteInstructionSlot {
+        /// The offset into the serialized instruction.
+        offset: u32,
+        /// The chunk of the serialized instruction.
+        data: Vec<u8>,
+    },
+    /// Finalize a governance proposal's instruction slot, appending the
+    /// written instruction to the proposal's transaction.
+    ///
+    /// Authority account provided must be the proposal creator.
+    ///
+    /// Lamports no longer required for the proposal transaction account to
+    /// remain rent-exempt at its smaller size are returned to the proposal
+    /// creator.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w, s]` Paladin stake authority account.
+    /// 1. `[ ]` Proposal account.
+    /// 2. `[w]` Proposal transaction account.
+    FinalizeInstructionSlot,
 }
 
 impl PaladinGovernanceInstruction {
@@ -200,7 +679,17 @@ impl PaladinGovernanceInstruction {
     /// into a byte buffer.
     pub fn pack(&self) -> Vec<u8> {
         match self {
-            Self::CreateProposal => vec![0],
+            Self::CreateProposal {
+                title,
+                uri,
+                content_hash,
+            } => {
+                let mut buf = vec![0];
+                title.serialize(&mut buf).unwrap();
+                uri.serialize(&mut buf).unwrap();
+                content_hash.serialize(&mut buf).unwrap();
+                buf
+            }
             Self::PushInstruction {
                 instruction_program_id,
                 instruction_account_metas,
@@ -228,30 +717,90 @@ impl PaladinGovernanceInstruction {
             }
             Self::InitializeGovernance {
                 cooldown_period_seconds,
+                execution_window_seconds,
                 proposal_acceptance_threshold,
+                proposal_minimum_stake,
                 proposal_rejection_threshold,
+                quorum_threshold,
+                veto_authority,
                 voting_period_seconds,
+                period_bounds,
             } => {
                 let mut buf = vec![8];
                 buf.extend_from_slice(&cooldown_period_seconds.to_le_bytes());
+                buf.extend_from_slice(&execution_window_seconds.to_le_bytes());
                 buf.extend_from_slice(&proposal_acceptance_threshold.to_le_bytes());
+                buf.extend_from_slice(&proposal_minimum_stake.to_le_bytes());
                 buf.extend_from_slice(&proposal_rejection_threshold.to_le_bytes());
+                buf.extend_from_slice(&quorum_threshold.to_le_bytes());
+                buf.extend_from_slice(veto_authority.0.as_ref());
                 buf.extend_from_slice(&voting_period_seconds.to_le_bytes());
+                buf.extend_from_slice(bytemuck::bytes_of(period_bounds));
                 buf
             }
             Self::UpdateGovernance {
                 cooldown_period_seconds,
+                execution_window_seconds,
                 proposal_acceptance_threshold,
+                proposal_minimum_stake,
                 proposal_rejection_threshold,
+                quorum_threshold,
+                veto_authority,
                 voting_period_seconds,
             } => {
                 let mut buf = vec![9];
                 buf.extend_from_slice(&cooldown_period_seconds.to_le_bytes());
+                buf.extend_from_slice(&execution_window_seconds.to_le_bytes());
                 buf.extend_from_slice(&proposal_acceptance_threshold.to_le_bytes());
+                buf.extend_from_slice(&proposal_minimum_stake.to_le_bytes());
                 buf.extend_from_slice(&proposal_rejection_threshold.to_le_bytes());
+                buf.extend_from_slice(&quorum_threshold.to_le_bytes());
+                buf.extend_from_slice(veto_authority.0.as_ref());
                 buf.extend_from_slice(&voting_period_seconds.to_le_bytes());
                 buf
             }
+            Self::FinalizeProposal => vec![10],
+            Self::CloseProposal => vec![11],
+            Self::CloseProposalVote => vec![12],
+            Self::CloseProposalTransaction => vec![13],
+            Self::SetVoteDelegation {
+                delegate,
+                expiry_timestamp,
+            } => {
+                let mut buf = vec![14];
+                buf.extend_from_slice(delegate.as_ref());
+                buf.extend_from_slice(&expiry_timestamp.map_or(0, NonZeroU64::get).to_le_bytes());
+                buf
+            }
+            Self::RevokeVoteDelegation => vec![15],
+            Self::MigrateProposalVote => vec![16],
+            Self::SyncVoteWeight => vec![17],
+            Self::WithdrawVote => vec![18],
+            Self::VetoProposal => vec![19],
+            Self::InitializeTreasury => vec![20],
+            Self::DepositToTreasury { amount } => {
+                let mut buf = vec![21];
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf
+            }
+            Self::TransferFromTreasury { amount } => {
+                let mut buf = vec![22];
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf
+            }
+            Self::MigrateAccount => vec![23],
+            Self::CreateInstructionSlot { instruction_len } => {
+                let mut buf = vec![24];
+                buf.extend_from_slice(&instruction_len.to_le_bytes());
+                buf
+            }
+            Self::WriteInstructionSlot { offset, data } => {
+                let mut buf = vec![25];
+                offset.serialize(&mut buf).unwrap();
+                data.serialize(&mut buf).unwrap();
+                buf
+            }
+            Self::FinalizeInstructionSlot => vec![26],
         }
     }
 
@@ -259,7 +808,25 @@ impl PaladinGovernanceInstruction {
     /// [PaladinGovernanceInstruction](enum.PaladinGovernanceInstruction.html).
     pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
         match input.split_first() {
-            Some((&0, _)) => Ok(Self::CreateProposal),
+            Some((&0, rest)) => {
+                #[derive(BorshDeserialize)]
+                struct Instruction {
+                    title: String,
+                    uri: String,
+                    content_hash: [u8; 32],
+                }
+                let Instruction {
+                    title,
+                    uri,
+                    content_hash,
+                } = Instruction::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Ok(Self::CreateProposal {
+                    title,
+                    uri,
+                    content_hash,
+                })
+            }
             Some((&1, rest)) => {
                 #[derive(BorshDeserialize)]
                 struct Instruction {
@@ -301,34 +868,97 @@ impl PaladinGovernanceInstruction {
                 let instruction_index = u32::from_le_bytes(rest.try_into().unwrap());
                 Ok(Self::ProcessInstruction { instruction_index })
             }
-            Some((&8, rest)) if rest.len() == 24 => {
+            Some((&8, rest)) if rest.len() == 124 => {
                 let cooldown_period_seconds = u64::from_le_bytes(rest[..8].try_into().unwrap());
+                let execution_window_seconds = u64::from_le_bytes(rest[8..16].try_into().unwrap());
                 let proposal_acceptance_threshold =
-                    u32::from_le_bytes(rest[8..12].try_into().unwrap());
+                    u32::from_le_bytes(rest[16..20].try_into().unwrap());
+                let proposal_minimum_stake = u64::from_le_bytes(rest[20..28].try_into().unwrap());
                 let proposal_rejection_threshold =
-                    u32::from_le_bytes(rest[12..16].try_into().unwrap());
-                let voting_period_seconds = u64::from_le_bytes(rest[16..24].try_into().unwrap());
+                    u32::from_le_bytes(rest[28..32].try_into().unwrap());
+                let quorum_threshold = u32::from_le_bytes(rest[32..36].try_into().unwrap());
+                let veto_authority =
+                    OptionalNonZeroPubkey(Pubkey::new_from_array(rest[36..68].try_into().unwrap()));
+                let voting_period_seconds = u64::from_le_bytes(rest[68..76].try_into().unwrap());
+                let period_bounds = bytemuck::pod_read_unaligned::<PeriodBounds>(&rest[76..124]);
                 Ok(Self::InitializeGovernance {
                     cooldown_period_seconds,
+                    execution_window_seconds,
                     proposal_acceptance_threshold,
+                    proposal_minimum_stake,
                     proposal_rejection_threshold,
+                    quorum_threshold,
+                    veto_authority,
                     voting_period_seconds,
+                    period_bounds,
                 })
             }
-            Some((&9, rest)) if rest.len() == 24 => {
+            Some((&9, rest)) if rest.len() == 76 => {
                 let cooldown_period_seconds = u64::from_le_bytes(rest[..8].try_into().unwrap());
+                let execution_window_seconds = u64::from_le_bytes(rest[8..16].try_into().unwrap());
                 let proposal_acceptance_threshold =
-                    u32::from_le_bytes(rest[8..12].try_into().unwrap());
+                    u32::from_le_bytes(rest[16..20].try_into().unwrap());
+                let proposal_minimum_stake = u64::from_le_bytes(rest[20..28].try_into().unwrap());
                 let proposal_rejection_threshold =
-                    u32::from_le_bytes(rest[12..16].try_into().unwrap());
-                let voting_period_seconds = u64::from_le_bytes(rest[16..24].try_into().unwrap());
+                    u32::from_le_bytes(rest[28..32].try_into().unwrap());
+                let quorum_threshold = u32::from_le_bytes(rest[32..36].try_into().unwrap());
+                let veto_authority =
+                    OptionalNonZeroPubkey(Pubkey::new_from_array(rest[36..68].try_into().unwrap()));
+                let voting_period_seconds = u64::from_le_bytes(rest[68..76].try_into().unwrap());
                 Ok(Self::UpdateGovernance {
                     cooldown_period_seconds,
+                    execution_window_seconds,
                     proposal_acceptance_threshold,
+                    proposal_minimum_stake,
                     proposal_rejection_threshold,
+                    quorum_threshold,
+                    veto_authority,
                     voting_period_seconds,
                 })
             }
+            Some((&10, _)) => Ok(Self::FinalizeProposal),
+            Some((&11, _)) => Ok(Self::CloseProposal),
+            Some((&12, _)) => Ok(Self::CloseProposalVote),
+            Some((&13, _)) => Ok(Self::CloseProposalTransaction),
+            Some((&14, rest)) if rest.len() == 40 => {
+                let delegate = Pubkey::new_from_array(rest[..32].try_into().unwrap());
+                let expiry_timestamp =
+                    NonZeroU64::new(u64::from_le_bytes(rest[32..40].try_into().unwrap()));
+                Ok(Self::SetVoteDelegation {
+                    delegate,
+                    expiry_timestamp,
+                })
+            }
+            Some((&15, _)) => Ok(Self::RevokeVoteDelegation),
+            Some((&16, _)) => Ok(Self::MigrateProposalVote),
+            Some((&17, _)) => Ok(Self::SyncVoteWeight),
+            Some((&18, _)) => Ok(Self::WithdrawVote),
+            Some((&19, _)) => Ok(Self::VetoProposal),
+            Some((&20, _)) => Ok(Self::InitializeTreasury),
+            Some((&21, rest)) if rest.len() == 8 => {
+                let amount = u64::from_le_bytes(rest.try_into().unwrap());
+                Ok(Self::DepositToTreasury { amount })
+            }
+            Some((&22, rest)) if rest.len() == 8 => {
+                let amount = u64::from_le_bytes(rest.try_into().unwrap());
+                Ok(Self::TransferFromTreasury { amount })
+            }
+            Some((&23, _)) => Ok(Self::MigrateAccount),
+            Some((&24, rest)) if rest.len() == 4 => {
+                let instruction_len = u32::from_le_bytes(rest.try_into().unwrap());
+                Ok(Self::CreateInstructionSlot { instruction_len })
+            }
+            Some((&25, rest)) => {
+                #[derive(BorshDeserialize)]
+                struct Instruction {
+                    offset: u32,
+                    data: Vec<u8>,
+                }
+                let Instruction { offset, data } = Instruction::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Ok(Self::WriteInstructionSlot { offset, data })
+            }
+            Some((&26, _)) => Ok(Self::FinalizeInstructionSlot),
             _ => Err(ProgramError::InvalidInstructionData),
         }
     }
@@ -337,22 +967,33 @@ impl PaladinGovernanceInstruction {
 /// Creates a
 /// [CreateProposal](enum.PaladinGovernanceInstruction.html)
 /// instruction.
+#[allow(clippy::too_many_arguments)]
 pub fn create_proposal(
     stake_authority_address: &Pubkey,
     stake_address: &Pubkey,
     proposal_address: &Pubkey,
     proposal_transaction_address: &Pubkey,
     governance_config_address: &Pubkey,
+    payer_address: &Pubkey,
+    title: String,
+    uri: String,
+    content_hash: [u8; 32],
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new_readonly(*stake_authority_address, true),
         AccountMeta::new_readonly(*stake_address, false),
         AccountMeta::new(*proposal_address, false),
         AccountMeta::new(*proposal_transaction_address, false),
-        AccountMeta::new_readonly(*governance_config_address, false),
+        AccountMeta::new(*governance_config_address, false),
+        AccountMeta::new(*payer_address, true),
         AccountMeta::new_readonly(system_program::id(), false),
     ];
-    let data = PaladinGovernanceInstruction::CreateProposal.pack();
+    let data = PaladinGovernanceInstruction::CreateProposal {
+        title,
+        uri,
+        content_hash,
+    }
+    .pack();
     Instruction::new_with_bytes(crate::id(), &data, accounts)
 }
 
@@ -363,6 +1004,7 @@ pub fn push_instruction(
     stake_authority_address: &Pubkey,
     proposal_address: &Pubkey,
     proposal_transaction_address: &Pubkey,
+    payer_address: &Pubkey,
     instruction_program_id: &Pubkey,
     instruction_account_metas: Vec<ProposalAccountMeta>,
     instruction_data: Vec<u8>,
@@ -371,6 +1013,8 @@ pub fn push_instruction(
         AccountMeta::new_readonly(*stake_authority_address, true),
         AccountMeta::new_readonly(*proposal_address, false),
         AccountMeta::new(*proposal_transaction_address, false),
+        AccountMeta::new(*payer_address, true),
+        AccountMeta::new_readonly(system_program::id(), false),
     ];
     let data = PaladinGovernanceInstruction::PushInstruction {
         instruction_program_id: *instruction_program_id,
@@ -391,7 +1035,7 @@ pub fn remove_instruction(
     instruction_index: u32,
 ) -> Instruction {
     let accounts = vec![
-        AccountMeta::new_readonly(*stake_authority_address, true),
+        AccountMeta::new(*stake_authority_address, true),
         AccountMeta::new_readonly(*proposal_address, false),
         AccountMeta::new(*proposal_transaction_address, false),
     ];
@@ -414,10 +1058,19 @@ pub fn cancel_proposal(stake_authority_address: &Pubkey, proposal_address: &Pubk
 /// Creates a
 /// [BeginVoting](enum.PaladinGovernanceInstruction.html)
 /// instruction.
-pub fn begin_voting(stake_authority_address: &Pubkey, proposal_address: &Pubkey) -> Instruction {
+pub fn begin_voting(
+    stake_authority_address: &Pubkey,
+    proposal_address: &Pubkey,
+    stake_config_address: &Pubkey,
+    stake_address: &Pubkey,
+    proposal_transaction_address: &Pubkey,
+) -> Instruction {
     let accounts = vec![
-        AccountMeta::new_readonly(*stake_authority_address, true),
+        AccountMeta::new(*stake_authority_address, true),
         AccountMeta::new(*proposal_address, false),
+        AccountMeta::new_readonly(*stake_config_address, false),
+        AccountMeta::new_readonly(*stake_address, false),
+        AccountMeta::new(*proposal_transaction_address, false),
     ];
     let data = PaladinGovernanceInstruction::BeginVoting.pack();
     Instruction::new_with_bytes(crate::id(), &data, accounts)
@@ -432,6 +1085,7 @@ pub fn vote(
     stake_config_address: &Pubkey,
     proposal_vote_address: &Pubkey,
     proposal_address: &Pubkey,
+    payer_address: &Pubkey,
     election: ProposalVoteElection,
 ) -> Instruction {
     let accounts = vec![
@@ -440,12 +1094,42 @@ pub fn vote(
         AccountMeta::new_readonly(*stake_config_address, false),
         AccountMeta::new(*proposal_vote_address, false),
         AccountMeta::new(*proposal_address, false),
+        AccountMeta::new(*payer_address, true),
         AccountMeta::new_readonly(system_program::id(), false),
     ];
     let data = PaladinGovernanceInstruction::Vote { election }.pack();
     Instruction::new_with_bytes(crate::id(), &data, accounts)
 }
 
+/// Creates a
+/// [Vote](enum.PaladinGovernanceInstruction.html)
+/// instruction, signed by a vote delegate on behalf of the stake account.
+#[allow(clippy::too_many_arguments)]
+pub fn vote_as_delegate(
+    delegate_address: &Pubkey,
+    stake_address: &Pubkey,
+    stake_config_address: &Pubkey,
+    proposal_vote_address: &Pubkey,
+    proposal_address: &Pubkey,
+    payer_address: &Pubkey,
+    vote_delegation_address: &Pubkey,
+    election: ProposalVoteElection,
+) -> Instruction {
+    let mut instruction = vote(
+        delegate_address,
+        stake_address,
+        stake_config_address,
+        proposal_vote_address,
+        proposal_address,
+        payer_address,
+        election,
+    );
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(*vote_delegation_address, false));
+    instruction
+}
+
 /// Creates a
 /// [SwitchVote](enum.PaladinGovernanceInstruction.html)
 /// instruction.
@@ -468,6 +1152,32 @@ pub fn switch_vote(
     Instruction::new_with_bytes(crate::id(), &data, accounts)
 }
 
+/// Creates a
+/// [SwitchVote](enum.PaladinGovernanceInstruction.html)
+/// instruction, signed by a vote delegate on behalf of the stake account.
+pub fn switch_vote_as_delegate(
+    delegate_address: &Pubkey,
+    stake_address: &Pubkey,
+    stake_config_address: &Pubkey,
+    proposal_vote_address: &Pubkey,
+    proposal_address: &Pubkey,
+    vote_delegation_address: &Pubkey,
+    new_election: ProposalVoteElection,
+) -> Instruction {
+    let mut instruction = switch_vote(
+        delegate_address,
+        stake_address,
+        stake_config_address,
+        proposal_vote_address,
+        proposal_address,
+        new_election,
+    );
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(*vote_delegation_address, false));
+    instruction
+}
+
 /// Creates a
 /// [ProcessInstruction](enum.PaladinGovernanceInstruction.html)
 /// instruction.
@@ -478,7 +1188,7 @@ pub fn process_instruction(
     instruction_index: u32,
 ) -> Instruction {
     let mut accounts = vec![
-        AccountMeta::new_readonly(*proposal_address, false),
+        AccountMeta::new(*proposal_address, false),
         AccountMeta::new(*proposal_transaction_address, false),
     ];
     accounts.extend_from_slice(account_metas);
@@ -489,24 +1199,38 @@ pub fn process_instruction(
 /// Creates a
 /// [InitializeGovernance](enum.PaladinGovernanceInstruction.html)
 /// instruction.
+#[allow(clippy::too_many_arguments)]
 pub fn initialize_governance(
     governance_config_address: &Pubkey,
     stake_config_address: &Pubkey,
+    authority_address: &Pubkey,
     cooldown_period_seconds: u64,
+    execution_window_seconds: u64,
     proposal_acceptance_threshold: u32,
+    proposal_minimum_stake: u64,
     proposal_rejection_threshold: u32,
+    quorum_threshold: u32,
+    veto_authority: Option<&Pubkey>,
     voting_period_seconds: u64,
+    period_bounds: PeriodBounds,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new(*governance_config_address, false),
         AccountMeta::new_readonly(*stake_config_address, false),
         AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(*authority_address, true),
+        AccountMeta::new_readonly(get_program_data_address(&crate::id()), false),
     ];
     let data = PaladinGovernanceInstruction::InitializeGovernance {
         cooldown_period_seconds,
+        execution_window_seconds,
         proposal_acceptance_threshold,
+        proposal_minimum_stake,
         proposal_rejection_threshold,
+        quorum_threshold,
+        veto_authority: OptionalNonZeroPubkey(veto_authority.copied().unwrap_or_default()),
         voting_period_seconds,
+        period_bounds,
     }
     .pack();
     Instruction::new_with_bytes(crate::id(), &data, accounts)
@@ -515,44 +1239,582 @@ pub fn initialize_governance(
 /// Creates a
 /// [UpdateGovernance](enum.PaladinGovernanceInstruction.html)
 /// instruction.
+#[allow(clippy::too_many_arguments)]
 pub fn update_governance(
     governance_config_address: &Pubkey,
     proposal_address: &Pubkey,
+    proposal_transaction_address: &Pubkey,
     cooldown_period_seconds: u64,
+    execution_window_seconds: u64,
     proposal_acceptance_threshold: u32,
+    proposal_minimum_stake: u64,
     proposal_rejection_threshold: u32,
+    quorum_threshold: u32,
+    veto_authority: Option<&Pubkey>,
     voting_period_seconds: u64,
 ) -> Instruction {
     let accounts = vec![
-        AccountMeta::new(*governance_config_address, false),
+        AccountMeta::new(*governance_config_address, true),
         AccountMeta::new_readonly(*proposal_address, false),
+        AccountMeta::new_readonly(*proposal_transaction_address, true),
     ];
     let data = PaladinGovernanceInstruction::UpdateGovernance {
         cooldown_period_seconds,
+        execution_window_seconds,
         proposal_acceptance_threshold,
+        proposal_minimum_stake,
         proposal_rejection_threshold,
+        quorum_threshold,
+        veto_authority: OptionalNonZeroPubkey(veto_authority.copied().unwrap_or_default()),
         voting_period_seconds,
     }
     .pack();
     Instruction::new_with_bytes(crate::id(), &data, accounts)
 }
 
-#[cfg(test)]
-mod tests {
-    use {super::*, crate::state::ProposalAccountMeta};
-
-    fn test_pack_unpack(instruction: PaladinGovernanceInstruction) {
-        let packed = instruction.pack();
-        let unpacked = PaladinGovernanceInstruction::unpack(&packed).unwrap();
-        assert_eq!(instruction, unpacked);
-    }
+/// Creates a
+/// [FinalizeProposal](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn finalize_proposal(
+    proposal_address: &Pubkey,
+    proposal_transaction_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*proposal_address, false),
+        AccountMeta::new_readonly(*proposal_transaction_address, false),
+    ];
+    let data = PaladinGovernanceInstruction::FinalizeProposal.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
 
-    #[test]
-    fn test_pack_unpack_create_proposal() {
-        test_pack_unpack(PaladinGovernanceInstruction::CreateProposal);
-    }
+/// Creates a
+/// [CloseProposal](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn close_proposal(
+    stake_authority_address: &Pubkey,
+    proposal_address: &Pubkey,
+    proposal_transaction_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*stake_authority_address, true),
+        AccountMeta::new(*proposal_address, false),
+        AccountMeta::new_readonly(*proposal_transaction_address, false),
+    ];
+    let data = PaladinGovernanceInstruction::CloseProposal.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
 
-    #[test]
+/// Creates a
+/// [CloseProposalVote](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn close_proposal_vote(
+    authority_address: &Pubkey,
+    stake_address: &Pubkey,
+    proposal_vote_address: &Pubkey,
+    proposal_address: &Pubkey,
+    payer_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*authority_address, true),
+        AccountMeta::new_readonly(*stake_address, false),
+        AccountMeta::new(*proposal_vote_address, false),
+        AccountMeta::new_readonly(*proposal_address, false),
+        AccountMeta::new(*payer_address, false),
+    ];
+    let data = PaladinGovernanceInstruction::CloseProposalVote.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [CloseProposalTransaction](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn close_proposal_transaction(
+    stake_authority_address: &Pubkey,
+    proposal_address: &Pubkey,
+    proposal_transaction_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*stake_authority_address, true),
+        AccountMeta::new_readonly(*proposal_address, false),
+        AccountMeta::new(*proposal_transaction_address, false),
+    ];
+    let data = PaladinGovernanceInstruction::CloseProposalTransaction.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [SetVoteDelegation](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn set_vote_delegation(
+    stake_authority_address: &Pubkey,
+    stake_address: &Pubkey,
+    stake_config_address: &Pubkey,
+    governance_config_address: &Pubkey,
+    vote_delegation_address: &Pubkey,
+    payer_address: &Pubkey,
+    delegate: &Pubkey,
+    expiry_timestamp: Option<NonZeroU64>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*stake_authority_address, true),
+        AccountMeta::new_readonly(*stake_address, false),
+        AccountMeta::new_readonly(*stake_config_address, false),
+        AccountMeta::new_readonly(*governance_config_address, false),
+        AccountMeta::new(*vote_delegation_address, false),
+        AccountMeta::new(*payer_address, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    let data = PaladinGovernanceInstruction::SetVoteDelegation {
+        delegate: *delegate,
+        expiry_timestamp,
+    }
+    .pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [RevokeVoteDelegation](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn revoke_vote_delegation(
+    stake_authority_address: &Pubkey,
+    stake_address: &Pubkey,
+    stake_config_address: &Pubkey,
+    vote_delegation_address: &Pubkey,
+    payer_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*stake_authority_address, true),
+        AccountMeta::new_readonly(*stake_address, false),
+        AccountMeta::new_readonly(*stake_config_address, false),
+        AccountMeta::new(*vote_delegation_address, false),
+        AccountMeta::new(*payer_address, false),
+    ];
+    let data = PaladinGovernanceInstruction::RevokeVoteDelegation.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [MigrateProposalVote](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+#[deprecated(note = "Use `migrate_proposal_vote_account` instead")]
+pub fn migrate_proposal_vote(
+    stake_address: &Pubkey,
+    proposal_vote_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*stake_address, false),
+        AccountMeta::new(*proposal_vote_address, false),
+    ];
+    let data = PaladinGovernanceInstruction::MigrateProposalVote.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [SyncVoteWeight](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn sync_vote_weight(
+    stake_address: &Pubkey,
+    stake_config_address: &Pubkey,
+    proposal_vote_address: &Pubkey,
+    proposal_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*stake_address, false),
+        AccountMeta::new_readonly(*stake_config_address, false),
+        AccountMeta::new(*proposal_vote_address, false),
+        AccountMeta::new(*proposal_address, false),
+    ];
+    let data = PaladinGovernanceInstruction::SyncVoteWeight.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [WithdrawVote](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn withdraw_vote(
+    stake_authority_address: &Pubkey,
+    stake_address: &Pubkey,
+    stake_config_address: &Pubkey,
+    proposal_vote_address: &Pubkey,
+    proposal_address: &Pubkey,
+    payer_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*stake_authority_address, true),
+        AccountMeta::new_readonly(*stake_address, false),
+        AccountMeta::new_readonly(*stake_config_address, false),
+        AccountMeta::new(*proposal_vote_address, false),
+        AccountMeta::new(*proposal_address, false),
+        AccountMeta::new(*payer_address, false),
+    ];
+    let data = PaladinGovernanceInstruction::WithdrawVote.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [WithdrawVote](enum.PaladinGovernanceInstruction.html)
+/// instruction, signed by a vote delegate on behalf of the stake account.
+pub fn withdraw_vote_as_delegate(
+    delegate_address: &Pubkey,
+    stake_address: &Pubkey,
+    stake_config_address: &Pubkey,
+    proposal_vote_address: &Pubkey,
+    proposal_address: &Pubkey,
+    payer_address: &Pubkey,
+    vote_delegation_address: &Pubkey,
+) -> Instruction {
+    let mut instruction = withdraw_vote(
+        delegate_address,
+        stake_address,
+        stake_config_address,
+        proposal_vote_address,
+        proposal_address,
+        payer_address,
+    );
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(*vote_delegation_address, false));
+    instruction
+}
+
+/// Creates a
+/// [VetoProposal](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn veto_proposal(
+    veto_authority_address: &Pubkey,
+    governance_config_address: &Pubkey,
+    proposal_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*veto_authority_address, true),
+        AccountMeta::new_readonly(*governance_config_address, false),
+        AccountMeta::new(*proposal_address, false),
+    ];
+    let data = PaladinGovernanceInstruction::VetoProposal.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates an
+/// [InitializeTreasury](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn initialize_treasury(
+    payer_address: &Pubkey,
+    treasury_address: &Pubkey,
+    governance_config_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*payer_address, true),
+        AccountMeta::new(*treasury_address, false),
+        AccountMeta::new_readonly(*governance_config_address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    let data = PaladinGovernanceInstruction::InitializeTreasury.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates an
+/// [InitializeTreasury](enum.PaladinGovernanceInstruction.html)
+/// instruction, also creating the treasury's associated token account for
+/// the mint.
+pub fn initialize_treasury_token_account(
+    payer_address: &Pubkey,
+    treasury_address: &Pubkey,
+    governance_config_address: &Pubkey,
+    mint_address: &Pubkey,
+    token_program_id: &Pubkey,
+) -> Instruction {
+    let mut instruction =
+        initialize_treasury(payer_address, treasury_address, governance_config_address);
+    instruction.accounts.extend([
+        AccountMeta::new(
+            get_associated_token_address_with_program_id(
+                treasury_address,
+                mint_address,
+                token_program_id,
+            ),
+            false,
+        ),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new_readonly(*token_program_id, false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+    ]);
+    instruction
+}
+
+/// Creates a
+/// [DepositToTreasury](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn deposit_to_treasury(
+    depositor_address: &Pubkey,
+    treasury_address: &Pubkey,
+    governance_config_address: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*depositor_address, true),
+        AccountMeta::new(*treasury_address, false),
+        AccountMeta::new_readonly(*governance_config_address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    let data = PaladinGovernanceInstruction::DepositToTreasury { amount }.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [DepositToTreasury](enum.PaladinGovernanceInstruction.html)
+/// instruction, depositing tokens into the treasury's associated token
+/// account for the mint.
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_tokens_to_treasury(
+    depositor_address: &Pubkey,
+    depositor_token_account_address: &Pubkey,
+    treasury_address: &Pubkey,
+    governance_config_address: &Pubkey,
+    mint_address: &Pubkey,
+    token_program_id: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let mut instruction = deposit_to_treasury(
+        depositor_address,
+        treasury_address,
+        governance_config_address,
+        amount,
+    );
+    instruction.accounts.extend([
+        AccountMeta::new(*depositor_token_account_address, false),
+        AccountMeta::new(
+            get_associated_token_address_with_program_id(
+                treasury_address,
+                mint_address,
+                token_program_id,
+            ),
+            false,
+        ),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new_readonly(*token_program_id, false),
+    ]);
+    instruction
+}
+
+/// Creates a
+/// [TransferFromTreasury](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn transfer_from_treasury(
+    governance_config_address: &Pubkey,
+    proposal_address: &Pubkey,
+    proposal_transaction_address: &Pubkey,
+    treasury_address: &Pubkey,
+    destination_address: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*governance_config_address, false),
+        AccountMeta::new_readonly(*proposal_address, false),
+        AccountMeta::new_readonly(*proposal_transaction_address, true),
+        AccountMeta::new(*treasury_address, false),
+        AccountMeta::new(*destination_address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    let data = PaladinGovernanceInstruction::TransferFromTreasury { amount }.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [TransferFromTreasury](enum.PaladinGovernanceInstruction.html)
+/// instruction, transferring tokens out of the treasury's associated token
+/// account for the mint.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_tokens_from_treasury(
+    governance_config_address: &Pubkey,
+    proposal_address: &Pubkey,
+    proposal_transaction_address: &Pubkey,
+    treasury_address: &Pubkey,
+    destination_token_account_address: &Pubkey,
+    mint_address: &Pubkey,
+    token_program_id: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let mut instruction = transfer_from_treasury(
+        governance_config_address,
+        proposal_address,
+        proposal_transaction_address,
+        treasury_address,
+        destination_token_account_address,
+        amount,
+    );
+    instruction.accounts.extend([
+        AccountMeta::new(
+            get_associated_token_address_with_program_id(
+                treasury_address,
+                mint_address,
+                token_program_id,
+            ),
+            false,
+        ),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new_readonly(*token_program_id, false),
+    ]);
+    instruction
+}
+
+/// Creates a
+/// [MigrateAccount](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn migrate_account(account_address: &Pubkey, payer_address: &Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*account_address, false),
+        AccountMeta::new(*payer_address, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    let data = PaladinGovernanceInstruction::MigrateAccount.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [MigrateAccount](enum.PaladinGovernanceInstruction.html)
+/// instruction, migrating a proposal transaction account from the
+/// unversioned layout.
+pub fn migrate_proposal_transaction_account(
+    proposal_transaction_address: &Pubkey,
+    payer_address: &Pubkey,
+    proposal_address: &Pubkey,
+) -> Instruction {
+    let mut instruction = migrate_account(proposal_transaction_address, payer_address);
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(*proposal_address, false));
+    instruction
+}
+
+/// Creates a
+/// [MigrateAccount](enum.PaladinGovernanceInstruction.html)
+/// instruction, migrating a proposal account in the voting stage from the
+/// unversioned layout.
+pub fn migrate_voting_proposal_account(
+    proposal_address: &Pubkey,
+    payer_address: &Pubkey,
+    stake_config_address: &Pubkey,
+) -> Instruction {
+    let mut instruction = migrate_account(proposal_address, payer_address);
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(*stake_config_address, false));
+    instruction
+}
+
+/// Creates a
+/// [MigrateAccount](enum.PaladinGovernanceInstruction.html)
+/// instruction, migrating a proposal vote account from the unversioned
+/// layout.
+pub fn migrate_proposal_vote_account(
+    proposal_vote_address: &Pubkey,
+    payer_address: &Pubkey,
+    stake_address: &Pubkey,
+) -> Instruction {
+    let mut instruction = migrate_account(proposal_vote_address, payer_address);
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(*stake_address, false));
+    instruction
+}
+
+/// Creates a
+/// [MigrateAccount](enum.PaladinGovernanceInstruction.html)
+/// instruction, migrating a vote delegation account from the version 1
+/// layout.
+pub fn migrate_vote_delegation_account(
+    vote_delegation_address: &Pubkey,
+    payer_address: &Pubkey,
+    stake_address: &Pubkey,
+) -> Instruction {
+    let mut instruction = migrate_account(vote_delegation_address, payer_address);
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(*stake_address, false));
+    instruction
+}
+
+/// Creates a
+/// [CreateInstructionSlot](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn create_instruction_slot(
+    stake_authority_address: &Pubkey,
+    proposal_address: &Pubkey,
+    proposal_transaction_address: &Pubkey,
+    payer_address: &Pubkey,
+    instruction_len: u32,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*stake_authority_address, true),
+        AccountMeta::new_readonly(*proposal_address, false),
+        AccountMeta::new(*proposal_transaction_address, false),
+        AccountMeta::new(*payer_address, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    let data = PaladinGovernanceInstruction::CreateInstructionSlot { instruction_len }.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [WriteInstructionSlot](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn write_instruction_slot(
+    stake_authority_address: &Pubkey,
+    proposal_address: &Pubkey,
+    proposal_transaction_address: &Pubkey,
+    payer_address: &Pubkey,
+    offset: u32,
+    data: Vec<u8>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*stake_authority_address, true),
+        AccountMeta::new_readonly(*proposal_address, false),
+        AccountMeta::new(*proposal_transaction_address, false),
+        AccountMeta::new(*payer_address, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    let data = PaladinGovernanceInstruction::WriteInstructionSlot { offset, data }.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [FinalizeInstructionSlot](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn finalize_instruction_slot(
+    stake_authority_address: &Pubkey,
+    proposal_address: &Pubkey,
+    proposal_transaction_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*stake_authority_address, true),
+        AccountMeta::new_readonly(*proposal_address, false),
+        AccountMeta::new(*proposal_transaction_address, false),
+    ];
+    let data = PaladinGovernanceInstruction::FinalizeInstructionSlot.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::state::ProposalAccountMeta};
+
+    fn test_pack_unpack(instruction: PaladinGovernanceInstruction) {
+        let packed = instruction.pack();
+        let unpacked = PaladinGovernanceInstruction::unpack(&packed).unwrap();
+        assert_eq!(instruction, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_create_proposal() {
+        test_pack_unpack(PaladinGovernanceInstruction::CreateProposal {
+            title: "Raise the acceptance threshold".to_string(),
+            uri: "https://example.com/proposals/1.md".to_string(),
+            content_hash: [7; 32],
+        });
+    }
+
+    #[test]
     fn test_pack_unpack_push_instruction() {
         let program_id = Pubkey::new_unique();
         let account_metas = vec![
@@ -629,9 +1891,21 @@ mod tests {
     fn test_pack_unpack_initialize_governance() {
         test_pack_unpack(PaladinGovernanceInstruction::InitializeGovernance {
             cooldown_period_seconds: 1,
+            execution_window_seconds: 7,
             proposal_acceptance_threshold: 2,
+            proposal_minimum_stake: 5,
             proposal_rejection_threshold: 3,
+            quorum_threshold: 6,
+            veto_authority: OptionalNonZeroPubkey(Pubkey::new_unique()),
             voting_period_seconds: 4,
+            period_bounds: PeriodBounds {
+                min_cooldown_period_seconds: 8,
+                max_cooldown_period_seconds: 9,
+                min_execution_window_seconds: 10,
+                max_execution_window_seconds: 11,
+                min_voting_period_seconds: 12,
+                max_voting_period_seconds: 13,
+            },
         });
     }
 
@@ -639,9 +1913,110 @@ mod tests {
     fn test_pack_unpack_update_governance() {
         test_pack_unpack(PaladinGovernanceInstruction::UpdateGovernance {
             cooldown_period_seconds: 1,
+            execution_window_seconds: 7,
             proposal_acceptance_threshold: 2,
+            proposal_minimum_stake: 5,
             proposal_rejection_threshold: 3,
+            quorum_threshold: 6,
+            veto_authority: OptionalNonZeroPubkey::default(),
             voting_period_seconds: 4,
         });
     }
+
+    #[test]
+    fn test_pack_unpack_finalize_proposal() {
+        test_pack_unpack(PaladinGovernanceInstruction::FinalizeProposal);
+    }
+
+    #[test]
+    fn test_pack_unpack_close_proposal() {
+        test_pack_unpack(PaladinGovernanceInstruction::CloseProposal);
+    }
+
+    #[test]
+    fn test_pack_unpack_close_proposal_vote() {
+        test_pack_unpack(PaladinGovernanceInstruction::CloseProposalVote);
+    }
+
+    #[test]
+    fn test_pack_unpack_close_proposal_transaction() {
+        test_pack_unpack(PaladinGovernanceInstruction::CloseProposalTransaction);
+    }
+
+    #[test]
+    fn test_pack_unpack_set_vote_delegation() {
+        test_pack_unpack(PaladinGovernanceInstruction::SetVoteDelegation {
+            delegate: Pubkey::new_unique(),
+            expiry_timestamp: NonZeroU64::new(1_000),
+        });
+        test_pack_unpack(PaladinGovernanceInstruction::SetVoteDelegation {
+            delegate: Pubkey::new_unique(),
+            expiry_timestamp: None,
+        });
+    }
+
+    #[test]
+    fn test_pack_unpack_revoke_vote_delegation() {
+        test_pack_unpack(PaladinGovernanceInstruction::RevokeVoteDelegation);
+    }
+
+    #[test]
+    fn test_pack_unpack_migrate_proposal_vote() {
+        test_pack_unpack(PaladinGovernanceInstruction::MigrateProposalVote);
+    }
+
+    #[test]
+    fn test_pack_unpack_sync_vote_weight() {
+        test_pack_unpack(PaladinGovernanceInstruction::SyncVoteWeight);
+    }
+
+    #[test]
+    fn test_pack_unpack_withdraw_vote() {
+        test_pack_unpack(PaladinGovernanceInstruction::WithdrawVote);
+    }
+
+    #[test]
+    fn test_pack_unpack_veto_proposal() {
+        test_pack_unpack(PaladinGovernanceInstruction::VetoProposal);
+    }
+
+    #[test]
+    fn test_pack_unpack_initialize_treasury() {
+        test_pack_unpack(PaladinGovernanceInstruction::InitializeTreasury);
+    }
+
+    #[test]
+    fn test_pack_unpack_deposit_to_treasury() {
+        test_pack_unpack(PaladinGovernanceInstruction::DepositToTreasury { amount: 1_000_000 });
+    }
+
+    #[test]
+    fn test_pack_unpack_transfer_from_treasury() {
+        test_pack_unpack(PaladinGovernanceInstruction::TransferFromTreasury { amount: u64::MAX });
+    }
+
+    #[test]
+    fn test_pack_unpack_migrate_account() {
+        test_pack_unpack(PaladinGovernanceInstruction::MigrateAccount);
+    }
+
+    #[test]
+    fn test_pack_unpack_create_instruction_slot() {
+        test_pack_unpack(PaladinGovernanceInstruction::CreateInstructionSlot {
+            instruction_len: 4_096,
+        });
+    }
+
+    #[test]
+    fn test_pack_unpack_write_instruction_slot() {
+        test_pack_unpack(PaladinGovernanceInstruction::WriteInstructionSlot {
+            offset: 1_024,
+            data: vec![5; 900],
+        });
+    }
+
+    #[test]
+    fn test_pack_unpack_finalize_instruction_slot() {
+        test_pack_unpack(PaladinGovernanceInstruction::FinalizeInstructionSlot);
+    }
 }
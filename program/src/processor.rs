@@ -5,31 +5,44 @@ use {
         error::PaladinGovernanceError,
         instruction::PaladinGovernanceInstruction,
         state::{
-            collect_governance_signer_seeds, collect_proposal_transaction_signer_seeds,
-            collect_proposal_vote_signer_seeds, collect_treasury_signer_seeds,
-            get_governance_address_and_bump_seed, get_proposal_transaction_address,
+            collect_governance_signer_seeds, collect_proposal_signer_seeds,
+            collect_proposal_transaction_signer_seeds, collect_proposal_vote_signer_seeds,
+            collect_treasury_signer_seeds, collect_vote_delegation_signer_seeds,
+            deserialize_account, get_governance_address, get_governance_address_and_bump_seed,
+            get_proposal_address_and_bump_seed, get_proposal_transaction_address,
             get_proposal_transaction_address_and_bump_seed, get_proposal_vote_address,
-            get_proposal_vote_address_and_bump_seed, get_treasury_address_and_bump_seed, Config,
-            Proposal, ProposalAccountMeta, ProposalInstruction, ProposalStatus,
-            ProposalTransaction, ProposalVote, ProposalVoteElection,
+            get_proposal_vote_address_and_bump_seed, get_treasury_address_and_bump_seed,
+            get_vote_delegation_address, get_vote_delegation_address_and_bump_seed, load_account,
+            load_account_mut, AccountType, Config, ConfigV0, ConfigV1, PeriodBounds, Proposal,
+            ProposalAccountMeta, ProposalInstruction, ProposalInstructionSlot, ProposalStatus,
+            ProposalTransaction, ProposalTransactionV0, ProposalTransactionV1, ProposalV0,
+            ProposalV1, ProposalVote, ProposalVoteElection, ProposalVoteV0, ProposalVoteV1,
+            ProposalVoteV2, VoteDelegation, VoteDelegationV1, MAX_INSTRUCTION_SLOT_LEN,
         },
     },
-    borsh::BorshDeserialize,
     paladin_stake_program::state::{find_stake_pda, Config as StakeConfig, Stake},
     solana_program::{
         account_info::{next_account_info, AccountInfo},
         borsh1::get_instance_packed_len,
+        bpf_loader_upgradeable::{self, get_program_data_address, UpgradeableLoaderState},
         clock::Clock,
         entrypoint::ProgramResult,
         instruction::Instruction,
         msg,
-        program::invoke_signed,
+        program::{invoke, invoke_signed},
         program_error::ProgramError,
         pubkey::Pubkey,
-        system_instruction,
+        rent::Rent,
+        system_instruction, system_program,
         sysvar::Sysvar,
     },
-    spl_discriminator::{ArrayDiscriminator, SplDiscriminate},
+    spl_associated_token_account::{
+        get_associated_token_address_with_program_id,
+        instruction::create_associated_token_account_idempotent,
+    },
+    spl_discriminator::SplDiscriminate,
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+    spl_token_2022::{extension::StateWithExtensions, instruction::transfer_checked, state::Mint},
     std::num::NonZeroU64,
 };
 
@@ -49,10 +62,76 @@ fn calculate_proposal_vote_threshold(stake: u64, total_stake: u64) -> Result<u32
         .ok_or(ProgramError::ArithmeticOverflow)
 }
 
+// Clears an active cooldown period if the proposal's support has dropped back
+// below the acceptance threshold, so acceptance reflects current support. If
+// support later re-crosses the threshold, a new cooldown period begins in full.
+fn reevaluate_cooldown(proposal_state: &mut Proposal) -> ProgramResult {
+    if proposal_state.cooldown_timestamp.is_some()
+        && calculate_proposal_vote_threshold(proposal_state.stake_for, proposal_state.total_stake)?
+            < proposal_state
+                .governance_config
+                .proposal_acceptance_threshold
+    {
+        proposal_state.cooldown_timestamp = None;
+    }
+    Ok(())
+}
+
+// Returns the status a proposal in the voting stage concludes with, or `None`
+// if its voting period or cooldown period is still active. Every instruction
+// that concludes a proposal goes through here, so the execution window and the
+// quorum requirement apply regardless of which one observes the outcome.
+fn get_concluded_proposal_status(
+    proposal_state: &Proposal,
+    clock: &Clock,
+) -> Result<Option<ProposalStatus>, ProgramError> {
+    // Cooldown periods take precedence over voting periods. For example, if a
+    // voting period expires, but a cooldown period still has time remaining,
+    // the proposal will remain open for voting until the cooldown period ends.
+    let status = if proposal_state.execution_window_has_ended(clock) {
+        // If the cooldown period and the execution window have both ended,
+        // the proposal has expired before it could be processed.
+        ProposalStatus::Expired
+    } else if proposal_state.cooldown_has_ended(clock) {
+        // If the cooldown period has ended, the proposal is accepted.
+        ProposalStatus::Accepted
+    } else if proposal_state.cooldown_timestamp.is_none() && proposal_state.voting_has_ended(clock)
+    {
+        // If the voting period has ended without a cooldown period having
+        // begun, the proposal is rejected.
+        ProposalStatus::Rejected
+    } else {
+        return Ok(None);
+    };
+
+    // Regardless of the outcome, the proposal only concludes if enough stake
+    // participated in the vote.
+    let participating_stake = proposal_state
+        .stake_for
+        .checked_add(proposal_state.stake_against)
+        .and_then(|stake| stake.checked_add(proposal_state.stake_abstained))
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if calculate_proposal_vote_threshold(participating_stake, proposal_state.total_stake)?
+        < proposal_state.governance_config.quorum_threshold
+    {
+        return Ok(Some(ProposalStatus::QuorumNotMet));
+    }
+
+    Ok(Some(status))
+}
+
 fn get_stake_checked(
     authority_key: &Pubkey,
     stake_config_address: &Pubkey,
     stake_info: &AccountInfo,
+) -> Result<u64, ProgramError> {
+    get_stake_checked_inner(Some(authority_key), stake_config_address, stake_info)
+}
+
+fn get_stake_checked_inner(
+    authority_key: Option<&Pubkey>,
+    stake_config_address: &Pubkey,
+    stake_info: &AccountInfo,
 ) -> Result<u64, ProgramError> {
     check_stake_exists(stake_info)?;
 
@@ -60,9 +139,11 @@ fn get_stake_checked(
     let state =
         bytemuck::try_from_bytes::<Stake>(&data).map_err(|_| ProgramError::InvalidAccountData)?;
 
-    // Ensure the stake account belongs to the authority.
-    if state.authority != *authority_key {
-        return Err(ProgramError::IncorrectAuthority);
+    // Ensure the stake account belongs to the authority, if one is required.
+    if let Some(authority_key) = authority_key {
+        if state.authority != *authority_key {
+            return Err(ProgramError::IncorrectAuthority);
+        }
     }
 
     // Ensure the stake account has the correct address derived from the
@@ -81,6 +162,53 @@ fn get_stake_checked(
     Ok(state.amount)
 }
 
+/// Returns the stake account's amount, ensuring the voter is either the stake
+/// account's authority or, if a vote delegation account is provided, the
+/// stake account's unexpired vote delegate for the governance config
+/// corresponding to the stake config.
+fn get_voting_stake_checked(
+    program_id: &Pubkey,
+    voter_key: &Pubkey,
+    stake_config_address: &Pubkey,
+    stake_info: &AccountInfo,
+    vote_delegation_info: Option<&AccountInfo>,
+) -> Result<u64, ProgramError> {
+    let Some(vote_delegation_info) = vote_delegation_info else {
+        return get_stake_checked(voter_key, stake_config_address, stake_info);
+    };
+
+    // Ensure the provided vote delegation address is the correct address
+    // derived from the stake account and governance config.
+    let governance_address = get_governance_address(stake_config_address, program_id);
+    if !vote_delegation_info.key.eq(&get_vote_delegation_address(
+        stake_info.key,
+        &governance_address,
+        program_id,
+    )) {
+        return Err(PaladinGovernanceError::IncorrectVoteDelegationAddress.into());
+    }
+
+    check_vote_delegation_exists(program_id, vote_delegation_info)?;
+
+    {
+        let data = vote_delegation_info.try_borrow_data()?;
+        let state = load_account::<VoteDelegation>(&data)?;
+
+        // Ensure the voter is the delegate.
+        if state.delegate != *voter_key {
+            return Err(ProgramError::IncorrectAuthority);
+        }
+
+        // Ensure the delegation has not expired.
+        let clock = <Clock as Sysvar>::get()?;
+        if state.has_expired(&clock) {
+            return Err(PaladinGovernanceError::VoteDelegationExpired.into());
+        }
+    }
+
+    get_stake_checked_inner(None, stake_config_address, stake_info)
+}
+
 fn check_stake_config_exists(stake_config_info: &AccountInfo) -> ProgramResult {
     // Ensure the stake config account is owned by the Paladin Stake program.
     if stake_config_info.owner != &paladin_stake_program::id() {
@@ -97,6 +225,127 @@ fn check_stake_config_exists(stake_config_info: &AccountInfo) -> ProgramResult {
     Ok(())
 }
 
+fn check_initialize_governance_authority(
+    program_id: &Pubkey,
+    authority_info: &AccountInfo,
+    stake_config_info: &AccountInfo,
+    program_data_info: &AccountInfo,
+) -> ProgramResult {
+    // Ensure the authority is a signer.
+    if !authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // The Paladin stake config authority may initialize governance.
+    {
+        let stake_config_data = stake_config_info.try_borrow_data()?;
+        let stake_config_state = bytemuck::try_from_bytes::<StakeConfig>(&stake_config_data)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+
+        if Option::<Pubkey>::from(stake_config_state.authority) == Some(*authority_info.key) {
+            return Ok(());
+        }
+    }
+
+    // Otherwise, the program's upgrade authority may initialize governance.
+    if !program_data_info
+        .key
+        .eq(&get_program_data_address(program_id))
+    {
+        return Err(PaladinGovernanceError::IncorrectProgramDataAddress.into());
+    }
+
+    if program_data_info.owner == &bpf_loader_upgradeable::id() {
+        let program_data = program_data_info.try_borrow_data()?;
+
+        // Program data accounts begin with the serialized
+        // `UpgradeableLoaderState::ProgramData` variant: a `u32` tag, the `u64`
+        // deployment slot, then the optional upgrade authority.
+        if let Some(metadata) =
+            program_data.get(..UpgradeableLoaderState::size_of_programdata_metadata())
+        {
+            let is_program_data = metadata[0..4] == 3u32.to_le_bytes();
+            let has_upgrade_authority = metadata[12] == 1;
+            if is_program_data
+                && has_upgrade_authority
+                && metadata[13..45] == authority_info.key.to_bytes()
+            {
+                return Ok(());
+            }
+        }
+    }
+
+    Err(ProgramError::IncorrectAuthority)
+}
+
+fn check_governance_params(
+    cooldown_period_seconds: u64,
+    execution_window_seconds: u64,
+    proposal_acceptance_threshold: u32,
+    proposal_rejection_threshold: u32,
+    quorum_threshold: u32,
+    voting_period_seconds: u64,
+    period_bounds: &PeriodBounds,
+) -> ProgramResult {
+    // Each period must be allowed a non-zero length.
+    for (min, max) in [
+        (
+            period_bounds.min_cooldown_period_seconds,
+            period_bounds.max_cooldown_period_seconds,
+        ),
+        (
+            period_bounds.min_execution_window_seconds,
+            period_bounds.max_execution_window_seconds,
+        ),
+        (
+            period_bounds.min_voting_period_seconds,
+            period_bounds.max_voting_period_seconds,
+        ),
+    ] {
+        if min == 0 || min > max {
+            return Err(PaladinGovernanceError::InvalidPeriodBounds.into());
+        }
+    }
+
+    // Thresholds are scaled by `1e9`, so anything above the scaling factor
+    // could never be reached.
+    if proposal_acceptance_threshold == 0
+        || u64::from(proposal_acceptance_threshold) > THRESHOLD_SCALING_FACTOR
+    {
+        return Err(PaladinGovernanceError::InvalidProposalAcceptanceThreshold.into());
+    }
+    if proposal_rejection_threshold == 0
+        || u64::from(proposal_rejection_threshold) > THRESHOLD_SCALING_FACTOR
+    {
+        return Err(PaladinGovernanceError::InvalidProposalRejectionThreshold.into());
+    }
+    // A quorum threshold of zero disables the quorum requirement. It may
+    // exceed the acceptance threshold (for example, a simple majority of a
+    // two-thirds turnout), since the quorum is checked whenever a proposal
+    // concludes, including once it's accepted.
+    if u64::from(quorum_threshold) > THRESHOLD_SCALING_FACTOR {
+        return Err(PaladinGovernanceError::InvalidQuorumThreshold.into());
+    }
+
+    if !(period_bounds.min_cooldown_period_seconds..=period_bounds.max_cooldown_period_seconds)
+        .contains(&cooldown_period_seconds)
+    {
+        return Err(PaladinGovernanceError::InvalidCooldownPeriod.into());
+    }
+    if !(period_bounds.min_execution_window_seconds..=period_bounds.max_execution_window_seconds)
+        .contains(&execution_window_seconds)
+    {
+        return Err(PaladinGovernanceError::InvalidExecutionWindow.into());
+    }
+    if !(period_bounds.min_voting_period_seconds..=period_bounds.max_voting_period_seconds)
+        .contains(&voting_period_seconds)
+    {
+        return Err(PaladinGovernanceError::InvalidVotingPeriod.into());
+    }
+
+    Ok(())
+}
+
 fn check_stake_exists(stake_info: &AccountInfo) -> ProgramResult {
     // Ensure the stake account is owned by the Paladin Stake program.
     if stake_info.owner != &paladin_stake_program::id() {
@@ -119,8 +368,15 @@ fn check_governance_exists(program_id: &Pubkey, governance_info: &AccountInfo) -
         return Err(ProgramError::InvalidAccountOwner);
     }
 
+    // Ensure the governance account has been migrated to the current version.
+    if Config::is_legacy_layout(&governance_info.try_borrow_data()?) {
+        return Err(PaladinGovernanceError::AccountVersionMismatch.into());
+    }
+
     // Ensure the governance account is initialized.
-    if governance_info.data_len() != std::mem::size_of::<Config>() {
+    if !(governance_info.data_len() == std::mem::size_of::<Config>()
+        && &governance_info.try_borrow_data()?[0..8] == Config::SPL_DISCRIMINATOR_SLICE)
+    {
         return Err(ProgramError::UninitializedAccount);
     }
 
@@ -133,6 +389,11 @@ fn check_proposal_exists(program_id: &Pubkey, proposal_info: &AccountInfo) -> Pr
         return Err(ProgramError::InvalidAccountOwner);
     }
 
+    // Ensure the proposal account has been migrated to the current version.
+    if Proposal::is_legacy_layout(&proposal_info.try_borrow_data()?) {
+        return Err(PaladinGovernanceError::AccountVersionMismatch.into());
+    }
+
     // Ensure the proposal account is initialized.
     if !(proposal_info.data_len() == std::mem::size_of::<Proposal>()
         && &proposal_info.try_borrow_data()?[0..8] == Proposal::SPL_DISCRIMINATOR_SLICE)
@@ -153,18 +414,184 @@ fn check_proposal_transaction_exists(
         return Err(ProgramError::InvalidAccountOwner);
     }
 
+    // Ensure the proposal transaction account has been migrated to the
+    // current version.
+    if ProposalTransaction::is_legacy_layout(&proposal_transaction_info.try_borrow_data()?) {
+        return Err(PaladinGovernanceError::AccountVersionMismatch.into());
+    }
+
     // Ensure the proposal transaction account is initialized.
-    if proposal_transaction_info.data_len() == 0 {
+    if !proposal_transaction_info
+        .try_borrow_data()?
+        .starts_with(ProposalTransaction::SPL_DISCRIMINATOR_SLICE)
+    {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    Ok(())
+}
+
+fn check_vote_delegation_exists(
+    program_id: &Pubkey,
+    vote_delegation_info: &AccountInfo,
+) -> ProgramResult {
+    // Ensure the vote delegation account is owned by the Paladin Governance
+    // program.
+    if vote_delegation_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Ensure the vote delegation account has been migrated to the current
+    // version.
+    if VoteDelegation::is_legacy_layout(&vote_delegation_info.try_borrow_data()?) {
+        return Err(PaladinGovernanceError::AccountVersionMismatch.into());
+    }
+
+    // Ensure the vote delegation account is initialized.
+    if !(vote_delegation_info.data_len() == std::mem::size_of::<VoteDelegation>()
+        && &vote_delegation_info.try_borrow_data()?[0..8]
+            == VoteDelegation::SPL_DISCRIMINATOR_SLICE)
+    {
         return Err(ProgramError::UninitializedAccount);
     }
 
     Ok(())
 }
 
+/// Ensure the provided governance config and treasury accounts belong
+/// together, returning the governance config's stake config address and the
+/// treasury's bump seed.
+fn check_treasury(
+    program_id: &Pubkey,
+    governance_info: &AccountInfo,
+    treasury_info: &AccountInfo,
+) -> Result<(Pubkey, u8), ProgramError> {
+    check_governance_exists(program_id, governance_info)?;
+
+    let governance_data = governance_info.try_borrow_data()?;
+    let governance_state = load_account::<Config>(&governance_data)?;
+    let stake_config_address = governance_state.stake_config_address;
+
+    // Ensure the provided governance address is the correct address derived
+    // from the stake config.
+    if !governance_info
+        .key
+        .eq(&get_governance_address(&stake_config_address, program_id))
+    {
+        return Err(PaladinGovernanceError::IncorrectGovernanceConfigAddress.into());
+    }
+
+    // Ensure the provided treasury address is the correct address derived
+    // from the stake config.
+    let (treasury_address, treasury_bump_seed) =
+        get_treasury_address_and_bump_seed(&stake_config_address, program_id);
+    if !treasury_info.key.eq(&treasury_address) {
+        return Err(PaladinGovernanceError::IncorrectTreasuryAddress.into());
+    }
+
+    Ok((stake_config_address, treasury_bump_seed))
+}
+
+fn check_treasury_token_account(
+    treasury_token_account_info: &AccountInfo,
+    treasury_address: &Pubkey,
+    mint_info: &AccountInfo,
+    token_program_info: &AccountInfo,
+) -> ProgramResult {
+    // Ensure the token program is either SPL Token or SPL Token-2022.
+    spl_token_2022::check_spl_token_program_account(token_program_info.key)?;
+
+    // Ensure the provided treasury token account is the treasury's associated
+    // token account for the mint.
+    if !treasury_token_account_info
+        .key
+        .eq(&get_associated_token_address_with_program_id(
+            treasury_address,
+            mint_info.key,
+            token_program_info.key,
+        ))
+    {
+        return Err(PaladinGovernanceError::IncorrectTreasuryTokenAccountAddress.into());
+    }
+
+    Ok(())
+}
+
+fn get_mint_decimals(mint_info: &AccountInfo) -> Result<u8, ProgramError> {
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+    Ok(mint.base.decimals)
+}
+
+fn close_account(account_info: &AccountInfo, destination_info: &AccountInfo) -> ProgramResult {
+    let new_destination_lamports = destination_info
+        .lamports()
+        .checked_add(account_info.lamports())
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Move all lamports to the destination account.
+    **account_info.try_borrow_mut_lamports()? = 0;
+    **destination_info.try_borrow_mut_lamports()? = new_destination_lamports;
+
+    // Clear the data and return the account to the System program.
+    account_info.realloc(0, true)?;
+    account_info.assign(&system_program::id());
+
+    Ok(())
+}
+
+// Transfers exactly the lamports, if any, an account needs from the payer to
+// be rent-exempt at the given size.
+fn top_up_rent_exemption<'a>(
+    payer_info: &AccountInfo<'a>,
+    account_info: &AccountInfo<'a>,
+    space: usize,
+) -> ProgramResult {
+    let rent = <Rent as Sysvar>::get()?;
+    let required_lamports = rent
+        .minimum_balance(space)
+        .saturating_sub(account_info.lamports());
+    if required_lamports > 0 {
+        invoke(
+            &system_instruction::transfer(payer_info.key, account_info.key, required_lamports),
+            &[payer_info.clone(), account_info.clone()],
+        )?;
+    }
+    Ok(())
+}
+
+// Moves any lamports beyond an account's rent-exempt minimum at its current
+// size to the destination.
+fn refund_excess_lamports(
+    account_info: &AccountInfo,
+    destination_info: &AccountInfo,
+) -> ProgramResult {
+    let rent = <Rent as Sysvar>::get()?;
+    let excess_lamports = account_info
+        .lamports()
+        .saturating_sub(rent.minimum_balance(account_info.data_len()));
+    if excess_lamports > 0 {
+        let new_destination_lamports = destination_info
+            .lamports()
+            .checked_add(excess_lamports)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        **account_info.try_borrow_mut_lamports()? -= excess_lamports;
+        **destination_info.try_borrow_mut_lamports()? = new_destination_lamports;
+    }
+    Ok(())
+}
+
 /// Processes a
 /// [CreateProposal](enum.PaladinGovernanceInstruction.html)
 /// instruction.
-fn process_create_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+fn process_create_proposal(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    title: String,
+    uri: String,
+    content_hash: [u8; 32],
+) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
 
     let stake_authority_info = next_account_info(accounts_iter)?;
@@ -172,64 +599,96 @@ fn process_create_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> Pro
     let proposal_info = next_account_info(accounts_iter)?;
     let proposal_transaction_info = next_account_info(accounts_iter)?;
     let governance_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
 
     // Ensure the stake authority is a signer.
     if !stake_authority_info.is_signer {
         return Err(ProgramError::MissingRequiredSignature);
     }
 
-    // Ensure a valid stake account was provided.
-    {
-        check_stake_exists(stake_info)?;
-
-        let data = stake_info.try_borrow_data()?;
-        let state = bytemuck::try_from_bytes::<Stake>(&data)
-            .map_err(|_| ProgramError::InvalidAccountData)?;
-
-        // Ensure the stake account belongs to the authority.
-        if state.authority != *stake_authority_info.key {
-            return Err(ProgramError::IncorrectAuthority);
-        }
+    // Ensure the payer is a signer.
+    if !payer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
     }
 
     check_governance_exists(program_id, governance_info)?;
 
-    let governance_config = {
-        let governance_data = governance_info.try_borrow_data()?;
-        *bytemuck::try_from_bytes::<Config>(&governance_data)
-            .map_err(|_| ProgramError::InvalidAccountData)?
-    };
+    let mut governance_data = governance_info.try_borrow_mut_data()?;
+    let governance_state = load_account_mut::<Config>(&mut governance_data)?;
+
+    // Ensure a valid stake account was provided, belonging to the authority
+    // and to the governance config's stake config.
+    let stake_amount = get_stake_checked(
+        stake_authority_info.key,
+        &governance_state.stake_config_address,
+        stake_info,
+    )?;
+
+    // Ensure the author has enough stake to create a proposal.
+    if stake_amount < governance_state.proposal_minimum_stake {
+        return Err(PaladinGovernanceError::ProposalMinimumStakeNotMet.into());
+    }
 
     // Initialize the proposal account.
     {
-        // Ensure the proposal account is owned by the Paladin Governance program.
-        if proposal_info.owner != program_id {
-            return Err(ProgramError::InvalidAccountOwner);
-        }
+        let proposal_index = governance_state.proposal_count.to_le_bytes();
+        let (proposal_address, signer_bump_seed) = get_proposal_address_and_bump_seed(
+            governance_info.key,
+            governance_state.proposal_count,
+            program_id,
+        );
+        let bump_seed = [signer_bump_seed];
+        let proposal_signer_seeds =
+            collect_proposal_signer_seeds(governance_info.key, &proposal_index, &bump_seed);
 
-        // Ensure the proposal account has enough space.
-        if proposal_info.data_len() != std::mem::size_of::<Proposal>() {
-            return Err(ProgramError::InvalidAccountData);
+        // Ensure the provided proposal address is the correct address derived
+        // from the governance config and its next proposal index.
+        if !proposal_info.key.eq(&proposal_address) {
+            return Err(PaladinGovernanceError::IncorrectProposalAddress.into());
         }
 
-        // Ensure the proposal account is not initialized.
-        if &proposal_info.try_borrow_data()?[0..8] != ArrayDiscriminator::UNINITIALIZED.as_slice() {
+        // Ensure the proposal account has not already been initialized.
+        if proposal_info.data_len() != 0 {
             return Err(ProgramError::AccountAlreadyInitialized);
         }
 
+        let space = std::mem::size_of::<Proposal>();
+
+        // Fund, allocate & assign.
+        top_up_rent_exemption(payer_info, proposal_info, space)?;
+        invoke_signed(
+            &system_instruction::allocate(&proposal_address, space as u64),
+            &[proposal_info.clone()],
+            &[&proposal_signer_seeds],
+        )?;
+        invoke_signed(
+            &system_instruction::assign(&proposal_address, program_id),
+            &[proposal_info.clone()],
+            &[&proposal_signer_seeds],
+        )?;
+
         let clock = <Clock as Sysvar>::get()?;
         let creation_timestamp = clock.unix_timestamp;
 
-        // Write the data.
-        let mut proposal_data = proposal_info.try_borrow_mut_data()?;
-        *bytemuck::try_from_bytes_mut::<Proposal>(&mut proposal_data)
-            .map_err(|_| ProgramError::InvalidAccountData)? = Proposal::new(
+        let mut proposal_state = Proposal::new(
             stake_authority_info.key,
             creation_timestamp,
-            governance_config,
+            *governance_state,
         );
+        proposal_state.set_metadata(&title, &uri, content_hash)?;
+
+        // Write the data.
+        let mut proposal_data = proposal_info.try_borrow_mut_data()?;
+        *load_account_mut::<Proposal>(&mut proposal_data)? = proposal_state;
     }
 
+    // Increment the governance config's proposal count.
+    governance_state.proposal_count = governance_state
+        .proposal_count
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
     // Initialize the proposal transaction account.
     {
         let (proposal_transaction_address, signer_bump_seed) =
@@ -254,11 +713,12 @@ fn process_create_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> Pro
         }
 
         let state = ProposalTransaction::default();
-        let space = get_instance_packed_len(&state)? as u64;
+        let space = get_instance_packed_len(&state)?;
 
-        // Allocate & assign.
+        // Fund, allocate & assign.
+        top_up_rent_exemption(payer_info, proposal_transaction_info, space)?;
         invoke_signed(
-            &system_instruction::allocate(&proposal_transaction_address, space),
+            &system_instruction::allocate(&proposal_transaction_address, space as u64),
             &[proposal_transaction_info.clone()],
             &[&proposal_transaction_signer_seeds],
         )?;
@@ -290,17 +750,23 @@ fn process_push_instruction(
     let stake_authority_info = next_account_info(accounts_iter)?;
     let proposal_info = next_account_info(accounts_iter)?;
     let proposal_transaction_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
 
     // Ensure the stake authority is a signer.
     if !stake_authority_info.is_signer {
         return Err(ProgramError::MissingRequiredSignature);
     }
 
+    // Ensure the payer is a signer.
+    if !payer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
     check_proposal_exists(program_id, proposal_info)?;
 
     let proposal_data = proposal_info.try_borrow_data()?;
-    let proposal_state = bytemuck::try_from_bytes::<Proposal>(&proposal_data)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let proposal_state = load_account::<Proposal>(&proposal_data)?;
 
     // Ensure the stake authority is the proposal author.
     proposal_state.check_author(stake_authority_info.key)?;
@@ -325,7 +791,7 @@ fn process_push_instruction(
     check_proposal_transaction_exists(program_id, proposal_transaction_info)?;
 
     let mut proposal_transaction_state =
-        ProposalTransaction::try_from_slice(&proposal_transaction_info.try_borrow_data()?)?;
+        deserialize_account::<ProposalTransaction>(&proposal_transaction_info.try_borrow_data()?)?;
 
     // Insert the instruction.
     let new_instruction = ProposalInstruction::new(
@@ -337,8 +803,9 @@ fn process_push_instruction(
         .instructions
         .push(new_instruction);
 
-    // Reallocate the account.
+    // Fund the account's larger size, then reallocate it.
     let new_len = get_instance_packed_len(&proposal_transaction_state)?;
+    top_up_rent_exemption(payer_info, proposal_transaction_info, new_len)?;
     proposal_transaction_info.realloc(new_len, true)?;
 
     // Write the data.
@@ -372,8 +839,7 @@ fn process_remove_instruction(
     check_proposal_exists(program_id, proposal_info)?;
 
     let proposal_data = proposal_info.try_borrow_data()?;
-    let proposal_state = bytemuck::try_from_bytes::<Proposal>(&proposal_data)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let proposal_state = load_account::<Proposal>(&proposal_data)?;
 
     // Ensure the stake authority is the proposal author.
     proposal_state.check_author(stake_authority_info.key)?;
@@ -398,7 +864,7 @@ fn process_remove_instruction(
     check_proposal_transaction_exists(program_id, proposal_transaction_info)?;
 
     let mut proposal_transaction_state =
-        ProposalTransaction::try_from_slice(&proposal_transaction_info.try_borrow_data()?)?;
+        deserialize_account::<ProposalTransaction>(&proposal_transaction_info.try_borrow_data()?)?;
 
     // Ensure the index is valid.
     let instruction_index = instruction_index as usize;
@@ -411,9 +877,11 @@ fn process_remove_instruction(
         .instructions
         .remove(instruction_index);
 
-    // Reallocate the account.
+    // Reallocate the account, returning the rent no longer needed to the
+    // author.
     let new_len = get_instance_packed_len(&proposal_transaction_state)?;
     proposal_transaction_info.realloc(new_len, true)?;
+    refund_excess_lamports(proposal_transaction_info, stake_authority_info)?;
 
     // Write the data.
     borsh::to_writer(
@@ -441,8 +909,7 @@ fn process_cancel_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> Pro
     check_proposal_exists(program_id, proposal_info)?;
 
     let mut proposal_data = proposal_info.try_borrow_mut_data()?;
-    let proposal_state = bytemuck::try_from_bytes_mut::<Proposal>(&mut proposal_data)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let proposal_state = load_account_mut::<Proposal>(&mut proposal_data)?;
 
     // Ensure the stake authority is the proposal author.
     proposal_state.check_author(stake_authority_info.key)?;
@@ -453,9 +920,10 @@ fn process_cancel_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> Pro
         ProposalStatus::Cancelled
         | ProposalStatus::Accepted
         | ProposalStatus::Rejected
-        | ProposalStatus::Processed => {
-            return Err(PaladinGovernanceError::ProposalIsImmutable.into())
-        }
+        | ProposalStatus::Processed
+        | ProposalStatus::QuorumNotMet
+        | ProposalStatus::Expired
+        | ProposalStatus::Vetoed => return Err(PaladinGovernanceError::ProposalIsImmutable.into()),
     }
 
     // Set the proposal's status to cancelled.
@@ -472,6 +940,9 @@ fn process_begin_voting(program_id: &Pubkey, accounts: &[AccountInfo]) -> Progra
 
     let stake_authority_info = next_account_info(accounts_iter)?;
     let proposal_info = next_account_info(accounts_iter)?;
+    let stake_config_info = next_account_info(accounts_iter)?;
+    let stake_info = next_account_info(accounts_iter)?;
+    let proposal_transaction_info = next_account_info(accounts_iter)?;
 
     // Ensure the stake authority is a signer.
     if !stake_authority_info.is_signer {
@@ -481,8 +952,7 @@ fn process_begin_voting(program_id: &Pubkey, accounts: &[AccountInfo]) -> Progra
     check_proposal_exists(program_id, proposal_info)?;
 
     let mut proposal_data = proposal_info.try_borrow_mut_data()?;
-    let proposal_state = bytemuck::try_from_bytes_mut::<Proposal>(&mut proposal_data)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let proposal_state = load_account_mut::<Proposal>(&mut proposal_data)?;
 
     // Ensure the stake authority is the proposal author.
     proposal_state.check_author(stake_authority_info.key)?;
@@ -492,6 +962,57 @@ fn process_begin_voting(program_id: &Pubkey, accounts: &[AccountInfo]) -> Progra
         return Err(PaladinGovernanceError::ProposalIsImmutable.into());
     }
 
+    check_stake_config_exists(stake_config_info)?;
+
+    // Ensure the address of the provided stake config account matches the one
+    // stored in the proposal's governance config.
+    proposal_state
+        .governance_config
+        .check_stake_config(stake_config_info.key)?;
+
+    // Ensure the author still has enough stake to put the proposal to a vote.
+    let stake = get_stake_checked(stake_authority_info.key, stake_config_info.key, stake_info)?;
+    if stake < proposal_state.governance_config.proposal_minimum_stake {
+        return Err(PaladinGovernanceError::ProposalMinimumStakeNotMet.into());
+    }
+
+    // Ensure the provided proposal transaction address is the correct address
+    // derived from the proposal.
+    if !proposal_transaction_info
+        .key
+        .eq(&get_proposal_transaction_address(
+            proposal_info.key,
+            program_id,
+        ))
+    {
+        return Err(PaladinGovernanceError::IncorrectProposalTransactionAddress.into());
+    }
+
+    check_proposal_transaction_exists(program_id, proposal_transaction_info)?;
+
+    let mut proposal_transaction_state =
+        deserialize_account::<ProposalTransaction>(&proposal_transaction_info.try_borrow_data()?)?;
+
+    // Discard any slot not yet finalized, since the transaction can no longer
+    // be modified, returning its rent to the author.
+    if proposal_transaction_state.instruction_slot.take().is_some() {
+        let new_len = get_instance_packed_len(&proposal_transaction_state)?;
+        proposal_transaction_info.realloc(new_len, true)?;
+        refund_excess_lamports(proposal_transaction_info, stake_authority_info)?;
+
+        borsh::to_writer(
+            &mut proposal_transaction_info.data.borrow_mut()[..],
+            &proposal_transaction_state,
+        )?;
+    }
+
+    // Snapshot the total delegated stake, to be used for all vote threshold
+    // calculations.
+    proposal_state.total_stake =
+        bytemuck::try_from_bytes::<StakeConfig>(&stake_config_info.try_borrow_data()?)
+            .map_err(|_| ProgramError::InvalidAccountData)?
+            .token_amount_delegated;
+
     // Set the proposal's status to voting.
     proposal_state.status = ProposalStatus::Voting;
 
@@ -517,28 +1038,37 @@ fn process_vote(
     let stake_config_info = next_account_info(accounts_iter)?;
     let proposal_vote_info = next_account_info(accounts_iter)?;
     let proposal_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
     let _system_program_info = next_account_info(accounts_iter)?;
+    let vote_delegation_info = next_account_info(accounts_iter).ok();
 
-    // Ensure the stake authority is a signer.
+    // Ensure the stake authority or vote delegate is a signer.
     if !stake_authority_info.is_signer {
         return Err(ProgramError::MissingRequiredSignature);
     }
 
-    let stake = get_stake_checked(stake_authority_info.key, stake_config_info.key, stake_info)?;
+    // Ensure the payer is a signer.
+    if !payer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let stake = get_voting_stake_checked(
+        program_id,
+        stake_authority_info.key,
+        stake_config_info.key,
+        stake_info,
+        vote_delegation_info,
+    )?;
 
     check_stake_config_exists(stake_config_info)?;
-    let total_stake =
-        bytemuck::try_from_bytes::<StakeConfig>(&stake_config_info.try_borrow_data()?)
-            .map_err(|_| ProgramError::InvalidAccountData)?
-            .token_amount_delegated;
 
     check_proposal_exists(program_id, proposal_info)?;
 
     let mut proposal_data = proposal_info.try_borrow_mut_data()?;
-    let proposal_state = bytemuck::try_from_bytes_mut::<Proposal>(&mut proposal_data)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let proposal_state = load_account_mut::<Proposal>(&mut proposal_data)?;
 
     let governance_config = proposal_state.governance_config;
+    let total_stake = proposal_state.total_stake;
 
     // Ensure the address of the provided stake config account matches the one
     // stored in the proposal's governance config.
@@ -551,19 +1081,9 @@ fn process_vote(
 
     let clock = <Clock as Sysvar>::get()?;
 
-    // If the proposal has an active cooldown period, ensure it has not ended.
-    if proposal_state.cooldown_has_ended(&clock) {
-        // If the cooldown period has ended, the proposal is accepted.
-        proposal_state.status = ProposalStatus::Accepted;
-        return Ok(());
-    }
-
-    // Cooldown periods take precedence over voting periods. For example, if a
-    // voting period expires, but a cooldown period still has time remaining,
-    // the proposal will remain open for voting until the cooldown period ends.
-    // Cooldown periods end only in an accepted or rejected proposal.
-    if proposal_state.cooldown_timestamp.is_none() && proposal_state.voting_has_ended(&clock) {
-        proposal_state.status = ProposalStatus::Rejected;
+    // If the proposal has already concluded, record its outcome instead.
+    if let Some(status) = get_concluded_proposal_status(proposal_state, &clock)? {
+        proposal_state.status = status;
         return Ok(());
     }
 
@@ -576,7 +1096,7 @@ fn process_vote(
             collect_proposal_vote_signer_seeds(stake_info.key, proposal_info.key, &bump_seed);
 
         // Ensure the provided proposal vote address is the correct address
-        // derived from the stake authority and proposal.
+        // derived from the stake account and proposal.
         if !proposal_vote_info.key.eq(&proposal_vote_address) {
             return Err(PaladinGovernanceError::IncorrectProposalVoteAddress.into());
         }
@@ -586,12 +1106,12 @@ fn process_vote(
             return Err(ProgramError::AccountAlreadyInitialized);
         }
 
-        // Allocate & assign.
+        let space = std::mem::size_of::<ProposalVote>();
+
+        // Fund, allocate & assign.
+        top_up_rent_exemption(payer_info, proposal_vote_info, space)?;
         invoke_signed(
-            &system_instruction::allocate(
-                &proposal_vote_address,
-                std::mem::size_of::<ProposalVote>() as u64,
-            ),
+            &system_instruction::allocate(&proposal_vote_address, space as u64),
             &[proposal_vote_info.clone()],
             &[&proposal_vote_signer_seeds],
         )?;
@@ -604,7 +1124,15 @@ fn process_vote(
         // Write the data.
         let mut data = proposal_vote_info.try_borrow_mut_data()?;
         *bytemuck::try_from_bytes_mut(&mut data).map_err(|_| ProgramError::InvalidAccountData)? =
-            ProposalVote::new(proposal_info.key, stake, stake_authority_info.key, election);
+            ProposalVote::new(
+                proposal_info.key,
+                stake,
+                stake_info.key,
+                stake_authority_info.key,
+                payer_info.key,
+                NonZeroU64::new(clock.unix_timestamp as u64),
+                election,
+            );
     }
 
     match election {
@@ -666,27 +1194,30 @@ fn process_switch_vote(
     let stake_config_info = next_account_info(accounts_iter)?;
     let proposal_vote_info = next_account_info(accounts_iter)?;
     let proposal_info = next_account_info(accounts_iter)?;
+    let vote_delegation_info = next_account_info(accounts_iter).ok();
 
-    // Ensure the stake authority is a signer.
+    // Ensure the stake authority or vote delegate is a signer.
     if !stake_authority_info.is_signer {
         return Err(ProgramError::MissingRequiredSignature);
     }
 
-    let stake = get_stake_checked(stake_authority_info.key, stake_config_info.key, stake_info)?;
+    let stake = get_voting_stake_checked(
+        program_id,
+        stake_authority_info.key,
+        stake_config_info.key,
+        stake_info,
+        vote_delegation_info,
+    )?;
 
     check_stake_config_exists(stake_config_info)?;
-    let total_stake =
-        bytemuck::try_from_bytes::<StakeConfig>(&stake_config_info.try_borrow_data()?)
-            .map_err(|_| ProgramError::InvalidAccountData)?
-            .token_amount_delegated;
 
     check_proposal_exists(program_id, proposal_info)?;
 
     let mut proposal_data = proposal_info.try_borrow_mut_data()?;
-    let proposal_state = bytemuck::try_from_bytes_mut::<Proposal>(&mut proposal_data)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let proposal_state = load_account_mut::<Proposal>(&mut proposal_data)?;
 
     let governance_config = proposal_state.governance_config;
+    let total_stake = proposal_state.total_stake;
 
     // Ensure the address of the provided stake config account matches the one
     // stored in the proposal's governance config.
@@ -699,26 +1230,16 @@ fn process_switch_vote(
 
     let clock = <Clock as Sysvar>::get()?;
 
-    // If the proposal has an active cooldown period, ensure it has not ended.
-    if proposal_state.cooldown_has_ended(&clock) {
-        // If the cooldown period has ended, the proposal is accepted.
-        proposal_state.status = ProposalStatus::Accepted;
-        return Ok(());
-    }
-
-    // Cooldown periods take precedence over voting periods. For example, if a
-    // voting period expires, but a cooldown period still has time remaining,
-    // the proposal will remain open for voting until the cooldown period ends.
-    // Cooldown periods end only in an accepted or rejected proposal.
-    if proposal_state.cooldown_timestamp.is_none() && proposal_state.voting_has_ended(&clock) {
-        proposal_state.status = ProposalStatus::Rejected;
+    // If the proposal has already concluded, record its outcome instead.
+    if let Some(status) = get_concluded_proposal_status(proposal_state, &clock)? {
+        proposal_state.status = status;
         return Ok(());
     }
 
     // Update the proposal vote account.
     let (last_election, last_stake) = {
         // Ensure the provided proposal vote address is the correct address
-        // derived from the stake authority and proposal.
+        // derived from the stake account and proposal.
         if !proposal_vote_info.key.eq(&get_proposal_vote_address(
             stake_info.key,
             proposal_info.key,
@@ -733,15 +1254,18 @@ fn process_switch_vote(
             return Err(ProgramError::InvalidAccountOwner);
         }
 
-        // Ensure the proposal vote account is initialized.
-        if proposal_vote_info.data_len() != std::mem::size_of::<ProposalVote>() {
+        // Ensure the proposal vote account is initialized. Proposal votes not
+        // yet migrated to the current version are rejected when loaded.
+        if proposal_vote_info.data_is_empty() {
             return Err(ProgramError::UninitializedAccount);
         }
 
         // Update the vote.
         let mut data = proposal_vote_info.try_borrow_mut_data()?;
-        let state = bytemuck::try_from_bytes_mut::<ProposalVote>(&mut data)
-            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let state = load_account_mut::<ProposalVote>(&mut data)?;
+
+        state.authority_address = *stake_authority_info.key;
+        state.vote_timestamp = NonZeroU64::new(clock.unix_timestamp as u64);
 
         (
             std::mem::replace(&mut state.election, new_election),
@@ -816,7 +1340,9 @@ fn process_switch_vote(
         }
     }
 
-    Ok(())
+    // Switching away from a vote in favor may drop support below the
+    // acceptance threshold.
+    reevaluate_cooldown(proposal_state)
 }
 
 /// Processes a
@@ -834,23 +1360,33 @@ fn process_process_instruction(
 
     check_proposal_exists(program_id, proposal_info)?;
 
-    let mut proposal_data = proposal_info.try_borrow_mut_data()?;
-    let proposal_state = bytemuck::try_from_bytes_mut::<Proposal>(&mut proposal_data)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+    // Copy the proposal state, rather than holding a borrow, since the
+    // proposal account may be passed to the invoked instruction.
+    let proposal_state = {
+        let proposal_data = proposal_info.try_borrow_data()?;
+        *load_account::<Proposal>(&proposal_data)?
+    };
 
     // Ensure the proposal was accepted.
     if proposal_state.status != ProposalStatus::Accepted {
         return Err(PaladinGovernanceError::ProposalNotAccepted.into());
     }
 
+    let clock = <Clock as Sysvar>::get()?;
+
+    // Ensure the proposal's execution window has not ended.
+    if proposal_state.execution_window_has_ended(&clock) {
+        return Err(PaladinGovernanceError::ProposalExecutionWindowEnded.into());
+    }
+
+    let (proposal_transaction_address, proposal_transaction_bump_seed) =
+        get_proposal_transaction_address_and_bump_seed(proposal_info.key, program_id);
+
     // Ensure the provided proposal transaction account has the correct address
     // derived from the proposal.
     if !proposal_transaction_info
         .key
-        .eq(&get_proposal_transaction_address(
-            proposal_info.key,
-            program_id,
-        ))
+        .eq(&proposal_transaction_address)
     {
         return Err(PaladinGovernanceError::IncorrectProposalTransactionAddress.into());
     }
@@ -858,7 +1394,7 @@ fn process_process_instruction(
     check_proposal_transaction_exists(program_id, proposal_transaction_info)?;
 
     let mut proposal_transaction_state =
-        ProposalTransaction::try_from_slice(&proposal_transaction_info.try_borrow_data()?)?;
+        deserialize_account::<ProposalTransaction>(&proposal_transaction_info.try_borrow_data()?)?;
 
     // Ensure the index is valid.
     let instruction_index = instruction_index as usize;
@@ -881,21 +1417,39 @@ fn process_process_instruction(
     }
 
     // Execute the instruction.
+    //
+    // The treasury signs for any funds the instruction moves, the governance
+    // config signs for governance updates, and the proposal transaction signs
+    // to identify which proposal is being executed.
     {
-        let (_treasury_address, signer_bump_seed) = get_treasury_address_and_bump_seed(
-            &proposal_state.governance_config.stake_config_address,
-            program_id,
-        );
-        let bump_seed = [signer_bump_seed];
-        let treasury_signer_seeds = collect_treasury_signer_seeds(
-            &proposal_state.governance_config.stake_config_address,
-            &bump_seed,
+        let stake_config_address = &proposal_state.governance_config.stake_config_address;
+
+        let (_treasury_address, treasury_bump_seed) =
+            get_treasury_address_and_bump_seed(stake_config_address, program_id);
+        let treasury_bump_seed = [treasury_bump_seed];
+        let treasury_signer_seeds =
+            collect_treasury_signer_seeds(stake_config_address, &treasury_bump_seed);
+
+        let (_governance_address, governance_bump_seed) =
+            get_governance_address_and_bump_seed(stake_config_address, program_id);
+        let governance_bump_seed = [governance_bump_seed];
+        let governance_signer_seeds =
+            collect_governance_signer_seeds(stake_config_address, &governance_bump_seed);
+
+        let proposal_transaction_bump_seed = [proposal_transaction_bump_seed];
+        let proposal_transaction_signer_seeds = collect_proposal_transaction_signer_seeds(
+            proposal_info.key,
+            &proposal_transaction_bump_seed,
         );
 
         invoke_signed(
             &Instruction::from(instruction),
             accounts_iter.as_slice(),
-            &[&treasury_signer_seeds],
+            &[
+                &treasury_signer_seeds,
+                &governance_signer_seeds,
+                &proposal_transaction_signer_seeds,
+            ],
         )?;
     }
 
@@ -908,28 +1462,57 @@ fn process_process_instruction(
         &proposal_transaction_state,
     )?;
 
+    // If every instruction has now been executed, mark the proposal as
+    // processed.
+    if proposal_transaction_state
+        .instructions
+        .iter()
+        .all(|instruction| instruction.executed)
+    {
+        let mut proposal_data = proposal_info.try_borrow_mut_data()?;
+        let proposal_state = load_account_mut::<Proposal>(&mut proposal_data)?;
+
+        proposal_state.status = ProposalStatus::Processed;
+        proposal_state.execution_timestamp = NonZeroU64::new(clock.unix_timestamp as u64);
+    }
+
     Ok(())
 }
 
 /// Processes a
 /// [InitializeGovernance](enum.PaladinGovernanceInstruction.html)
 /// instruction.
+#[allow(clippy::too_many_arguments)]
 fn process_initialize_governance(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     cooldown_period_seconds: u64,
+    execution_window_seconds: u64,
     proposal_acceptance_threshold: u32,
+    proposal_minimum_stake: u64,
     proposal_rejection_threshold: u32,
+    quorum_threshold: u32,
+    veto_authority: OptionalNonZeroPubkey,
     voting_period_seconds: u64,
+    period_bounds: PeriodBounds,
 ) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
 
     let governance_info = next_account_info(accounts_iter)?;
     let stake_config_info = next_account_info(accounts_iter)?;
     let _system_program_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+    let program_data_info = next_account_info(accounts_iter)?;
 
     check_stake_config_exists(stake_config_info)?;
 
+    check_initialize_governance_authority(
+        program_id,
+        authority_info,
+        stake_config_info,
+        program_data_info,
+    )?;
+
     // Create the governance config account.
     {
         let (governance_address, signer_bump_seed) =
@@ -949,6 +1532,16 @@ fn process_initialize_governance(
             return Err(ProgramError::AccountAlreadyInitialized);
         }
 
+        check_governance_params(
+            cooldown_period_seconds,
+            execution_window_seconds,
+            proposal_acceptance_threshold,
+            proposal_rejection_threshold,
+            quorum_threshold,
+            voting_period_seconds,
+            &period_bounds,
+        )?;
+
         // Allocate & assign.
         invoke_signed(
             &system_instruction::allocate(
@@ -965,16 +1558,23 @@ fn process_initialize_governance(
         )?;
 
         // Write the data.
+        let mut config = Config::new(
+            cooldown_period_seconds,
+            execution_window_seconds,
+            proposal_acceptance_threshold,
+            proposal_minimum_stake,
+            proposal_rejection_threshold,
+            quorum_threshold,
+            signer_bump_seed,
+            stake_config_info.key,
+            Option::<Pubkey>::from(veto_authority).as_ref(),
+            voting_period_seconds,
+        );
+        config.period_bounds = period_bounds;
+
         let mut data = governance_info.try_borrow_mut_data()?;
         *bytemuck::try_from_bytes_mut(&mut data).map_err(|_| ProgramError::InvalidAccountData)? =
-            Config::new(
-                cooldown_period_seconds,
-                proposal_acceptance_threshold,
-                proposal_rejection_threshold,
-                signer_bump_seed,
-                stake_config_info.key,
-                voting_period_seconds,
-            );
+            config;
     }
 
     Ok(())
@@ -983,117 +1583,1670 @@ fn process_initialize_governance(
 /// Processes a
 /// [UpdateGovernance](enum.PaladinGovernanceInstruction.html)
 /// instruction.
+#[allow(clippy::too_many_arguments)]
 fn process_update_governance(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     cooldown_period_seconds: u64,
+    execution_window_seconds: u64,
     proposal_acceptance_threshold: u32,
+    proposal_minimum_stake: u64,
     proposal_rejection_threshold: u32,
+    quorum_threshold: u32,
+    veto_authority: OptionalNonZeroPubkey,
     voting_period_seconds: u64,
 ) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
 
     let governance_info = next_account_info(accounts_iter)?;
     let proposal_info = next_account_info(accounts_iter)?;
+    let proposal_transaction_info = next_account_info(accounts_iter)?;
 
     check_governance_exists(program_id, governance_info)?;
     check_proposal_exists(program_id, proposal_info)?;
 
+    // Ensure the governance config is a signer. The governance PDA can only
+    // sign through a proposal's `ProcessInstruction` instruction.
+    if !governance_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let mut data = governance_info.try_borrow_mut_data()?;
+    let state = load_account_mut::<Config>(&mut data)?;
+
+    // Ensure the provided governance address is the correct address derived
+    // from the stake config.
+    if !governance_info.key.eq(&get_governance_address(
+        &state.stake_config_address,
+        program_id,
+    )) {
+        return Err(PaladinGovernanceError::IncorrectGovernanceConfigAddress.into());
+    }
+
     {
-        // Ensure the proposal meets the acceptance threshold.
         let proposal_data = proposal_info.try_borrow_data()?;
-        let proposal_state = bytemuck::try_from_bytes::<Proposal>(&proposal_data)
-            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let proposal_state = load_account::<Proposal>(&proposal_data)?;
+
+        // Ensure the proposal belongs to this governance config.
+        proposal_state
+            .governance_config
+            .check_stake_config(&state.stake_config_address)?;
+
+        // Ensure the proposal is the one currently being executed. Its
+        // proposal transaction PDA can only sign through the proposal's own
+        // `ProcessInstruction` instruction.
+        if !proposal_transaction_info
+            .key
+            .eq(&get_proposal_transaction_address(
+                proposal_info.key,
+                program_id,
+            ))
+        {
+            return Err(PaladinGovernanceError::IncorrectProposalTransactionAddress.into());
+        }
+        if !proposal_transaction_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
 
+        // Ensure the proposal meets the acceptance threshold.
         let clock = <Clock as Sysvar>::get()?;
 
         if !proposal_state.cooldown_has_ended(&clock) {
             return Err(PaladinGovernanceError::ProposalNotAccepted.into());
         }
-
-        // TODO: This instruction requires a gate to ensure it can only be
-        // invoked from a proposal.
     }
 
-    // Update the governance config.
-    let mut data = governance_info.try_borrow_mut_data()?;
-    let state = bytemuck::try_from_bytes_mut::<Config>(&mut data)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+    check_governance_params(
+        cooldown_period_seconds,
+        execution_window_seconds,
+        proposal_acceptance_threshold,
+        proposal_rejection_threshold,
+        quorum_threshold,
+        voting_period_seconds,
+        &state.period_bounds,
+    )?;
+
+    // Update the governance config. The period bounds are fixed when the
+    // governance config is initialized.
     state.cooldown_period_seconds = cooldown_period_seconds;
+    state.execution_window_seconds = execution_window_seconds;
     state.proposal_acceptance_threshold = proposal_acceptance_threshold;
+    state.proposal_minimum_stake = proposal_minimum_stake;
     state.proposal_rejection_threshold = proposal_rejection_threshold;
+    state.quorum_threshold = quorum_threshold;
+    state.veto_authority = veto_authority;
     state.voting_period_seconds = voting_period_seconds;
 
     Ok(())
 }
 
 /// Processes a
-/// [PaladinGovernanceInstruction](enum.PaladinGovernanceInstruction.html).
-pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> ProgramResult {
-    let instruction = PaladinGovernanceInstruction::unpack(input)?;
-    match instruction {
-        PaladinGovernanceInstruction::CreateProposal => {
-            msg!("Instruction: CreateProposal");
-            process_create_proposal(program_id, accounts)
-        }
-        PaladinGovernanceInstruction::PushInstruction {
-            instruction_program_id,
-            instruction_account_metas,
-            instruction_data,
-        } => {
-            msg!("Instruction: PushInstruction");
-            process_push_instruction(
-                program_id,
-                accounts,
-                instruction_program_id,
-                instruction_account_metas,
-                instruction_data,
-            )
-        }
-        PaladinGovernanceInstruction::RemoveInstruction { instruction_index } => {
-            msg!("Instruction: RemoveInstruction");
-            process_remove_instruction(program_id, accounts, instruction_index)
+/// [FinalizeProposal](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+fn process_finalize_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let proposal_info = next_account_info(accounts_iter)?;
+    let proposal_transaction_info = next_account_info(accounts_iter)?;
+
+    check_proposal_exists(program_id, proposal_info)?;
+
+    // Ensure the provided proposal transaction account has the correct address
+    // derived from the proposal, whichever stage the proposal concludes in.
+    if !proposal_transaction_info
+        .key
+        .eq(&get_proposal_transaction_address(
+            proposal_info.key,
+            program_id,
+        ))
+    {
+        return Err(PaladinGovernanceError::IncorrectProposalTransactionAddress.into());
+    }
+
+    let mut proposal_data = proposal_info.try_borrow_mut_data()?;
+    let proposal_state = load_account_mut::<Proposal>(&mut proposal_data)?;
+
+    let clock = <Clock as Sysvar>::get()?;
+
+    let concluded = match proposal_state.status {
+        ProposalStatus::Voting => {
+            let Some(status) = get_concluded_proposal_status(proposal_state, &clock)? else {
+                // Either the voting period or the cooldown period is still
+                // active.
+                return Err(PaladinGovernanceError::ProposalVotingNotEnded.into());
+            };
+            proposal_state.status = status;
+
+            if status != ProposalStatus::Accepted {
+                return Ok(());
+            }
+            true
         }
-        PaladinGovernanceInstruction::CancelProposal => {
-            msg!("Instruction: CancelProposal");
-            process_cancel_proposal(program_id, accounts)
+        ProposalStatus::Accepted => false,
+        _ => return Err(PaladinGovernanceError::ProposalNotInVotingStage.into()),
+    };
+
+    // Ensure the proposal transaction account is owned by the program before
+    // reading it.
+    check_proposal_transaction_exists(program_id, proposal_transaction_info)?;
+
+    let proposal_transaction_state =
+        deserialize_account::<ProposalTransaction>(&proposal_transaction_info.try_borrow_data()?)?;
+
+    // An accepted proposal without instructions has nothing to execute, so it
+    // is processed as soon as it's accepted.
+    if proposal_transaction_state.instructions.is_empty() {
+        proposal_state.status = ProposalStatus::Processed;
+        proposal_state.execution_timestamp = NonZeroU64::new(clock.unix_timestamp as u64);
+        return Ok(());
+    }
+
+    // An accepted proposal that was not fully processed within its execution
+    // window expires.
+    if !concluded {
+        if !proposal_state.execution_window_has_ended(&clock) {
+            return Err(PaladinGovernanceError::ProposalExecutionWindowNotEnded.into());
         }
-        PaladinGovernanceInstruction::BeginVoting => {
-            msg!("Instruction: BeginVoting");
-            process_begin_voting(program_id, accounts)
+        proposal_state.status = ProposalStatus::Expired;
+    }
+
+    Ok(())
+}
+
+/// Processes a
+/// [CloseProposal](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+fn process_close_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let stake_authority_info = next_account_info(accounts_iter)?;
+    let proposal_info = next_account_info(accounts_iter)?;
+    let proposal_transaction_info = next_account_info(accounts_iter)?;
+
+    // Ensure the stake authority is a signer.
+    if !stake_authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    check_proposal_exists(program_id, proposal_info)?;
+
+    {
+        let proposal_data = proposal_info.try_borrow_data()?;
+        let proposal_state = load_account::<Proposal>(&proposal_data)?;
+
+        // Ensure the stake authority is the proposal author.
+        proposal_state.check_author(stake_authority_info.key)?;
+
+        // Ensure the proposal has terminated.
+        if !proposal_state.status.is_terminal() {
+            return Err(PaladinGovernanceError::ProposalNotTerminal.into());
         }
-        PaladinGovernanceInstruction::Vote { election } => {
-            msg!("Instruction: Vote");
-            process_vote(program_id, accounts, election)
+    }
+
+    // Ensure the provided proposal transaction account has the correct address
+    // derived from the proposal.
+    if !proposal_transaction_info
+        .key
+        .eq(&get_proposal_transaction_address(
+            proposal_info.key,
+            program_id,
+        ))
+    {
+        return Err(PaladinGovernanceError::IncorrectProposalTransactionAddress.into());
+    }
+
+    // Ensure the proposal transaction account has already been closed, so its
+    // lamports are not stranded.
+    if proposal_transaction_info.owner == program_id {
+        return Err(PaladinGovernanceError::ProposalTransactionNotClosed.into());
+    }
+
+    close_account(proposal_info, stake_authority_info)
+}
+
+/// Processes a
+/// [CloseProposalVote](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+fn process_close_proposal_vote(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let authority_info = next_account_info(accounts_iter)?;
+    let stake_info = next_account_info(accounts_iter)?;
+    let proposal_vote_info = next_account_info(accounts_iter)?;
+    let proposal_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+
+    // Ensure the authority is a signer.
+    if !authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure the proposal vote account is owned by the Paladin Governance
+    // program.
+    if proposal_vote_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Determine the stake account the proposal vote address was derived from
+    // and the payer to return its lamports to.
+    //
+    // Proposal votes in the current version record their stake account,
+    // voting authority and payer, so may be closed by either the payer or the
+    // voting authority, even once the stake account has been closed.
+    //
+    // Proposal votes which have not been migrated to the current version may
+    // still be closed, but did not record their payer, so may only be closed
+    // by the stake account's authority, to which their lamports are returned.
+    let (stake_address, payer_address) =
+        if proposal_vote_info.data_len() == std::mem::size_of::<ProposalVote>() {
+            let data = proposal_vote_info.try_borrow_data()?;
+            let state = load_account::<ProposalVote>(&data)?;
+
+            // Ensure the authority is the payer or the voting authority.
+            if !authority_info.key.eq(&state.payer_address)
+                && !authority_info.key.eq(&state.authority_address)
+            {
+                return Err(ProgramError::IncorrectAuthority);
+            }
+
+            (state.stake_address, state.payer_address)
+        } else if ProposalVote::LEGACY_LENS.contains(&proposal_vote_info.data_len()) {
+            check_stake_exists(stake_info)?;
+
+            let data = stake_info.try_borrow_data()?;
+            let state = bytemuck::try_from_bytes::<Stake>(&data)
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+
+            // Ensure the stake account belongs to the authority.
+            if state.authority != *authority_info.key {
+                return Err(ProgramError::IncorrectAuthority);
+            }
+
+            (*stake_info.key, *authority_info.key)
+        } else {
+            return Err(ProgramError::UninitializedAccount);
+        };
+
+    // Ensure the provided proposal vote address is the correct address
+    // derived from the stake account and proposal.
+    if !proposal_vote_info.key.eq(&get_proposal_vote_address(
+        &stake_address,
+        proposal_info.key,
+        program_id,
+    )) {
+        return Err(PaladinGovernanceError::IncorrectProposalVoteAddress.into());
+    }
+
+    // Ensure the provided payer is the one the lamports are returned to.
+    if !payer_info.key.eq(&payer_address) {
+        return Err(PaladinGovernanceError::IncorrectProposalVotePayer.into());
+    }
+
+    // Ensure the proposal has terminated. A proposal account no longer owned by
+    // the program has already been closed.
+    if proposal_info.owner == program_id {
+        check_proposal_exists(program_id, proposal_info)?;
+
+        let proposal_data = proposal_info.try_borrow_data()?;
+        let proposal_state = load_account::<Proposal>(&proposal_data)?;
+
+        if !proposal_state.status.is_terminal() {
+            return Err(PaladinGovernanceError::ProposalNotTerminal.into());
         }
-        PaladinGovernanceInstruction::SwitchVote { new_election } => {
-            msg!("Instruction: SwitchVote");
-            process_switch_vote(program_id, accounts, new_election)
+    }
+
+    close_account(proposal_vote_info, payer_info)
+}
+
+/// Processes a
+/// [CloseProposalTransaction](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+fn process_close_proposal_transaction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let stake_authority_info = next_account_info(accounts_iter)?;
+    let proposal_info = next_account_info(accounts_iter)?;
+    let proposal_transaction_info = next_account_info(accounts_iter)?;
+
+    // Ensure the stake authority is a signer.
+    if !stake_authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    check_proposal_exists(program_id, proposal_info)?;
+
+    {
+        let proposal_data = proposal_info.try_borrow_data()?;
+        let proposal_state = load_account::<Proposal>(&proposal_data)?;
+
+        // Ensure the stake authority is the proposal author.
+        proposal_state.check_author(stake_authority_info.key)?;
+
+        // Ensure the proposal has terminated.
+        if !proposal_state.status.is_terminal() {
+            return Err(PaladinGovernanceError::ProposalNotTerminal.into());
         }
-        PaladinGovernanceInstruction::ProcessInstruction { instruction_index } => {
-            msg!("Instruction: ProcessInstruction");
-            process_process_instruction(program_id, accounts, instruction_index)
+    }
+
+    // Ensure the provided proposal transaction account has the correct address
+    // derived from the proposal.
+    if !proposal_transaction_info
+        .key
+        .eq(&get_proposal_transaction_address(
+            proposal_info.key,
+            program_id,
+        ))
+    {
+        return Err(PaladinGovernanceError::IncorrectProposalTransactionAddress.into());
+    }
+
+    check_proposal_transaction_exists(program_id, proposal_transaction_info)?;
+
+    close_account(proposal_transaction_info, stake_authority_info)
+}
+
+/// Processes a
+/// [SetVoteDelegation](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+fn process_set_vote_delegation(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    delegate: Pubkey,
+    expiry_timestamp: Option<NonZeroU64>,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let stake_authority_info = next_account_info(accounts_iter)?;
+    let stake_info = next_account_info(accounts_iter)?;
+    let stake_config_info = next_account_info(accounts_iter)?;
+    let governance_info = next_account_info(accounts_iter)?;
+    let vote_delegation_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    // Ensure the stake authority is a signer.
+    if !stake_authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure the payer is a signer.
+    if !payer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    get_stake_checked(stake_authority_info.key, stake_config_info.key, stake_info)?;
+
+    check_stake_config_exists(stake_config_info)?;
+
+    check_governance_exists(program_id, governance_info)?;
+
+    // Ensure the provided governance address is the correct address derived
+    // from the stake config.
+    if !governance_info
+        .key
+        .eq(&get_governance_address(stake_config_info.key, program_id))
+    {
+        return Err(PaladinGovernanceError::IncorrectGovernanceConfigAddress.into());
+    }
+
+    // Ensure the expiry, if provided, is in the future.
+    if let Some(expiry_timestamp) = expiry_timestamp {
+        let clock = <Clock as Sysvar>::get()?;
+        if expiry_timestamp.get() <= clock.unix_timestamp as u64 {
+            return Err(PaladinGovernanceError::InvalidVoteDelegationExpiry.into());
+        }
+    }
+
+    let (vote_delegation_address, bump_seed) =
+        get_vote_delegation_address_and_bump_seed(stake_info.key, governance_info.key, program_id);
+
+    // Ensure the provided vote delegation address is the correct address
+    // derived from the stake account and governance config.
+    if !vote_delegation_info.key.eq(&vote_delegation_address) {
+        return Err(PaladinGovernanceError::IncorrectVoteDelegationAddress.into());
+    }
+
+    // Record the payer that funded the account. A replaced delegation keeps
+    // the payer that funded the existing account.
+    let payer_address = if vote_delegation_info.data_len() == 0 {
+        // Create the vote delegation account.
+        let bump_seed = [bump_seed];
+        let vote_delegation_signer_seeds =
+            collect_vote_delegation_signer_seeds(stake_info.key, governance_info.key, &bump_seed);
+
+        let space = std::mem::size_of::<VoteDelegation>();
+
+        // Fund, allocate & assign.
+        top_up_rent_exemption(payer_info, vote_delegation_info, space)?;
+        invoke_signed(
+            &system_instruction::allocate(&vote_delegation_address, space as u64),
+            &[vote_delegation_info.clone()],
+            &[&vote_delegation_signer_seeds],
+        )?;
+        invoke_signed(
+            &system_instruction::assign(&vote_delegation_address, program_id),
+            &[vote_delegation_info.clone()],
+            &[&vote_delegation_signer_seeds],
+        )?;
+
+        *payer_info.key
+    } else {
+        // Otherwise, replace the existing delegation.
+        check_vote_delegation_exists(program_id, vote_delegation_info)?;
+
+        let data = vote_delegation_info.try_borrow_data()?;
+        load_account::<VoteDelegation>(&data)?.payer_address
+    };
+
+    // Write the data.
+    let mut data = vote_delegation_info.try_borrow_mut_data()?;
+    *bytemuck::try_from_bytes_mut(&mut data).map_err(|_| ProgramError::InvalidAccountData)? =
+        VoteDelegation::new(
+            &delegate,
+            expiry_timestamp,
+            governance_info.key,
+            stake_info.key,
+            &payer_address,
+        );
+
+    Ok(())
+}
+
+/// Processes a
+/// [RevokeVoteDelegation](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+fn process_revoke_vote_delegation(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let stake_authority_info = next_account_info(accounts_iter)?;
+    let stake_info = next_account_info(accounts_iter)?;
+    let stake_config_info = next_account_info(accounts_iter)?;
+    let vote_delegation_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+
+    // Ensure the stake authority is a signer.
+    if !stake_authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    get_stake_checked(stake_authority_info.key, stake_config_info.key, stake_info)?;
+
+    // Ensure the provided vote delegation address is the correct address
+    // derived from the stake account and governance config.
+    let governance_address = get_governance_address(stake_config_info.key, program_id);
+    if !vote_delegation_info.key.eq(&get_vote_delegation_address(
+        stake_info.key,
+        &governance_address,
+        program_id,
+    )) {
+        return Err(PaladinGovernanceError::IncorrectVoteDelegationAddress.into());
+    }
+
+    check_vote_delegation_exists(program_id, vote_delegation_info)?;
+
+    // Ensure the provided payer is the one that funded the vote delegation
+    // account, which is refunded its rent-exempt lamports.
+    {
+        let data = vote_delegation_info.try_borrow_data()?;
+        let state = load_account::<VoteDelegation>(&data)?;
+
+        if !payer_info.key.eq(&state.payer_address) {
+            return Err(PaladinGovernanceError::IncorrectVoteDelegationPayer.into());
+        }
+    }
+
+    close_account(vote_delegation_info, payer_info)
+}
+
+/// Processes a
+/// [MigrateProposalVote](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+fn process_migrate_proposal_vote(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let stake_info = next_account_info(accounts_iter)?;
+    let proposal_vote_info = next_account_info(accounts_iter)?;
+
+    // Ensure the proposal vote account is owned by the Paladin Governance
+    // program.
+    if proposal_vote_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Ensure the proposal vote account has not already been migrated.
+    if proposal_vote_info.data_len() == std::mem::size_of::<ProposalVote>() {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    // Ensure the proposal vote account is initialized with the previous
+    // layout.
+    if proposal_vote_info.data_len() != std::mem::size_of::<ProposalVoteV0>() {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    let state = migrate_proposal_vote_v0(program_id, proposal_vote_info, stake_info)?;
+
+    // Reallocate the account.
+    proposal_vote_info.realloc(std::mem::size_of::<ProposalVote>(), true)?;
+
+    // Write the data.
+    let mut data = proposal_vote_info.try_borrow_mut_data()?;
+    *bytemuck::try_from_bytes_mut(&mut data).map_err(|_| ProgramError::InvalidAccountData)? = state;
+
+    Ok(())
+}
+
+/// Processes a
+/// [SyncVoteWeight](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+fn process_sync_vote_weight(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let stake_info = next_account_info(accounts_iter)?;
+    let stake_config_info = next_account_info(accounts_iter)?;
+    let proposal_vote_info = next_account_info(accounts_iter)?;
+    let proposal_info = next_account_info(accounts_iter)?;
+
+    let stake = get_stake_checked_inner(None, stake_config_info.key, stake_info)?;
+
+    check_stake_config_exists(stake_config_info)?;
+
+    check_proposal_exists(program_id, proposal_info)?;
+
+    let mut proposal_data = proposal_info.try_borrow_mut_data()?;
+    let proposal_state = load_account_mut::<Proposal>(&mut proposal_data)?;
+
+    let governance_config = proposal_state.governance_config;
+    let total_stake = proposal_state.total_stake;
+
+    // Ensure the address of the provided stake config account matches the one
+    // stored in the proposal's governance config.
+    governance_config.check_stake_config(stake_config_info.key)?;
+
+    // Ensure the proposal is in the voting stage.
+    if proposal_state.status != ProposalStatus::Voting {
+        return Err(PaladinGovernanceError::ProposalNotInVotingStage.into());
+    }
+
+    let clock = <Clock as Sysvar>::get()?;
+
+    // If the proposal has already concluded, record its outcome instead.
+    if let Some(status) = get_concluded_proposal_status(proposal_state, &clock)? {
+        proposal_state.status = status;
+        return Ok(());
+    }
+
+    // Update the proposal vote account.
+    let (election, last_stake) = {
+        // Ensure the provided proposal vote address is the correct address
+        // derived from the stake account and proposal.
+        if !proposal_vote_info.key.eq(&get_proposal_vote_address(
+            stake_info.key,
+            proposal_info.key,
+            program_id,
+        )) {
+            return Err(PaladinGovernanceError::IncorrectProposalVoteAddress.into());
+        }
+
+        // Ensure the proposal vote account is owned by the Paladin Governance
+        // program.
+        if proposal_vote_info.owner != program_id {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        // Ensure the proposal vote account is initialized. Proposal votes not
+        // yet migrated to the current version are rejected when loaded.
+        if proposal_vote_info.data_is_empty() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        // Update the vote weight.
+        let mut data = proposal_vote_info.try_borrow_mut_data()?;
+        let state = load_account_mut::<ProposalVote>(&mut data)?;
+
+        (state.election, std::mem::replace(&mut state.stake, stake))
+    };
+
+    // Replace the vote's previous weight with its current weight.
+    match election {
+        ProposalVoteElection::For => {
+            proposal_state.stake_for = proposal_state
+                .stake_for
+                .checked_sub(last_stake)
+                .and_then(|stake_for| stake_for.checked_add(stake))
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+
+            if calculate_proposal_vote_threshold(proposal_state.stake_for, total_stake)?
+                >= governance_config.proposal_acceptance_threshold
+                && proposal_state.cooldown_timestamp.is_none()
+            {
+                // If the proposal has met the acceptance threshold, and it's
+                // currently not in a cooldown period, begin a new cooldown period.
+                proposal_state.cooldown_timestamp = NonZeroU64::new(clock.unix_timestamp as u64);
+            }
+        }
+        ProposalVoteElection::Against => {
+            proposal_state.stake_against = proposal_state
+                .stake_against
+                .checked_sub(last_stake)
+                .and_then(|stake_against| stake_against.checked_add(stake))
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+
+            if calculate_proposal_vote_threshold(proposal_state.stake_against, total_stake)?
+                >= governance_config.proposal_rejection_threshold
+            {
+                // If the proposal has met the rejection threshold, reject the proposal.
+                // This is done regardless of any cooldown period.
+                proposal_state.status = ProposalStatus::Rejected;
+            }
+        }
+        ProposalVoteElection::DidNotVote => {
+            proposal_state.stake_abstained = proposal_state
+                .stake_abstained
+                .checked_sub(last_stake)
+                .and_then(|stake_abstained| stake_abstained.checked_add(stake))
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+        }
+    }
+
+    // A vote in favor losing weight may drop support below the acceptance
+    // threshold.
+    reevaluate_cooldown(proposal_state)
+}
+
+/// Processes a
+/// [WithdrawVote](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+fn process_withdraw_vote(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let stake_authority_info = next_account_info(accounts_iter)?;
+    let stake_info = next_account_info(accounts_iter)?;
+    let stake_config_info = next_account_info(accounts_iter)?;
+    let proposal_vote_info = next_account_info(accounts_iter)?;
+    let proposal_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+    let vote_delegation_info = next_account_info(accounts_iter).ok();
+
+    // Ensure the stake authority or vote delegate is a signer.
+    if !stake_authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    get_voting_stake_checked(
+        program_id,
+        stake_authority_info.key,
+        stake_config_info.key,
+        stake_info,
+        vote_delegation_info,
+    )?;
+
+    check_stake_config_exists(stake_config_info)?;
+
+    check_proposal_exists(program_id, proposal_info)?;
+
+    let mut proposal_data = proposal_info.try_borrow_mut_data()?;
+    let proposal_state = load_account_mut::<Proposal>(&mut proposal_data)?;
+
+    // Ensure the address of the provided stake config account matches the one
+    // stored in the proposal's governance config.
+    proposal_state
+        .governance_config
+        .check_stake_config(stake_config_info.key)?;
+
+    // Ensure the proposal is in the voting stage.
+    if proposal_state.status != ProposalStatus::Voting {
+        return Err(PaladinGovernanceError::ProposalNotInVotingStage.into());
+    }
+
+    let clock = <Clock as Sysvar>::get()?;
+
+    // If the proposal has already concluded, record its outcome instead.
+    if let Some(status) = get_concluded_proposal_status(proposal_state, &clock)? {
+        proposal_state.status = status;
+        return Ok(());
+    }
+
+    let (last_election, last_stake) = {
+        // Ensure the provided proposal vote address is the correct address
+        // derived from the stake account and proposal.
+        if !proposal_vote_info.key.eq(&get_proposal_vote_address(
+            stake_info.key,
+            proposal_info.key,
+            program_id,
+        )) {
+            return Err(PaladinGovernanceError::IncorrectProposalVoteAddress.into());
+        }
+
+        // Ensure the proposal vote account is owned by the Paladin Governance
+        // program.
+        if proposal_vote_info.owner != program_id {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        // Ensure the proposal vote account is initialized. Proposal votes not
+        // yet migrated to the current version are rejected when loaded.
+        if proposal_vote_info.data_is_empty() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        let data = proposal_vote_info.try_borrow_data()?;
+        let state = load_account::<ProposalVote>(&data)?;
+
+        // Ensure the provided payer is the one that funded the proposal vote
+        // account, which is refunded its rent-exempt lamports.
+        if !payer_info.key.eq(&state.payer_address) {
+            return Err(PaladinGovernanceError::IncorrectProposalVotePayer.into());
+        }
+
+        (state.election, state.stake)
+    };
+
+    // Deduct the vote's stake from the proposal.
+    match last_election {
+        ProposalVoteElection::For => {
+            proposal_state.stake_for = proposal_state
+                .stake_for
+                .checked_sub(last_stake)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+        }
+        ProposalVoteElection::Against => {
+            proposal_state.stake_against = proposal_state
+                .stake_against
+                .checked_sub(last_stake)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+        }
+        ProposalVoteElection::DidNotVote => {
+            proposal_state.stake_abstained = proposal_state
+                .stake_abstained
+                .checked_sub(last_stake)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+        }
+    }
+
+    // Withdrawing a vote in favor may drop support below the acceptance
+    // threshold.
+    reevaluate_cooldown(proposal_state)?;
+
+    close_account(proposal_vote_info, payer_info)
+}
+
+/// Processes a
+/// [VetoProposal](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+fn process_veto_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let veto_authority_info = next_account_info(accounts_iter)?;
+    let governance_info = next_account_info(accounts_iter)?;
+    let proposal_info = next_account_info(accounts_iter)?;
+
+    // Ensure the veto authority is a signer.
+    if !veto_authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    check_governance_exists(program_id, governance_info)?;
+    check_proposal_exists(program_id, proposal_info)?;
+
+    let governance_data = governance_info.try_borrow_data()?;
+    let governance_state = load_account::<Config>(&governance_data)?;
+
+    // Ensure the provided governance address is the correct address derived
+    // from the stake config.
+    if !governance_info.key.eq(&get_governance_address(
+        &governance_state.stake_config_address,
+        program_id,
+    )) {
+        return Err(PaladinGovernanceError::IncorrectGovernanceConfigAddress.into());
+    }
+
+    // Ensure the veto authority is the governance config's current veto
+    // authority.
+    governance_state.check_veto_authority(veto_authority_info.key)?;
+
+    let mut proposal_data = proposal_info.try_borrow_mut_data()?;
+    let proposal_state = load_account_mut::<Proposal>(&mut proposal_data)?;
+
+    // Ensure the proposal belongs to this governance config.
+    proposal_state
+        .governance_config
+        .check_stake_config(&governance_state.stake_config_address)?;
+
+    // Ensure the proposal is in its cooldown period or accepted.
+    match proposal_state.status {
+        ProposalStatus::Voting if proposal_state.cooldown_timestamp.is_some() => (),
+        ProposalStatus::Accepted => (),
+        _ => return Err(PaladinGovernanceError::ProposalNotVetoable.into()),
+    }
+
+    // Set the proposal's status to vetoed.
+    proposal_state.status = ProposalStatus::Vetoed;
+
+    Ok(())
+}
+
+/// Processes an
+/// [InitializeTreasury](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+fn process_initialize_treasury(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let payer_info = next_account_info(accounts_iter)?;
+    let treasury_info = next_account_info(accounts_iter)?;
+    let governance_info = next_account_info(accounts_iter)?;
+    let system_program_info = next_account_info(accounts_iter)?;
+
+    // Ensure the payer is a signer.
+    if !payer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    check_treasury(program_id, governance_info, treasury_info)?;
+
+    // Top up the treasury to the rent-exempt minimum, so it can receive
+    // deposits of any size.
+    top_up_rent_exemption(payer_info, treasury_info, 0)?;
+
+    // If token accounts were provided, create the treasury's associated token
+    // account for the mint.
+    if let Ok(treasury_token_account_info) = next_account_info(accounts_iter) {
+        let mint_info = next_account_info(accounts_iter)?;
+        let token_program_info = next_account_info(accounts_iter)?;
+        let associated_token_program_info = next_account_info(accounts_iter)?;
+
+        check_treasury_token_account(
+            treasury_token_account_info,
+            treasury_info.key,
+            mint_info,
+            token_program_info,
+        )?;
+
+        invoke(
+            &create_associated_token_account_idempotent(
+                payer_info.key,
+                treasury_info.key,
+                mint_info.key,
+                token_program_info.key,
+            ),
+            &[
+                payer_info.clone(),
+                treasury_token_account_info.clone(),
+                treasury_info.clone(),
+                mint_info.clone(),
+                system_program_info.clone(),
+                token_program_info.clone(),
+                associated_token_program_info.clone(),
+            ],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Processes a
+/// [DepositToTreasury](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+fn process_deposit_to_treasury(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let depositor_info = next_account_info(accounts_iter)?;
+    let treasury_info = next_account_info(accounts_iter)?;
+    let governance_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    // Ensure the depositor is a signer.
+    if !depositor_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    check_treasury(program_id, governance_info, treasury_info)?;
+
+    // If token accounts were provided, deposit tokens into the treasury's
+    // associated token account. Otherwise, deposit lamports.
+    if let Ok(depositor_token_account_info) = next_account_info(accounts_iter) {
+        let treasury_token_account_info = next_account_info(accounts_iter)?;
+        let mint_info = next_account_info(accounts_iter)?;
+        let token_program_info = next_account_info(accounts_iter)?;
+
+        check_treasury_token_account(
+            treasury_token_account_info,
+            treasury_info.key,
+            mint_info,
+            token_program_info,
+        )?;
+
+        let decimals = get_mint_decimals(mint_info)?;
+
+        invoke(
+            &transfer_checked(
+                token_program_info.key,
+                depositor_token_account_info.key,
+                mint_info.key,
+                treasury_token_account_info.key,
+                depositor_info.key,
+                &[],
+                amount,
+                decimals,
+            )?,
+            &[
+                depositor_token_account_info.clone(),
+                mint_info.clone(),
+                treasury_token_account_info.clone(),
+                depositor_info.clone(),
+                token_program_info.clone(),
+            ],
+        )?;
+    } else {
+        invoke(
+            &system_instruction::transfer(depositor_info.key, treasury_info.key, amount),
+            &[depositor_info.clone(), treasury_info.clone()],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Processes a
+/// [TransferFromTreasury](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+fn process_transfer_from_treasury(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let governance_info = next_account_info(accounts_iter)?;
+    let proposal_info = next_account_info(accounts_iter)?;
+    let proposal_transaction_info = next_account_info(accounts_iter)?;
+    let treasury_info = next_account_info(accounts_iter)?;
+    let destination_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    let (stake_config_address, treasury_bump_seed) =
+        check_treasury(program_id, governance_info, treasury_info)?;
+
+    check_proposal_exists(program_id, proposal_info)?;
+
+    {
+        let proposal_data = proposal_info.try_borrow_data()?;
+        let proposal_state = load_account::<Proposal>(&proposal_data)?;
+
+        // Ensure the proposal belongs to this governance config.
+        proposal_state
+            .governance_config
+            .check_stake_config(&stake_config_address)?;
+
+        // Ensure the proposal is the one currently being executed. Its
+        // proposal transaction PDA can only sign through the proposal's own
+        // `ProcessInstruction` instruction.
+        if !proposal_transaction_info
+            .key
+            .eq(&get_proposal_transaction_address(
+                proposal_info.key,
+                program_id,
+            ))
+        {
+            return Err(PaladinGovernanceError::IncorrectProposalTransactionAddress.into());
+        }
+        if !proposal_transaction_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Ensure the proposal meets the acceptance threshold.
+        let clock = <Clock as Sysvar>::get()?;
+
+        if !proposal_state.cooldown_has_ended(&clock) {
+            return Err(PaladinGovernanceError::ProposalNotAccepted.into());
+        }
+    }
+
+    let treasury_bump_seed = [treasury_bump_seed];
+    let treasury_signer_seeds =
+        collect_treasury_signer_seeds(&stake_config_address, &treasury_bump_seed);
+
+    // If token accounts were provided, transfer tokens out of the treasury's
+    // associated token account. Otherwise, transfer lamports.
+    if let Ok(treasury_token_account_info) = next_account_info(accounts_iter) {
+        let mint_info = next_account_info(accounts_iter)?;
+        let token_program_info = next_account_info(accounts_iter)?;
+
+        check_treasury_token_account(
+            treasury_token_account_info,
+            treasury_info.key,
+            mint_info,
+            token_program_info,
+        )?;
+
+        let decimals = get_mint_decimals(mint_info)?;
+
+        invoke_signed(
+            &transfer_checked(
+                token_program_info.key,
+                treasury_token_account_info.key,
+                mint_info.key,
+                destination_info.key,
+                treasury_info.key,
+                &[],
+                amount,
+                decimals,
+            )?,
+            &[
+                treasury_token_account_info.clone(),
+                mint_info.clone(),
+                destination_info.clone(),
+                treasury_info.clone(),
+                token_program_info.clone(),
+            ],
+            &[&treasury_signer_seeds],
+        )?;
+    } else {
+        invoke_signed(
+            &system_instruction::transfer(treasury_info.key, destination_info.key, amount),
+            &[treasury_info.clone(), destination_info.clone()],
+            &[&treasury_signer_seeds],
+        )?;
+    }
+
+    Ok(())
+}
+
+// Reallocates an account to the size of its migrated state, funding any
+// additional rent-exemption from the payer, and writes the state.
+fn write_migrated_account<'a>(
+    payer_info: &AccountInfo<'a>,
+    account_info: &AccountInfo<'a>,
+    data: &[u8],
+) -> ProgramResult {
+    top_up_rent_exemption(payer_info, account_info, data.len())?;
+    account_info.realloc(data.len(), true)?;
+    account_info.try_borrow_mut_data()?.copy_from_slice(data);
+    Ok(())
+}
+
+// Converts an unversioned proposal vote, which did not record its stake
+// account, ensuring the provided stake account is the one its address was
+// derived from.
+fn migrate_proposal_vote_v0(
+    program_id: &Pubkey,
+    proposal_vote_info: &AccountInfo,
+    stake_info: &AccountInfo,
+) -> Result<ProposalVote, ProgramError> {
+    let v0_state =
+        *bytemuck::try_from_bytes::<ProposalVoteV0>(&proposal_vote_info.try_borrow_data()?)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    if !proposal_vote_info.key.eq(&get_proposal_vote_address(
+        stake_info.key,
+        &v0_state.proposal_address,
+        program_id,
+    )) {
+        return Err(PaladinGovernanceError::IncorrectProposalVoteAddress.into());
+    }
+
+    Ok(ProposalVote::from_v0(&v0_state, stake_info.key))
+}
+
+/// Processes a
+/// [MigrateAccount](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+fn process_migrate_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let account_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+    let related_info = next_account_info(accounts_iter).ok();
+
+    // Ensure the payer is a signer.
+    if !payer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure the account is owned by the Paladin Governance program.
+    if account_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let data_len = account_info.data_len();
+
+    // Ensure the account has not already been migrated.
+    {
+        let data = account_info.try_borrow_data()?;
+        if load_account::<Config>(&data).is_ok()
+            || load_account::<Proposal>(&data).is_ok()
+            || load_account::<ProposalVote>(&data).is_ok()
+            || load_account::<VoteDelegation>(&data).is_ok()
+            || deserialize_account::<ProposalTransaction>(&data).is_ok()
+        {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+    }
+
+    // Version 1 vote delegations share the current discriminator, but did
+    // not record their payer, so record the stake account's authority, which
+    // funded them.
+    if VoteDelegation::is_legacy_layout(&account_info.try_borrow_data()?)
+        && account_info
+            .try_borrow_data()?
+            .starts_with(VoteDelegation::SPL_DISCRIMINATOR_SLICE)
+    {
+        let v1_state =
+            *bytemuck::try_from_bytes::<VoteDelegationV1>(&account_info.try_borrow_data()?)
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+        let stake_info = related_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        // Ensure the provided stake account is the one the vote delegation
+        // address was derived from.
+        if !account_info.key.eq(&get_vote_delegation_address(
+            stake_info.key,
+            &v1_state.governance_config_address,
+            program_id,
+        )) {
+            return Err(PaladinGovernanceError::IncorrectVoteDelegationAddress.into());
+        }
+
+        check_stake_exists(stake_info)?;
+        let stake_authority = bytemuck::try_from_bytes::<Stake>(&stake_info.try_borrow_data()?)
+            .map_err(|_| ProgramError::InvalidAccountData)?
+            .authority;
+
+        return write_migrated_account(
+            payer_info,
+            account_info,
+            bytemuck::bytes_of(&VoteDelegation::from_v1(&v1_state, &stake_authority)),
+        );
+    }
+
+    // Version 1 proposal transactions share the current discriminator and
+    // only lack the instruction slot.
+    if ProposalTransaction::is_legacy_layout(&account_info.try_borrow_data()?)
+        && account_info
+            .try_borrow_data()?
+            .starts_with(ProposalTransaction::SPL_DISCRIMINATOR_SLICE)
+    {
+        let v1_state =
+            borsh::from_slice::<ProposalTransactionV1>(&account_info.try_borrow_data()?)?;
+        return write_migrated_account(
+            payer_info,
+            account_info,
+            &borsh::to_vec(&ProposalTransaction::from_v1(v1_state))?,
+        );
+    }
+
+    // Unversioned proposal transactions are a bare list of instructions, so
+    // are verified against the address derived from the provided proposal.
+    if ProposalTransaction::is_legacy_layout(&account_info.try_borrow_data()?) {
+        let proposal_info = related_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        if !account_info.key.eq(&get_proposal_transaction_address(
+            proposal_info.key,
+            program_id,
+        )) {
+            return Err(PaladinGovernanceError::IncorrectProposalTransactionAddress.into());
+        }
+
+        let v0_state =
+            borsh::from_slice::<ProposalTransactionV0>(&account_info.try_borrow_data()?)?;
+        return write_migrated_account(
+            payer_info,
+            account_info,
+            &borsh::to_vec(&ProposalTransaction::from_v0(v0_state))?,
+        );
+    }
+
+    // Version 1 governance configs share the current discriminator and only
+    // lack the period bounds, which are set to their defaults.
+    if Config::is_legacy_layout(&account_info.try_borrow_data()?)
+        && data_len == std::mem::size_of::<ConfigV1>()
+    {
+        let v1_state = *bytemuck::try_from_bytes::<ConfigV1>(&account_info.try_borrow_data()?)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        return write_migrated_account(
+            payer_info,
+            account_info,
+            bytemuck::bytes_of(&Config::from_v1(&v1_state)),
+        );
+    }
+
+    // Unversioned governance configs have no discriminator, so are verified
+    // against the address derived from their contents.
+    if Config::is_legacy_layout(&account_info.try_borrow_data()?) {
+        let v0_state = *bytemuck::try_from_bytes::<ConfigV0>(&account_info.try_borrow_data()?)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        if account_info.key.eq(&get_governance_address(
+            &v0_state.stake_config_address,
+            program_id,
+        )) {
+            return write_migrated_account(
+                payer_info,
+                account_info,
+                bytemuck::bytes_of(&Config::from_v0(&v0_state)),
+            );
+        }
+    }
+
+    // Version 1 proposals share the current discriminator and only lack the
+    // period bounds in their governance config snapshot.
+    if Proposal::is_legacy_layout(&account_info.try_borrow_data()?)
+        && data_len == std::mem::size_of::<ProposalV1>()
+    {
+        let v1_state = *bytemuck::try_from_bytes::<ProposalV1>(&account_info.try_borrow_data()?)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        return write_migrated_account(
+            payer_info,
+            account_info,
+            bytemuck::bytes_of(&Proposal::from_v1(&v1_state)),
+        );
+    }
+
+    // Unversioned proposals share the current discriminator.
+    if Proposal::is_legacy_layout(&account_info.try_borrow_data()?) {
+        let v0_state = *bytemuck::try_from_bytes::<ProposalV0>(&account_info.try_borrow_data()?)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+
+        // Votes on unversioned proposals were measured against the stake
+        // delegated at the time of each vote, so snapshot it for a proposal
+        // still in the voting stage.
+        let total_stake = if v0_state.status == ProposalStatus::Voting {
+            let stake_config_info = related_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+            check_stake_config_exists(stake_config_info)?;
+
+            // Ensure the provided stake config is the one stored in the
+            // proposal's governance config.
+            if !stake_config_info
+                .key
+                .eq(&v0_state.governance_config.stake_config_address)
+            {
+                return Err(PaladinGovernanceError::IncorrectStakeConfig.into());
+            }
+
+            bytemuck::try_from_bytes::<StakeConfig>(&stake_config_info.try_borrow_data()?)
+                .map_err(|_| ProgramError::InvalidAccountData)?
+                .token_amount_delegated
+        } else {
+            0
+        };
+
+        return write_migrated_account(
+            payer_info,
+            account_info,
+            bytemuck::bytes_of(&Proposal::from_v0(&v0_state, total_stake)),
+        );
+    }
+
+    // Version 1 proposal votes have no discriminator, so are verified against
+    // the address derived from their contents.
+    if data_len == std::mem::size_of::<ProposalVoteV1>() {
+        let v1_state =
+            *bytemuck::try_from_bytes::<ProposalVoteV1>(&account_info.try_borrow_data()?)
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+        if v1_state.version == 1
+            && account_info.key.eq(&get_proposal_vote_address(
+                &v1_state.stake_address,
+                &v1_state.proposal_address,
+                program_id,
+            ))
+        {
+            return write_migrated_account(
+                payer_info,
+                account_info,
+                bytemuck::bytes_of(&ProposalVote::from_v1(&v1_state)),
+            );
+        }
+    }
+
+    // Version 2 proposal votes share the current discriminator, so are
+    // detected by their version.
+    if data_len == std::mem::size_of::<ProposalVoteV2>() {
+        let v2_state =
+            *bytemuck::try_from_bytes::<ProposalVoteV2>(&account_info.try_borrow_data()?)
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+        if v2_state.discriminator == ProposalVote::SPL_DISCRIMINATOR_SLICE && v2_state.version == 2
+        {
+            return write_migrated_account(
+                payer_info,
+                account_info,
+                bytemuck::bytes_of(&ProposalVote::from_v2(&v2_state)),
+            );
+        }
+    }
+
+    if data_len == std::mem::size_of::<ProposalVoteV0>() {
+        let stake_info = related_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let state = migrate_proposal_vote_v0(program_id, account_info, stake_info)?;
+        return write_migrated_account(payer_info, account_info, bytemuck::bytes_of(&state));
+    }
+
+    Err(ProgramError::InvalidAccountData)
+}
+
+/// Processes a
+/// [CreateInstructionSlot](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+fn process_create_instruction_slot(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_len: u32,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let stake_authority_info = next_account_info(accounts_iter)?;
+    let proposal_info = next_account_info(accounts_iter)?;
+    let proposal_transaction_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    // Ensure the stake authority is a signer.
+    if !stake_authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure the payer is a signer.
+    if !payer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure the declared length does not exceed the maximum.
+    if instruction_len > MAX_INSTRUCTION_SLOT_LEN {
+        return Err(PaladinGovernanceError::InstructionSlotTooLong.into());
+    }
+
+    check_proposal_exists(program_id, proposal_info)?;
+
+    let proposal_data = proposal_info.try_borrow_data()?;
+    let proposal_state = load_account::<Proposal>(&proposal_data)?;
+
+    // Ensure the stake authority is the proposal author.
+    proposal_state.check_author(stake_authority_info.key)?;
+
+    // Ensure the proposal is in draft stage.
+    if proposal_state.status != ProposalStatus::Draft {
+        return Err(PaladinGovernanceError::ProposalIsImmutable.into());
+    }
+
+    // Ensure the provided proposal transaction address is the correct address
+    // derived from the proposal.
+    if !proposal_transaction_info
+        .key
+        .eq(&get_proposal_transaction_address(
+            proposal_info.key,
+            program_id,
+        ))
+    {
+        return Err(PaladinGovernanceError::IncorrectProposalTransactionAddress.into());
+    }
+
+    check_proposal_transaction_exists(program_id, proposal_transaction_info)?;
+
+    let mut proposal_transaction_state =
+        deserialize_account::<ProposalTransaction>(&proposal_transaction_info.try_borrow_data()?)?;
+
+    // Create the slot, discarding any slot not yet finalized.
+    proposal_transaction_state.instruction_slot =
+        Some(ProposalInstructionSlot::new(instruction_len));
+
+    // Fund the account's new size, then reallocate it, returning any rent no
+    // longer needed to the author.
+    let new_len = get_instance_packed_len(&proposal_transaction_state)?;
+    top_up_rent_exemption(payer_info, proposal_transaction_info, new_len)?;
+    proposal_transaction_info.realloc(new_len, true)?;
+    refund_excess_lamports(proposal_transaction_info, stake_authority_info)?;
+
+    // Write the data.
+    borsh::to_writer(
+        &mut proposal_transaction_info.data.borrow_mut()[..],
+        &proposal_transaction_state,
+    )?;
+
+    Ok(())
+}
+
+/// Processes a
+/// [WriteInstructionSlot](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+fn process_write_instruction_slot(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    offset: u32,
+    data: Vec<u8>,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let stake_authority_info = next_account_info(accounts_iter)?;
+    let proposal_info = next_account_info(accounts_iter)?;
+    let proposal_transaction_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    // Ensure the stake authority is a signer.
+    if !stake_authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure the payer is a signer.
+    if !payer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    check_proposal_exists(program_id, proposal_info)?;
+
+    let proposal_data = proposal_info.try_borrow_data()?;
+    let proposal_state = load_account::<Proposal>(&proposal_data)?;
+
+    // Ensure the stake authority is the proposal author.
+    proposal_state.check_author(stake_authority_info.key)?;
+
+    // Ensure the proposal is in draft stage.
+    if proposal_state.status != ProposalStatus::Draft {
+        return Err(PaladinGovernanceError::ProposalIsImmutable.into());
+    }
+
+    // Ensure the provided proposal transaction address is the correct address
+    // derived from the proposal.
+    if !proposal_transaction_info
+        .key
+        .eq(&get_proposal_transaction_address(
+            proposal_info.key,
+            program_id,
+        ))
+    {
+        return Err(PaladinGovernanceError::IncorrectProposalTransactionAddress.into());
+    }
+
+    check_proposal_transaction_exists(program_id, proposal_transaction_info)?;
+
+    let mut proposal_transaction_state =
+        deserialize_account::<ProposalTransaction>(&proposal_transaction_info.try_borrow_data()?)?;
+
+    // Write the chunk to the slot.
+    proposal_transaction_state
+        .instruction_slot
+        .as_mut()
+        .ok_or(PaladinGovernanceError::InstructionSlotNotCreated)?
+        .write(offset, &data)?;
+
+    // Fund the account's larger size, if any, then reallocate it.
+    let new_len = get_instance_packed_len(&proposal_transaction_state)?;
+    top_up_rent_exemption(payer_info, proposal_transaction_info, new_len)?;
+    proposal_transaction_info.realloc(new_len, true)?;
+
+    // Write the data.
+    borsh::to_writer(
+        &mut proposal_transaction_info.data.borrow_mut()[..],
+        &proposal_transaction_state,
+    )?;
+
+    Ok(())
+}
+
+/// Processes a
+/// [FinalizeInstructionSlot](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+fn process_finalize_instruction_slot(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let stake_authority_info = next_account_info(accounts_iter)?;
+    let proposal_info = next_account_info(accounts_iter)?;
+    let proposal_transaction_info = next_account_info(accounts_iter)?;
+
+    // Ensure the stake authority is a signer.
+    if !stake_authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    check_proposal_exists(program_id, proposal_info)?;
+
+    let proposal_data = proposal_info.try_borrow_data()?;
+    let proposal_state = load_account::<Proposal>(&proposal_data)?;
+
+    // Ensure the stake authority is the proposal author.
+    proposal_state.check_author(stake_authority_info.key)?;
+
+    // Ensure the proposal is in draft stage.
+    if proposal_state.status != ProposalStatus::Draft {
+        return Err(PaladinGovernanceError::ProposalIsImmutable.into());
+    }
+
+    // Ensure the provided proposal transaction address is the correct address
+    // derived from the proposal.
+    if !proposal_transaction_info
+        .key
+        .eq(&get_proposal_transaction_address(
+            proposal_info.key,
+            program_id,
+        ))
+    {
+        return Err(PaladinGovernanceError::IncorrectProposalTransactionAddress.into());
+    }
+
+    check_proposal_transaction_exists(program_id, proposal_transaction_info)?;
+
+    let mut proposal_transaction_state =
+        deserialize_account::<ProposalTransaction>(&proposal_transaction_info.try_borrow_data()?)?;
+
+    // Append the slot's instruction.
+    let new_instruction = proposal_transaction_state
+        .instruction_slot
+        .take()
+        .ok_or(PaladinGovernanceError::InstructionSlotNotCreated)?
+        .to_instruction()?;
+    proposal_transaction_state
+        .instructions
+        .push(new_instruction);
+
+    // Reallocate the account, returning the rent no longer needed to the
+    // author.
+    let new_len = get_instance_packed_len(&proposal_transaction_state)?;
+    proposal_transaction_info.realloc(new_len, true)?;
+    refund_excess_lamports(proposal_transaction_info, stake_authority_info)?;
+
+    // Write the data.
+    borsh::to_writer(
+        &mut proposal_transaction_info.data.borrow_mut()[..],
+        &proposal_transaction_state,
+    )?;
+
+    Ok(())
+}
+
+/// Processes a
+/// [PaladinGovernanceInstruction](enum.PaladinGovernanceInstruction.html).
+pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> ProgramResult {
+    let instruction = PaladinGovernanceInstruction::unpack(input)?;
+    match instruction {
+        PaladinGovernanceInstruction::CreateProposal {
+            title,
+            uri,
+            content_hash,
+        } => {
+            msg!("Instruction: CreateProposal");
+            process_create_proposal(program_id, accounts, title, uri, content_hash)
+        }
+        PaladinGovernanceInstruction::PushInstruction {
+            instruction_program_id,
+            instruction_account_metas,
+            instruction_data,
+        } => {
+            msg!("Instruction: PushInstruction");
+            process_push_instruction(
+                program_id,
+                accounts,
+                instruction_program_id,
+                instruction_account_metas,
+                instruction_data,
+            )
+        }
+        PaladinGovernanceInstruction::RemoveInstruction { instruction_index } => {
+            msg!("Instruction: RemoveInstruction");
+            process_remove_instruction(program_id, accounts, instruction_index)
+        }
+        PaladinGovernanceInstruction::CancelProposal => {
+            msg!("Instruction: CancelProposal");
+            process_cancel_proposal(program_id, accounts)
+        }
+        PaladinGovernanceInstruction::BeginVoting => {
+            msg!("Instruction: BeginVoting");
+            process_begin_voting(program_id, accounts)
+        }
+        PaladinGovernanceInstruction::Vote { election } => {
+            msg!("Instruction: Vote");
+            process_vote(program_id, accounts, election)
+        }
+        PaladinGovernanceInstruction::SwitchVote { new_election } => {
+            msg!("Instruction: SwitchVote");
+            process_switch_vote(program_id, accounts, new_election)
+        }
+        PaladinGovernanceInstruction::ProcessInstruction { instruction_index } => {
+            msg!("Instruction: ProcessInstruction");
+            process_process_instruction(program_id, accounts, instruction_index)
         }
         PaladinGovernanceInstruction::InitializeGovernance {
             cooldown_period_seconds,
+            execution_window_seconds,
             proposal_acceptance_threshold,
+            proposal_minimum_stake,
             proposal_rejection_threshold,
+            quorum_threshold,
+            veto_authority,
             voting_period_seconds,
+            period_bounds,
         } => {
             msg!("Instruction: InitializeGovernance");
             process_initialize_governance(
                 program_id,
                 accounts,
                 cooldown_period_seconds,
+                execution_window_seconds,
                 proposal_acceptance_threshold,
+                proposal_minimum_stake,
                 proposal_rejection_threshold,
+                quorum_threshold,
+                veto_authority,
                 voting_period_seconds,
+                period_bounds,
             )
         }
         PaladinGovernanceInstruction::UpdateGovernance {
             cooldown_period_seconds,
+            execution_window_seconds,
             proposal_acceptance_threshold,
+            proposal_minimum_stake,
             proposal_rejection_threshold,
+            quorum_threshold,
+            veto_authority,
             voting_period_seconds,
         } => {
             msg!("Instruction: UpdateGovernance");
@@ -1101,10 +3254,85 @@ pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> P
                 program_id,
                 accounts,
                 cooldown_period_seconds,
+                execution_window_seconds,
                 proposal_acceptance_threshold,
+                proposal_minimum_stake,
                 proposal_rejection_threshold,
+                quorum_threshold,
+                veto_authority,
                 voting_period_seconds,
             )
         }
+        PaladinGovernanceInstruction::FinalizeProposal => {
+            msg!("Instruction: FinalizeProposal");
+            process_finalize_proposal(program_id, accounts)
+        }
+        PaladinGovernanceInstruction::CloseProposal => {
+            msg!("Instruction: CloseProposal");
+            process_close_proposal(program_id, accounts)
+        }
+        PaladinGovernanceInstruction::CloseProposalVote => {
+            msg!("Instruction: CloseProposalVote");
+            process_close_proposal_vote(program_id, accounts)
+        }
+        PaladinGovernanceInstruction::CloseProposalTransaction => {
+            msg!("Instruction: CloseProposalTransaction");
+            process_close_proposal_transaction(program_id, accounts)
+        }
+        PaladinGovernanceInstruction::SetVoteDelegation {
+            delegate,
+            expiry_timestamp,
+        } => {
+            msg!("Instruction: SetVoteDelegation");
+            process_set_vote_delegation(program_id, accounts, delegate, expiry_timestamp)
+        }
+        PaladinGovernanceInstruction::RevokeVoteDelegation => {
+            msg!("Instruction: RevokeVoteDelegation");
+            process_revoke_vote_delegation(program_id, accounts)
+        }
+        PaladinGovernanceInstruction::MigrateProposalVote => {
+            msg!("Instruction: MigrateProposalVote");
+            process_migrate_proposal_vote(program_id, accounts)
+        }
+        PaladinGovernanceInstruction::SyncVoteWeight => {
+            msg!("Instruction: SyncVoteWeight");
+            process_sync_vote_weight(program_id, accounts)
+        }
+        PaladinGovernanceInstruction::WithdrawVote => {
+            msg!("Instruction: WithdrawVote");
+            process_withdraw_vote(program_id, accounts)
+        }
+        PaladinGovernanceInstruction::VetoProposal => {
+            msg!("Instruction: VetoProposal");
+            process_veto_proposal(program_id, accounts)
+        }
+        PaladinGovernanceInstruction::InitializeTreasury => {
+            msg!("Instruction: InitializeTreasury");
+            process_initialize_treasury(program_id, accounts)
+        }
+        PaladinGovernanceInstruction::DepositToTreasury { amount } => {
+            msg!("Instruction: DepositToTreasury");
+            process_deposit_to_treasury(program_id, accounts, amount)
+        }
+        PaladinGovernanceInstruction::TransferFromTreasury { amount } => {
+            msg!("Instruction: TransferFromTreasury");
+            process_transfer_from_treasury(program_id, accounts, amount)
+        }
+        PaladinGovernanceInstruction::MigrateAccount => {
+            msg!("Instruction: MigrateAccount");
+            process_migrate_account(program_id, accounts)
+        }
+        PaladinGovernanceInstruction::CreateInstructionSlot { instruction_len } => {
+            msg!("Instruction: CreateInstructionSlot");
+            process_create_instruction_slot(program_id, accounts, instruction_len)
+        }
+        PaladinGovernanceInstruction::WriteInstructionSlot { offset, data } => {
+            msg!("Instruction: WriteInstructionSlot");
+            process_write_instruction_slot(program_id, accounts, offset, data)
+        }
+        PaladinGovernanceInstruction::FinalizeInstructionSlot => {
+            msg!("Instruction: FinalizeInstructionSlot");
+            process_finalize_instruction_slot(program_id, accounts)
+        }
     }
 }
@@ -7,12 +7,13 @@ use {
     num_enum::{IntoPrimitive, TryFromPrimitive},
     solana_program::{
         clock::{Clock, UnixTimestamp},
-        entrypoint::ProgramResult,
+        entrypoint::{ProgramResult, MAX_PERMITTED_DATA_INCREASE},
         instruction::{AccountMeta, Instruction},
         program_error::ProgramError,
         pubkey::Pubkey,
     },
     spl_discriminator::SplDiscriminate,
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
     std::num::NonZeroU64,
 };
 
@@ -24,6 +25,10 @@ pub const SEED_PREFIX_TREASURY: &[u8] = b"piggy_bank";
 /// governance config account.
 /// Seeds: `"governance" + stake_config_address`.
 pub const SEED_PREFIX_GOVERNANCE: &[u8] = b"governance";
+/// The seed prefix (`"proposal"`) in bytes used to derive the address of a
+/// proposal account.
+/// Seeds: `"proposal" + governance_config_address + proposal_index`.
+pub const SEED_PREFIX_PROPOSAL: &[u8] = b"proposal";
 /// The seed prefix (`"proposal_vote"`) in bytes used to derive the address of
 /// the proposal vote account, representing a vote cast by a validator for a
 /// proposal.
@@ -34,6 +39,40 @@ pub const SEED_PREFIX_PROPOSAL_VOTE: &[u8] = b"proposal_vote";
 /// instructions to be executed by a proposal.
 /// Seeds: `"proposal_transaction" + proposal_address`.
 pub const SEED_PREFIX_PROPOSAL_TRANSACTION: &[u8] = b"proposal_transaction";
+/// The seed prefix (`"vote_delegation"`) in bytes used to derive the address
+/// of a vote delegation account, representing a key authorized to vote on
+/// behalf of a stake account under a governance config.
+/// Seeds: `"vote_delegation" + stake_address + governance_config_address`.
+pub const SEED_PREFIX_VOTE_DELEGATION: &[u8] = b"vote_delegation";
+
+/// The maximum length, in bytes, of a proposal title.
+pub const MAX_PROPOSAL_TITLE_LEN: usize = 64;
+/// The maximum length, in bytes, of a proposal description URI.
+pub const MAX_PROPOSAL_URI_LEN: usize = 128;
+/// The maximum declared length, in bytes, of an instruction written to an
+/// instruction slot. Leaves room for an instruction carrying the largest
+/// data a cross-program invocation accepts (10 KiB) along with its program
+/// ID and accounts.
+pub const MAX_INSTRUCTION_SLOT_LEN: u32 = 2 * MAX_PERMITTED_DATA_INCREASE as u32;
+
+/// The default minimum cooldown period, in seconds, a governance config may
+/// set.
+pub const DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS: u64 = 60 * 60; // 1 hour
+/// The default maximum cooldown period, in seconds, a governance config may
+/// set.
+pub const DEFAULT_MAX_COOLDOWN_PERIOD_SECONDS: u64 = 30 * 24 * 60 * 60; // 30 days
+/// The default minimum execution window, in seconds, a governance config may
+/// set.
+pub const DEFAULT_MIN_EXECUTION_WINDOW_SECONDS: u64 = 60 * 60; // 1 hour
+/// The default maximum execution window, in seconds, a governance config may
+/// set.
+pub const DEFAULT_MAX_EXECUTION_WINDOW_SECONDS: u64 = 30 * 24 * 60 * 60; // 30 days
+/// The default minimum voting period, in seconds, a governance config may
+/// set.
+pub const DEFAULT_MIN_VOTING_PERIOD_SECONDS: u64 = 60 * 60; // 1 hour
+/// The default maximum voting period, in seconds, a governance config may
+/// set.
+pub const DEFAULT_MAX_VOTING_PERIOD_SECONDS: u64 = 30 * 24 * 60 * 60; // 30 days
 
 /// Derive the address of the treasury account.
 pub fn get_treasury_address(stake_config_address: &Pubkey, program_id: &Pubkey) -> Pubkey {
@@ -91,6 +130,51 @@ pub(crate) fn collect_governance_signer_seeds<'a>(
     ]
 }
 
+/// Derive the address of a proposal account.
+pub fn get_proposal_address(
+    governance_config_address: &Pubkey,
+    proposal_index: u64,
+    program_id: &Pubkey,
+) -> Pubkey {
+    get_proposal_address_and_bump_seed(governance_config_address, proposal_index, program_id).0
+}
+
+/// Derive the address of a proposal account, with bump seed.
+pub fn get_proposal_address_and_bump_seed(
+    governance_config_address: &Pubkey,
+    proposal_index: u64,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &collect_proposal_seeds(governance_config_address, &proposal_index.to_le_bytes()),
+        program_id,
+    )
+}
+
+pub(crate) fn collect_proposal_seeds<'a>(
+    governance_config_address: &'a Pubkey,
+    proposal_index: &'a [u8],
+) -> [&'a [u8]; 3] {
+    [
+        SEED_PREFIX_PROPOSAL,
+        governance_config_address.as_ref(),
+        proposal_index,
+    ]
+}
+
+pub(crate) fn collect_proposal_signer_seeds<'a>(
+    governance_config_address: &'a Pubkey,
+    proposal_index: &'a [u8],
+    bump_seed: &'a [u8],
+) -> [&'a [u8]; 4] {
+    [
+        SEED_PREFIX_PROPOSAL,
+        governance_config_address.as_ref(),
+        proposal_index,
+        bump_seed,
+    ]
+}
+
 /// Derive the address of a proposal transaction account.
 pub fn get_proposal_transaction_address(proposal_address: &Pubkey, program_id: &Pubkey) -> Pubkey {
     get_proposal_transaction_address_and_bump_seed(proposal_address, program_id).0
@@ -167,10 +251,181 @@ pub(crate) fn collect_proposal_vote_signer_seeds<'a>(
     ]
 }
 
+/// Derive the address of a vote delegation account.
+pub fn get_vote_delegation_address(
+    stake_address: &Pubkey,
+    governance_config_address: &Pubkey,
+    program_id: &Pubkey,
+) -> Pubkey {
+    get_vote_delegation_address_and_bump_seed(stake_address, governance_config_address, program_id)
+        .0
+}
+
+/// Derive the address of a vote delegation account, with bump seed.
+pub fn get_vote_delegation_address_and_bump_seed(
+    stake_address: &Pubkey,
+    governance_config_address: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &collect_vote_delegation_seeds(stake_address, governance_config_address),
+        program_id,
+    )
+}
+
+pub(crate) fn collect_vote_delegation_seeds<'a>(
+    stake_address: &'a Pubkey,
+    governance_config_address: &'a Pubkey,
+) -> [&'a [u8]; 3] {
+    [
+        SEED_PREFIX_VOTE_DELEGATION,
+        stake_address.as_ref(),
+        governance_config_address.as_ref(),
+    ]
+}
+
+pub(crate) fn collect_vote_delegation_signer_seeds<'a>(
+    stake_address: &'a Pubkey,
+    governance_config_address: &'a Pubkey,
+    bump_seed: &'a [u8],
+) -> [&'a [u8]; 4] {
+    [
+        SEED_PREFIX_VOTE_DELEGATION,
+        stake_address.as_ref(),
+        governance_config_address.as_ref(),
+        bump_seed,
+    ]
+}
+
+/// A Paladin Governance account type.
+///
+/// Every account's data begins with the discriminator of its type, so an
+/// account of one type cannot be passed as another, and carries the version
+/// of its layout, so accounts created under a previous layout can be
+/// detected.
+pub trait AccountType: SplDiscriminate {
+    /// The current account layout version.
+    const VERSION: u8;
+
+    /// The data lengths of the account type's previous fixed-size layouts,
+    /// which must be migrated before the account can be loaded.
+    const LEGACY_LENS: &'static [usize] = &[];
+
+    /// The account's layout version.
+    fn version(&self) -> u8;
+
+    /// Whether the data is laid out as one of the account type's previous
+    /// layouts.
+    fn is_legacy_layout(data: &[u8]) -> bool {
+        Self::LEGACY_LENS.contains(&data.len())
+    }
+}
+
+fn check_legacy_layout<T: AccountType>(data: &[u8]) -> ProgramResult {
+    // Previous layouts may predate the discriminator, so detect them before
+    // checking it.
+    if T::is_legacy_layout(data) {
+        return Err(PaladinGovernanceError::AccountVersionMismatch.into());
+    }
+    Ok(())
+}
+
+fn check_discriminator<T: AccountType>(data: &[u8]) -> ProgramResult {
+    if !data.starts_with(T::SPL_DISCRIMINATOR_SLICE) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(())
+}
+
+fn check_version<T: AccountType>(state: &T) -> ProgramResult {
+    if state.version() != T::VERSION {
+        return Err(PaladinGovernanceError::AccountVersionMismatch.into());
+    }
+    Ok(())
+}
+
+/// Load an account's `Pod` state, ensuring the data is of the expected
+/// account type and current layout version.
+pub fn load_account<T: AccountType + Pod>(data: &[u8]) -> Result<&T, ProgramError> {
+    check_legacy_layout::<T>(data)?;
+    check_discriminator::<T>(data)?;
+    let state =
+        bytemuck::try_from_bytes::<T>(data).map_err(|_| ProgramError::InvalidAccountData)?;
+    check_version(state)?;
+    Ok(state)
+}
+
+/// Load an account's `Pod` state mutably, ensuring the data is of the
+/// expected account type and current layout version.
+pub fn load_account_mut<T: AccountType + Pod>(data: &mut [u8]) -> Result<&mut T, ProgramError> {
+    check_legacy_layout::<T>(data)?;
+    check_discriminator::<T>(data)?;
+    let state =
+        bytemuck::try_from_bytes_mut::<T>(data).map_err(|_| ProgramError::InvalidAccountData)?;
+    check_version(&*state)?;
+    Ok(state)
+}
+
+/// Deserialize an account's Borsh state, ensuring the data is of the expected
+/// account type and current layout version.
+///
+/// Borsh layouts store the version directly after the discriminator, so a
+/// previous layout is detected before attempting to deserialize it.
+pub fn deserialize_account<T: AccountType + BorshDeserialize>(
+    data: &[u8],
+) -> Result<T, ProgramError> {
+    check_legacy_layout::<T>(data)?;
+    check_discriminator::<T>(data)?;
+    match data.get(T::SPL_DISCRIMINATOR_SLICE.len()) {
+        Some(version) if *version == T::VERSION => (),
+        Some(_) => return Err(PaladinGovernanceError::AccountVersionMismatch.into()),
+        None => return Err(ProgramError::InvalidAccountData),
+    }
+    let state = T::try_from_slice(data).map_err(|_| ProgramError::InvalidAccountData)?;
+    check_version(&state)?;
+    Ok(state)
+}
+
+/// The minimum and maximum periods, in seconds, a governance config may set.
+///
+/// Chosen when the governance config is initialized, and enforced whenever
+/// its periods are updated.
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+#[repr(C)]
+pub struct PeriodBounds {
+    /// The minimum cooldown period.
+    pub min_cooldown_period_seconds: u64,
+    /// The maximum cooldown period.
+    pub max_cooldown_period_seconds: u64,
+    /// The minimum execution window.
+    pub min_execution_window_seconds: u64,
+    /// The maximum execution window.
+    pub max_execution_window_seconds: u64,
+    /// The minimum voting period.
+    pub min_voting_period_seconds: u64,
+    /// The maximum voting period.
+    pub max_voting_period_seconds: u64,
+}
+
+impl Default for PeriodBounds {
+    fn default() -> Self {
+        Self {
+            min_cooldown_period_seconds: DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS,
+            max_cooldown_period_seconds: DEFAULT_MAX_COOLDOWN_PERIOD_SECONDS,
+            min_execution_window_seconds: DEFAULT_MIN_EXECUTION_WINDOW_SECONDS,
+            max_execution_window_seconds: DEFAULT_MAX_EXECUTION_WINDOW_SECONDS,
+            min_voting_period_seconds: DEFAULT_MIN_VOTING_PERIOD_SECONDS,
+            max_voting_period_seconds: DEFAULT_MAX_VOTING_PERIOD_SECONDS,
+        }
+    }
+}
+
 /// Governance configuration account.
-#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, SplDiscriminate, Zeroable)]
+#[discriminator_hash_input("governance::state::config")]
 #[repr(C)]
 pub struct Config {
+    discriminator: [u8; 8],
     /// The cooldown period that begins when a proposal reaches the
     /// `proposal_acceptance_threshold` and upon its conclusion will execute
     /// the proposal's instruction.
@@ -187,35 +442,79 @@ pub struct Config {
     /// Stored as a `u32`, which includes a scaling factor of `1e9` to
     /// represent the threshold with 9 decimal places of precision.
     pub proposal_rejection_threshold: u32,
+    /// The minimum required threshold (percentage) of participation, counted
+    /// as stake for, against and abstained, for a proposal to be concluded
+    /// once voting has ended. Zero disables the quorum requirement.
+    ///
+    /// Stored as a `u32`, which includes a scaling factor of `1e9` to
+    /// represent the threshold with 9 decimal places of precision.
+    pub quorum_threshold: u32,
     /// The signing bump seed, used to sign transactions for this governance
     /// config account with `invoke_signed`. Stored here to save on compute.
     pub signer_bump_seed: u8,
-    _padding: [u8; 7],
+    /// Account layout version.
+    pub version: u8,
+    _padding: [u8; 2],
     /// The Paladin stake config account that this governance config account
     /// corresponds to.
     pub stake_config_address: Pubkey,
     /// The voting period for proposals.
     pub voting_period_seconds: u64,
+    /// The number of proposals created under this governance config.
+    ///
+    /// Used as the index of the next proposal, which is derived from the
+    /// governance config address and that index.
+    pub proposal_count: u64,
+    /// The minimum amount of stake required to create a proposal and to
+    /// begin voting on it.
+    pub proposal_minimum_stake: u64,
+    /// The window, beginning once a proposal's cooldown period has ended,
+    /// within which all of the proposal's instructions must be executed.
+    ///
+    /// An accepted proposal that is not fully executed within this window
+    /// expires.
+    pub execution_window_seconds: u64,
+    /// The authority permitted to veto proposals in their cooldown period or
+    /// once accepted, such as a security council multisig.
+    ///
+    /// A `None` value means proposals cannot be vetoed.
+    pub veto_authority: OptionalNonZeroPubkey,
+    /// The minimum and maximum periods the governance config may set.
+    pub period_bounds: PeriodBounds,
 }
 
 impl Config {
-    /// Create a new [Config](struct.Config.html).
+    /// Create a new [Config](struct.Config.html), with the default period
+    /// bounds.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         cooldown_period_seconds: u64,
+        execution_window_seconds: u64,
         proposal_acceptance_threshold: u32,
+        proposal_minimum_stake: u64,
         proposal_rejection_threshold: u32,
+        quorum_threshold: u32,
         signer_bump_seed: u8,
         stake_config_address: &Pubkey,
+        veto_authority: Option<&Pubkey>,
         voting_period_seconds: u64,
     ) -> Self {
         Self {
+            discriminator: Self::SPL_DISCRIMINATOR.into(),
             cooldown_period_seconds,
             proposal_acceptance_threshold,
             proposal_rejection_threshold,
+            quorum_threshold,
             signer_bump_seed,
-            _padding: [0; 7],
+            version: Self::VERSION,
+            _padding: [0; 2],
             stake_config_address: *stake_config_address,
             voting_period_seconds,
+            proposal_count: 0,
+            proposal_minimum_stake,
+            execution_window_seconds,
+            veto_authority: OptionalNonZeroPubkey(veto_authority.copied().unwrap_or_default()),
+            period_bounds: PeriodBounds::default(),
         }
     }
 
@@ -226,6 +525,132 @@ impl Config {
         }
         Err(PaladinGovernanceError::IncorrectStakeConfig.into())
     }
+
+    /// Evaluate a provided address against the configured veto authority.
+    pub fn check_veto_authority(&self, veto_authority: &Pubkey) -> ProgramResult {
+        match Option::<Pubkey>::from(self.veto_authority) {
+            Some(authority) if authority == *veto_authority => Ok(()),
+            Some(_) => Err(ProgramError::IncorrectAuthority),
+            None => Err(PaladinGovernanceError::VetoAuthorityNotSet.into()),
+        }
+    }
+
+    /// Migrate a [ConfigV0](struct.ConfigV0.html) to the current layout.
+    ///
+    /// Settings the previous layout did not have are left disabled, except
+    /// the execution window, which is set to its maximum, as proposals could
+    /// previously be executed at any time.
+    pub fn from_v0(v0: &ConfigV0) -> Self {
+        Self::new(
+            v0.cooldown_period_seconds,
+            DEFAULT_MAX_EXECUTION_WINDOW_SECONDS,
+            v0.proposal_acceptance_threshold,
+            /* proposal_minimum_stake */ 0,
+            v0.proposal_rejection_threshold,
+            /* quorum_threshold */ 0,
+            v0.signer_bump_seed,
+            &v0.stake_config_address,
+            /* veto_authority */ None,
+            v0.voting_period_seconds,
+        )
+    }
+
+    /// Migrate a [ConfigV1](struct.ConfigV1.html) to the current layout,
+    /// with the default period bounds.
+    pub fn from_v1(v1: &ConfigV1) -> Self {
+        let mut config = Self::new(
+            v1.cooldown_period_seconds,
+            v1.execution_window_seconds,
+            v1.proposal_acceptance_threshold,
+            v1.proposal_minimum_stake,
+            v1.proposal_rejection_threshold,
+            v1.quorum_threshold,
+            v1.signer_bump_seed,
+            &v1.stake_config_address,
+            Option::<Pubkey>::from(v1.veto_authority).as_ref(),
+            v1.voting_period_seconds,
+        );
+        config.proposal_count = v1.proposal_count;
+        config
+    }
+}
+
+impl AccountType for Config {
+    const VERSION: u8 = 2;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn is_legacy_layout(data: &[u8]) -> bool {
+        // Version 0 predates the discriminator, while version 1 shares it,
+        // but is the same size as a version 2 proposal vote.
+        data.len() == std::mem::size_of::<ConfigV0>()
+            || (data.len() == std::mem::size_of::<ConfigV1>()
+                && data.starts_with(Self::SPL_DISCRIMINATOR_SLICE))
+    }
+}
+
+/// Governance configuration account, as laid out at version 1, prior to the
+/// period bounds.
+///
+/// Accounts with this layout must be migrated with
+/// [MigrateAccount](../instruction/enum.PaladinGovernanceInstruction.html)
+/// before they can be used.
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+#[repr(C)]
+pub struct ConfigV1 {
+    /// Account discriminator, shared with the current layout.
+    pub discriminator: [u8; 8],
+    /// The cooldown period.
+    pub cooldown_period_seconds: u64,
+    /// The proposal acceptance threshold.
+    pub proposal_acceptance_threshold: u32,
+    /// The proposal rejection threshold.
+    pub proposal_rejection_threshold: u32,
+    /// The quorum threshold.
+    pub quorum_threshold: u32,
+    /// The signing bump seed.
+    pub signer_bump_seed: u8,
+    /// Account layout version.
+    pub version: u8,
+    _padding: [u8; 2],
+    /// The Paladin stake config account.
+    pub stake_config_address: Pubkey,
+    /// The voting period for proposals.
+    pub voting_period_seconds: u64,
+    /// The number of proposals created under this governance config.
+    pub proposal_count: u64,
+    /// The minimum amount of stake required to create a proposal.
+    pub proposal_minimum_stake: u64,
+    /// The execution window for accepted proposals.
+    pub execution_window_seconds: u64,
+    /// The authority permitted to veto proposals.
+    pub veto_authority: OptionalNonZeroPubkey,
+}
+
+/// Governance configuration account, as laid out prior to the account
+/// discriminator.
+///
+/// Accounts with this layout must be migrated with
+/// [MigrateAccount](../instruction/enum.PaladinGovernanceInstruction.html)
+/// before they can be used.
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+#[repr(C)]
+pub struct ConfigV0 {
+    /// The cooldown period.
+    pub cooldown_period_seconds: u64,
+    /// The proposal acceptance threshold.
+    pub proposal_acceptance_threshold: u32,
+    /// The proposal rejection threshold.
+    pub proposal_rejection_threshold: u32,
+    /// The signing bump seed.
+    pub signer_bump_seed: u8,
+    _padding: [u8; 7],
+    /// The Paladin stake config account.
+    pub stake_config_address: Pubkey,
+    /// The voting period for proposals.
+    pub voting_period_seconds: u64,
 }
 
 /// An account metadata for a proposal instruction.
@@ -304,9 +729,142 @@ impl From<&Instruction> for ProposalInstruction {
     }
 }
 
-/// Governance proposal transaction account.
+/// An instruction being written to a proposal transaction in chunks, for
+/// instructions too large to push in a single transaction.
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, Default, PartialEq)]
+pub struct ProposalInstructionSlot {
+    /// The declared length of the serialized instruction.
+    pub instruction_len: u32,
+    /// The serialized instruction, as written so far.
+    pub data: Vec<u8>,
+}
+
+impl ProposalInstructionSlot {
+    /// Create a new
+    /// [ProposalInstructionSlot](struct.ProposalInstructionSlot.html).
+    pub fn new(instruction_len: u32) -> Self {
+        Self {
+            instruction_len,
+            data: Vec::new(),
+        }
+    }
+
+    /// Write a chunk of the serialized instruction at the given offset,
+    /// zero-filling any gap beyond the data written so far.
+    pub fn write(&mut self, offset: u32, chunk: &[u8]) -> ProgramResult {
+        let start = offset as usize;
+        let end = start
+            .checked_add(chunk.len())
+            .filter(|end| *end <= self.instruction_len as usize)
+            .ok_or(PaladinGovernanceError::InstructionSlotWriteOutOfBounds)?;
+        if end > self.data.len() {
+            self.data.resize(end, 0);
+        }
+        self.data[start..end].copy_from_slice(chunk);
+        Ok(())
+    }
+
+    /// Decode the instruction, once it has been written in full.
+    pub fn to_instruction(&self) -> Result<ProposalInstruction, ProgramError> {
+        if self.data.len() != self.instruction_len as usize {
+            return Err(PaladinGovernanceError::InstructionSlotIncomplete.into());
+        }
+        let instruction = ProposalInstruction::try_from_slice(&self.data)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        Ok(ProposalInstruction::new(
+            &instruction.program_id,
+            instruction.accounts,
+            instruction.data,
+        ))
+    }
+}
+
+/// Governance proposal transaction account.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq, SplDiscriminate)]
+#[discriminator_hash_input("governance::state::proposal_transaction")]
 pub struct ProposalTransaction {
+    discriminator: [u8; 8],
+    /// Account layout version.
+    pub version: u8,
+    /// The instructions to execute.
+    pub instructions: Vec<ProposalInstruction>,
+    /// An instruction being written in chunks, not yet appended to the
+    /// instructions to execute.
+    pub instruction_slot: Option<ProposalInstructionSlot>,
+}
+
+impl ProposalTransaction {
+    /// Create a new [ProposalTransaction](struct.ProposalTransaction.html).
+    pub fn new(instructions: Vec<ProposalInstruction>) -> Self {
+        Self {
+            discriminator: Self::SPL_DISCRIMINATOR.into(),
+            version: Self::VERSION,
+            instructions,
+            instruction_slot: None,
+        }
+    }
+
+    /// Migrate a
+    /// [ProposalTransactionV0](struct.ProposalTransactionV0.html) to the
+    /// current layout.
+    pub fn from_v0(v0: ProposalTransactionV0) -> Self {
+        Self::new(v0.instructions)
+    }
+    /// Migrate a
+    /// [ProposalTransactionV1](struct.ProposalTransactionV1.html) to the
+    /// current layout.
+    pub fn from_v1(v1: ProposalTransactionV1) -> Self {
+        Self::new(v1.instructions)
+    }
+}
+
+impl Default for ProposalTransaction {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl AccountType for ProposalTransaction {
+    const VERSION: u8 = 2;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn is_legacy_layout(data: &[u8]) -> bool {
+        // Version 1 shares the current discriminator, while version 0
+        // predates it.
+        if data.starts_with(Self::SPL_DISCRIMINATOR_SLICE) {
+            return data.get(Self::SPL_DISCRIMINATOR_SLICE.len()) == Some(&1);
+        }
+        ProposalTransactionV0::try_from_slice(data).is_ok()
+    }
+}
+
+/// Governance proposal transaction account, as laid out prior to the
+/// account discriminator: a bare list of instructions.
+///
+/// Accounts with this layout must be migrated with
+/// [MigrateAccount](../instruction/enum.PaladinGovernanceInstruction.html)
+/// before they can be used.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, Default, PartialEq)]
+pub struct ProposalTransactionV0 {
+    /// The instructions to execute.
+    pub instructions: Vec<ProposalInstruction>,
+}
+
+/// Governance proposal transaction account, as laid out at version 1, prior
+/// to the instruction slot.
+///
+/// Accounts with this layout must be migrated with
+/// [MigrateAccount](../instruction/enum.PaladinGovernanceInstruction.html)
+/// before they can be used.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ProposalTransactionV1 {
+    /// Account discriminator, shared with the current layout.
+    pub discriminator: [u8; 8],
+    /// Account layout version.
+    pub version: u8,
     /// The instructions to execute.
     pub instructions: Vec<ProposalInstruction>,
 }
@@ -327,6 +885,30 @@ pub enum ProposalStatus {
     Rejected,
     /// The proposal was accepted and processed.
     Processed,
+    /// The voting period ended without the proposal reaching the governance
+    /// quorum.
+    QuorumNotMet,
+    /// The proposal was accepted, but was not fully processed within the
+    /// governance config's execution window.
+    Expired,
+    /// The proposal was vetoed by the governance config's veto authority.
+    Vetoed,
+}
+
+impl ProposalStatus {
+    /// Whether the proposal has reached a terminal stage, after which its
+    /// accounts may be closed.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Self::Cancelled
+                | Self::Rejected
+                | Self::Processed
+                | Self::QuorumNotMet
+                | Self::Expired
+                | Self::Vetoed
+        )
+    }
 }
 
 unsafe impl Pod for ProposalStatus {}
@@ -340,12 +922,19 @@ pub struct Proposal {
     discriminator: [u8; 8],
     /// The proposal author.
     pub author: Pubkey,
+    /// Hash of the off-chain proposal description document.
+    pub content_hash: [u8; 32],
     /// Timestamp for when the cooldown period began.
     ///
-    /// A `None` value means cooldown has not begun.
+    /// A `None` value means cooldown has not begun, or was cleared because
+    /// support dropped back below the acceptance threshold.
     pub cooldown_timestamp: Option<NonZeroU64>,
     /// Timestamp for when proposal was created.
     pub creation_timestamp: UnixTimestamp,
+    /// Timestamp for when the proposal's final instruction was executed.
+    ///
+    /// A `None` value means the proposal has not been fully processed.
+    pub execution_timestamp: Option<NonZeroU64>,
     /// The governance config for this proposal.
     pub governance_config: Config,
     /// Amount of stake that did not vote.
@@ -356,7 +945,19 @@ pub struct Proposal {
     pub stake_for: u64,
     /// Proposal status
     pub status: ProposalStatus,
-    _padding: [u8; 7],
+    /// Account layout version.
+    pub version: u8,
+    _padding: [u8; 6],
+    /// The proposal title, UTF-8 encoded and zero-padded.
+    pub title: [u8; MAX_PROPOSAL_TITLE_LEN],
+    /// Total stake delegated in the Paladin stake config when voting began.
+    ///
+    /// Used as the denominator for all vote threshold calculations, so
+    /// results do not depend on when votes arrive.
+    pub total_stake: u64,
+    /// URI of the off-chain proposal description document, UTF-8 encoded and
+    /// zero-padded.
+    pub uri: [u8; MAX_PROPOSAL_URI_LEN],
     /// The timestamp when voting began.
     pub voting_start_timestamp: Option<NonZeroU64>,
 }
@@ -371,15 +972,21 @@ impl Proposal {
         Self {
             discriminator: Self::SPL_DISCRIMINATOR.into(),
             author: *author,
+            content_hash: [0; 32],
             cooldown_timestamp: None,
             creation_timestamp,
+            execution_timestamp: None,
             governance_config,
             stake_abstained: 0,
             stake_against: 0,
             stake_for: 0,
             status: ProposalStatus::Draft,
+            version: Self::VERSION,
+            _padding: [0; 6],
+            title: [0; MAX_PROPOSAL_TITLE_LEN],
+            total_stake: 0,
+            uri: [0; MAX_PROPOSAL_URI_LEN],
             voting_start_timestamp: None,
-            _padding: [0; 7],
         }
     }
 
@@ -391,6 +998,40 @@ impl Proposal {
         Err(ProgramError::IncorrectAuthority)
     }
 
+    /// Set the proposal's title, description URI and description content
+    /// hash.
+    pub fn set_metadata(
+        &mut self,
+        title: &str,
+        uri: &str,
+        content_hash: [u8; 32],
+    ) -> ProgramResult {
+        if title.len() > MAX_PROPOSAL_TITLE_LEN {
+            return Err(PaladinGovernanceError::ProposalTitleTooLong.into());
+        }
+        if uri.len() > MAX_PROPOSAL_URI_LEN {
+            return Err(PaladinGovernanceError::ProposalUriTooLong.into());
+        }
+
+        self.title = [0; MAX_PROPOSAL_TITLE_LEN];
+        self.title[..title.len()].copy_from_slice(title.as_bytes());
+        self.uri = [0; MAX_PROPOSAL_URI_LEN];
+        self.uri[..uri.len()].copy_from_slice(uri.as_bytes());
+        self.content_hash = content_hash;
+
+        Ok(())
+    }
+
+    /// The proposal title, without padding.
+    pub fn title_str(&self) -> &str {
+        unpad_str(&self.title)
+    }
+
+    /// The proposal description URI, without padding.
+    pub fn uri_str(&self) -> &str {
+        unpad_str(&self.uri)
+    }
+
     /// Evaluate the proposal cooldown period against the clock sysvar.
     pub fn cooldown_has_ended(&self, clock: &Clock) -> bool {
         if let Some(cooldown_timestamp) = self.cooldown_timestamp {
@@ -404,6 +1045,21 @@ impl Proposal {
         false
     }
 
+    /// Evaluate the proposal execution window, which begins once the cooldown
+    /// period has ended, against the clock sysvar.
+    pub fn execution_window_has_ended(&self, clock: &Clock) -> bool {
+        if let Some(cooldown_timestamp) = self.cooldown_timestamp {
+            if (clock.unix_timestamp as u64)
+                .saturating_sub(self.governance_config.cooldown_period_seconds)
+                .saturating_sub(self.governance_config.execution_window_seconds)
+                >= cooldown_timestamp.get()
+            {
+                return true;
+            }
+        }
+        false
+    }
+
     /// Evaluate the proposal voting period against the clock sysvar.
     pub fn voting_has_ended(&self, clock: &Clock) -> bool {
         if let Some(voting_start_timestamp) = self.voting_start_timestamp {
@@ -418,6 +1074,148 @@ impl Proposal {
     }
 }
 
+impl AccountType for Proposal {
+    const VERSION: u8 = 2;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn is_legacy_layout(data: &[u8]) -> bool {
+        // Previous layouts share the proposal discriminator, and version 0 is
+        // the same size as a proposal vote.
+        (data.len() == std::mem::size_of::<ProposalV0>()
+            || data.len() == std::mem::size_of::<ProposalV1>())
+            && data.starts_with(Self::SPL_DISCRIMINATOR_SLICE)
+    }
+}
+
+/// Governance proposal account, as laid out at version 1, embedding a
+/// [ConfigV1](struct.ConfigV1.html).
+///
+/// Accounts with this layout must be migrated with
+/// [MigrateAccount](../instruction/enum.PaladinGovernanceInstruction.html)
+/// before they can be used.
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+#[repr(C)]
+pub struct ProposalV1 {
+    /// The proposal discriminator, shared with the current layout.
+    pub discriminator: [u8; 8],
+    /// The proposal author.
+    pub author: Pubkey,
+    /// Hash of the off-chain proposal description document.
+    pub content_hash: [u8; 32],
+    /// Timestamp for when the cooldown period began.
+    pub cooldown_timestamp: Option<NonZeroU64>,
+    /// Timestamp for when proposal was created.
+    pub creation_timestamp: UnixTimestamp,
+    /// Timestamp for when the proposal's final instruction was executed.
+    pub execution_timestamp: Option<NonZeroU64>,
+    /// The governance config for this proposal.
+    pub governance_config: ConfigV1,
+    /// Amount of stake that did not vote.
+    pub stake_abstained: u64,
+    /// Amount of stake against the proposal.
+    pub stake_against: u64,
+    /// Amount of stake in favor of the proposal.
+    pub stake_for: u64,
+    /// Proposal status
+    pub status: ProposalStatus,
+    /// Account layout version.
+    pub version: u8,
+    _padding: [u8; 6],
+    /// The proposal title.
+    pub title: [u8; MAX_PROPOSAL_TITLE_LEN],
+    /// Total stake delegated in the Paladin stake config when voting began.
+    pub total_stake: u64,
+    /// URI of the off-chain proposal description document.
+    pub uri: [u8; MAX_PROPOSAL_URI_LEN],
+    /// The timestamp when voting began.
+    pub voting_start_timestamp: Option<NonZeroU64>,
+}
+
+/// Governance proposal account, as laid out prior to the account layout
+/// version, embedding a [ConfigV0](struct.ConfigV0.html).
+///
+/// Accounts with this layout must be migrated with
+/// [MigrateAccount](../instruction/enum.PaladinGovernanceInstruction.html)
+/// before they can be used.
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+#[repr(C)]
+pub struct ProposalV0 {
+    /// The proposal discriminator, shared with the current layout.
+    pub discriminator: [u8; 8],
+    /// The proposal author.
+    pub author: Pubkey,
+    /// Timestamp for when the cooldown period began.
+    pub cooldown_timestamp: Option<NonZeroU64>,
+    /// Timestamp for when proposal was created.
+    pub creation_timestamp: UnixTimestamp,
+    /// The governance config for this proposal.
+    pub governance_config: ConfigV0,
+    /// Amount of stake that did not vote.
+    pub stake_abstained: u64,
+    /// Amount of stake against the proposal.
+    pub stake_against: u64,
+    /// Amount of stake in favor of the proposal.
+    pub stake_for: u64,
+    /// Proposal status
+    pub status: ProposalStatus,
+    _padding: [u8; 7],
+    /// The timestamp when voting began.
+    pub voting_start_timestamp: Option<NonZeroU64>,
+}
+
+impl Proposal {
+    /// Migrate a [ProposalV0](struct.ProposalV0.html) to the current layout.
+    ///
+    /// The previous layout measured votes against the total delegated stake
+    /// at the time of each vote, so the provided total stake is recorded in
+    /// its place.
+    pub fn from_v0(v0: &ProposalV0, total_stake: u64) -> Self {
+        let mut proposal = Self::new(
+            &v0.author,
+            v0.creation_timestamp,
+            Config::from_v0(&v0.governance_config),
+        );
+        proposal.cooldown_timestamp = v0.cooldown_timestamp;
+        proposal.stake_abstained = v0.stake_abstained;
+        proposal.stake_against = v0.stake_against;
+        proposal.stake_for = v0.stake_for;
+        proposal.status = v0.status;
+        proposal.total_stake = total_stake;
+        proposal.voting_start_timestamp = v0.voting_start_timestamp;
+        proposal
+    }
+
+    /// Migrate a [ProposalV1](struct.ProposalV1.html) to the current layout,
+    /// with the default period bounds in its governance config.
+    pub fn from_v1(v1: &ProposalV1) -> Self {
+        let mut proposal = Self::new(
+            &v1.author,
+            v1.creation_timestamp,
+            Config::from_v1(&v1.governance_config),
+        );
+        proposal.content_hash = v1.content_hash;
+        proposal.cooldown_timestamp = v1.cooldown_timestamp;
+        proposal.execution_timestamp = v1.execution_timestamp;
+        proposal.stake_abstained = v1.stake_abstained;
+        proposal.stake_against = v1.stake_against;
+        proposal.stake_for = v1.stake_for;
+        proposal.status = v1.status;
+        proposal.title = v1.title;
+        proposal.total_stake = v1.total_stake;
+        proposal.uri = v1.uri;
+        proposal.voting_start_timestamp = v1.voting_start_timestamp;
+        proposal
+    }
+}
+
+fn unpad_str(bytes: &[u8]) -> &str {
+    let len = bytes.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+    std::str::from_utf8(&bytes[..len]).unwrap_or_default()
+}
+
 /// Proposal vote election.
 #[derive(Clone, Copy, Debug, IntoPrimitive, PartialEq, TryFromPrimitive)]
 #[repr(u8)]
@@ -434,18 +1232,33 @@ unsafe impl Pod for ProposalVoteElection {}
 unsafe impl Zeroable for ProposalVoteElection {}
 
 /// Proposal vote account.
-#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, SplDiscriminate, Zeroable)]
+#[discriminator_hash_input("governance::state::proposal_vote")]
 #[repr(C)]
 pub struct ProposalVote {
+    discriminator: [u8; 8],
     /// Proposal address.
     pub proposal_address: Pubkey,
     /// Amount of stake.
     pub stake: u64,
-    /// Authority address.
+    /// Stake account address.
     pub stake_address: Pubkey,
+    /// Address of the authority that cast the vote, either the stake
+    /// authority or its vote delegate.
+    pub authority_address: Pubkey,
+    /// Address of the payer that funded the account's rent-exempt lamports,
+    /// which are returned to it when the account is closed.
+    pub payer_address: Pubkey,
+    /// Timestamp of the most recent election.
+    ///
+    /// A `None` value means the vote was migrated from a previous version,
+    /// which did not record the timestamp.
+    pub vote_timestamp: Option<NonZeroU64>,
     /// Vote election.
     pub election: ProposalVoteElection,
-    _padding: [u8; 7],
+    /// Account layout version.
+    pub version: u8,
+    _padding: [u8; 6],
 }
 
 impl ProposalVote {
@@ -454,14 +1267,739 @@ impl ProposalVote {
         proposal_address: &Pubkey,
         stake: u64,
         stake_address: &Pubkey,
+        authority_address: &Pubkey,
+        payer_address: &Pubkey,
+        vote_timestamp: Option<NonZeroU64>,
         election: ProposalVoteElection,
     ) -> Self {
         Self {
+            discriminator: Self::SPL_DISCRIMINATOR.into(),
             proposal_address: *proposal_address,
             stake,
             stake_address: *stake_address,
+            authority_address: *authority_address,
+            payer_address: *payer_address,
+            vote_timestamp,
             election,
+            version: Self::VERSION,
+            _padding: [0; 6],
+        }
+    }
+
+    /// Migrate a [ProposalVoteV0](struct.ProposalVoteV0.html) to the current
+    /// layout.
+    ///
+    /// The stake account address must be provided, since the previous layout
+    /// stored the voting authority in its place. Previous layouts did not
+    /// record the payer, so the voting authority is recorded in its place.
+    pub fn from_v0(v0: &ProposalVoteV0, stake_address: &Pubkey) -> Self {
+        Self::new(
+            &v0.proposal_address,
+            v0.stake,
+            stake_address,
+            &v0.authority_address,
+            &v0.authority_address,
+            None,
+            v0.election,
+        )
+    }
+
+    /// Migrate a [ProposalVoteV1](struct.ProposalVoteV1.html) to the current
+    /// layout, recording the voting authority as the payer.
+    pub fn from_v1(v1: &ProposalVoteV1) -> Self {
+        Self::new(
+            &v1.proposal_address,
+            v1.stake,
+            &v1.stake_address,
+            &v1.authority_address,
+            &v1.authority_address,
+            v1.vote_timestamp,
+            v1.election,
+        )
+    }
+
+    /// Migrate a [ProposalVoteV2](struct.ProposalVoteV2.html) to the current
+    /// layout, recording the voting authority as the payer.
+    pub fn from_v2(v2: &ProposalVoteV2) -> Self {
+        Self::new(
+            &v2.proposal_address,
+            v2.stake,
+            &v2.stake_address,
+            &v2.authority_address,
+            &v2.authority_address,
+            v2.vote_timestamp,
+            v2.election,
+        )
+    }
+}
+
+impl AccountType for ProposalVote {
+    const VERSION: u8 = 3;
+    const LEGACY_LENS: &'static [usize] = &[
+        std::mem::size_of::<ProposalVoteV0>(),
+        std::mem::size_of::<ProposalVoteV1>(),
+        std::mem::size_of::<ProposalVoteV2>(),
+    ];
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+/// Proposal vote account, as laid out prior to versioning.
+///
+/// Accounts with this layout must be migrated with
+/// [MigrateProposalVote](../instruction/enum.PaladinGovernanceInstruction.html)
+/// or `MigrateAccount` before they can be updated.
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+#[repr(C)]
+pub struct ProposalVoteV0 {
+    /// Proposal address.
+    pub proposal_address: Pubkey,
+    /// Amount of stake.
+    pub stake: u64,
+    /// Authority address.
+    pub authority_address: Pubkey,
+    /// Vote election.
+    pub election: ProposalVoteElection,
+    _padding: [u8; 7],
+}
+
+impl ProposalVoteV0 {
+    /// Create a new [ProposalVoteV0](struct.ProposalVoteV0.html).
+    pub fn new(
+        proposal_address: &Pubkey,
+        stake: u64,
+        authority_address: &Pubkey,
+        election: ProposalVoteElection,
+    ) -> Self {
+        Self {
+            proposal_address: *proposal_address,
+            stake,
+            authority_address: *authority_address,
+            election,
+            _padding: [0; 7],
+        }
+    }
+}
+
+/// Proposal vote account, as laid out at version 1, prior to the account
+/// discriminator.
+///
+/// Accounts with this layout must be migrated with
+/// [MigrateAccount](../instruction/enum.PaladinGovernanceInstruction.html)
+/// before they can be updated.
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+#[repr(C)]
+pub struct ProposalVoteV1 {
+    /// Proposal address.
+    pub proposal_address: Pubkey,
+    /// Amount of stake.
+    pub stake: u64,
+    /// Stake account address.
+    pub stake_address: Pubkey,
+    /// Address of the authority that cast the vote.
+    pub authority_address: Pubkey,
+    /// Timestamp of the most recent election.
+    pub vote_timestamp: Option<NonZeroU64>,
+    /// Vote election.
+    pub election: ProposalVoteElection,
+    /// Account layout version.
+    pub version: u8,
+    _padding: [u8; 6],
+}
+
+/// Proposal vote account, as laid out at version 2, prior to recording the
+/// payer.
+///
+/// Accounts with this layout must be migrated with
+/// [MigrateAccount](../instruction/enum.PaladinGovernanceInstruction.html)
+/// before they can be updated.
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+#[repr(C)]
+pub struct ProposalVoteV2 {
+    /// Account discriminator, shared with the current layout.
+    pub discriminator: [u8; 8],
+    /// Proposal address.
+    pub proposal_address: Pubkey,
+    /// Amount of stake.
+    pub stake: u64,
+    /// Stake account address.
+    pub stake_address: Pubkey,
+    /// Address of the authority that cast the vote.
+    pub authority_address: Pubkey,
+    /// Timestamp of the most recent election.
+    pub vote_timestamp: Option<NonZeroU64>,
+    /// Vote election.
+    pub election: ProposalVoteElection,
+    /// Account layout version.
+    pub version: u8,
+    _padding: [u8; 6],
+}
+
+/// Vote delegation account.
+///
+/// Authorizes a delegate to vote on behalf of a stake account on proposals
+/// under a single governance config.
+#[derive(Clone, Copy, Debug, PartialEq, Pod, SplDiscriminate, Zeroable)]
+#[discriminator_hash_input("governance::state::vote_delegation")]
+#[repr(C)]
+pub struct VoteDelegation {
+    discriminator: [u8; 8],
+    /// The key authorized to vote on behalf of the stake account.
+    pub delegate: Pubkey,
+    /// Timestamp after which the delegation is no longer valid.
+    ///
+    /// A `None` value means the delegation does not expire.
+    pub expiry_timestamp: Option<NonZeroU64>,
+    /// The governance config the delegation is scoped to.
+    pub governance_config_address: Pubkey,
+    /// The stake account whose voting power is delegated.
+    pub stake_address: Pubkey,
+    /// The payer that funded the vote delegation account, which is refunded
+    /// its rent-exempt lamports when the delegation is revoked.
+    pub payer_address: Pubkey,
+    /// Account layout version.
+    pub version: u8,
+    _padding: [u8; 7],
+}
+
+impl VoteDelegation {
+    /// Create a new [VoteDelegation](struct.VoteDelegation.html).
+    pub fn new(
+        delegate: &Pubkey,
+        expiry_timestamp: Option<NonZeroU64>,
+        governance_config_address: &Pubkey,
+        stake_address: &Pubkey,
+        payer_address: &Pubkey,
+    ) -> Self {
+        Self {
+            discriminator: Self::SPL_DISCRIMINATOR.into(),
+            delegate: *delegate,
+            expiry_timestamp,
+            governance_config_address: *governance_config_address,
+            stake_address: *stake_address,
+            payer_address: *payer_address,
+            version: Self::VERSION,
             _padding: [0; 7],
         }
     }
+
+    /// Migrate a [VoteDelegationV1](struct.VoteDelegationV1.html) to the
+    /// current layout, recording the given payer, which was not previously
+    /// recorded.
+    pub fn from_v1(v1: &VoteDelegationV1, payer_address: &Pubkey) -> Self {
+        Self::new(
+            &v1.delegate,
+            v1.expiry_timestamp,
+            &v1.governance_config_address,
+            &v1.stake_address,
+            payer_address,
+        )
+    }
+
+    /// Evaluate the delegation expiry against the clock sysvar.
+    pub fn has_expired(&self, clock: &Clock) -> bool {
+        if let Some(expiry_timestamp) = self.expiry_timestamp {
+            if clock.unix_timestamp as u64 >= expiry_timestamp.get() {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl AccountType for VoteDelegation {
+    const VERSION: u8 = 2;
+    const LEGACY_LENS: &'static [usize] = &[std::mem::size_of::<VoteDelegationV1>()];
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+/// Vote delegation account, as laid out at version 1, prior to recording the
+/// payer.
+///
+/// Accounts with this layout must be migrated with
+/// [MigrateAccount](../instruction/enum.PaladinGovernanceInstruction.html)
+/// before they can be used.
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+#[repr(C)]
+pub struct VoteDelegationV1 {
+    /// Account discriminator, shared with the current layout.
+    pub discriminator: [u8; 8],
+    /// The key authorized to vote on behalf of the stake account.
+    pub delegate: Pubkey,
+    /// Timestamp after which the delegation is no longer valid.
+    pub expiry_timestamp: Option<NonZeroU64>,
+    /// The governance config the delegation is scoped to.
+    pub governance_config_address: Pubkey,
+    /// The stake account whose voting power is delegated.
+    pub stake_address: Pubkey,
+    /// Account layout version.
+    pub version: u8,
+    _padding: [u8; 7],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Config {
+        Config::new(0, 0, 0, 0, 0, 0, 0, &Pubkey::new_unique(), None, 0)
+    }
+
+    #[test]
+    fn test_load_account() {
+        let config = config();
+        assert_eq!(
+            load_account::<Config>(bytemuck::bytes_of(&config)).unwrap(),
+            &config
+        );
+    }
+
+    #[test]
+    fn test_load_account_incorrect_account_type() {
+        // A version 2 proposal vote is the same size as a governance config.
+        let mut proposal_vote = ProposalVoteV2::zeroed();
+        proposal_vote.discriminator = ProposalVote::SPL_DISCRIMINATOR.into();
+        proposal_vote.version = 2;
+        assert_eq!(
+            load_account::<Config>(bytemuck::bytes_of(&proposal_vote)).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+
+        // A proposal vote is the same size as a previous proposal layout.
+        let proposal_vote = ProposalVote::new(
+            &Pubkey::new_unique(),
+            0,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            None,
+            ProposalVoteElection::For,
+        );
+        assert_eq!(
+            load_account::<Proposal>(bytemuck::bytes_of(&proposal_vote)).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+    }
+
+    #[test]
+    fn test_load_account_version_mismatch() {
+        let mut config = config();
+        config.version = Config::VERSION + 1;
+        assert_eq!(
+            load_account::<Config>(bytemuck::bytes_of(&config)).unwrap_err(),
+            PaladinGovernanceError::AccountVersionMismatch.into()
+        );
+    }
+
+    #[test]
+    fn test_load_account_legacy_layouts() {
+        let expected = Err(PaladinGovernanceError::AccountVersionMismatch.into());
+
+        assert_eq!(
+            load_account::<Config>(bytemuck::bytes_of(&ConfigV0::zeroed())).map(|_| ()),
+            expected
+        );
+        let mut config = ConfigV1::zeroed();
+        config.discriminator = Config::SPL_DISCRIMINATOR.into();
+        config.version = 1;
+        assert_eq!(
+            load_account::<Config>(bytemuck::bytes_of(&config)).map(|_| ()),
+            expected
+        );
+        let mut proposal = ProposalV0::zeroed();
+        proposal.discriminator = Proposal::SPL_DISCRIMINATOR.into();
+        assert_eq!(
+            load_account::<Proposal>(bytemuck::bytes_of(&proposal)).map(|_| ()),
+            expected
+        );
+        let mut proposal = ProposalV1::zeroed();
+        proposal.discriminator = Proposal::SPL_DISCRIMINATOR.into();
+        proposal.version = 1;
+        assert_eq!(
+            load_account::<Proposal>(bytemuck::bytes_of(&proposal)).map(|_| ()),
+            expected
+        );
+
+        let v0 = ProposalVoteV0::new(
+            &Pubkey::new_unique(),
+            0,
+            &Pubkey::new_unique(),
+            ProposalVoteElection::For,
+        );
+        let mut v1 = ProposalVoteV1::zeroed();
+        v1.version = 1;
+        let mut v2 = ProposalVoteV2::zeroed();
+        v2.discriminator = ProposalVote::SPL_DISCRIMINATOR.into();
+        v2.version = 2;
+        for data in [
+            bytemuck::bytes_of(&v0),
+            bytemuck::bytes_of(&v1),
+            bytemuck::bytes_of(&v2),
+        ] {
+            assert_eq!(load_account::<ProposalVote>(data).map(|_| ()), expected);
+            assert_eq!(
+                load_account_mut::<ProposalVote>(&mut data.to_vec()).map(|_| ()),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_deserialize_account() {
+        let proposal_transaction = ProposalTransaction::new(vec![ProposalInstruction::new(
+            &Pubkey::new_unique(),
+            vec![],
+            vec![1, 2, 3],
+        )]);
+        let data = borsh::to_vec(&proposal_transaction).unwrap();
+        assert_eq!(
+            deserialize_account::<ProposalTransaction>(&data).unwrap(),
+            proposal_transaction
+        );
+
+        let mut data = data;
+        data[8] = ProposalTransaction::VERSION + 1;
+        assert_eq!(
+            deserialize_account::<ProposalTransaction>(&data).unwrap_err(),
+            PaladinGovernanceError::AccountVersionMismatch.into()
+        );
+
+        data[0] ^= 1;
+        assert_eq!(
+            deserialize_account::<ProposalTransaction>(&data).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+    }
+
+    #[test]
+    fn test_legacy_layouts() {
+        assert_eq!(std::mem::size_of::<ConfigV0>(), 64);
+        assert_eq!(std::mem::size_of::<ConfigV1>(), 128);
+        assert_eq!(std::mem::size_of::<Config>(), 176);
+        assert_eq!(std::mem::size_of::<ProposalV0>(), 160);
+        assert_eq!(std::mem::size_of::<ProposalV1>(), 464);
+        assert_eq!(std::mem::size_of::<Proposal>(), 512);
+        assert_eq!(std::mem::size_of::<ProposalVoteV0>(), 80);
+        assert_eq!(std::mem::size_of::<ProposalVoteV1>(), 120);
+        assert_eq!(std::mem::size_of::<ProposalVoteV2>(), 128);
+        assert_eq!(std::mem::size_of::<ProposalVote>(), 160);
+    }
+
+    #[test]
+    fn test_config_from_v0() {
+        let mut v0 = ConfigV0::zeroed();
+        v0.cooldown_period_seconds = 1;
+        v0.proposal_acceptance_threshold = 2;
+        v0.proposal_rejection_threshold = 3;
+        v0.signer_bump_seed = 4;
+        v0.stake_config_address = Pubkey::new_unique();
+        v0.voting_period_seconds = 5;
+
+        let expected = Config::new(
+            1,
+            DEFAULT_MAX_EXECUTION_WINDOW_SECONDS,
+            2,
+            0,
+            3,
+            0,
+            4,
+            &v0.stake_config_address,
+            None,
+            5,
+        );
+
+        let config = Config::from_v0(&v0);
+        assert_eq!(config, expected);
+        assert_eq!(config.proposal_count, 0);
+        assert_eq!(
+            load_account::<Config>(bytemuck::bytes_of(&config)).unwrap(),
+            &expected
+        );
+    }
+
+    #[test]
+    fn test_proposal_from_v0() {
+        let mut v0 = ProposalV0::zeroed();
+        v0.discriminator = Proposal::SPL_DISCRIMINATOR.into();
+        v0.author = Pubkey::new_unique();
+        v0.cooldown_timestamp = NonZeroU64::new(20);
+        v0.creation_timestamp = 10;
+        v0.governance_config.stake_config_address = Pubkey::new_unique();
+        v0.stake_abstained = 1;
+        v0.stake_against = 2;
+        v0.stake_for = 3;
+        v0.status = ProposalStatus::Voting;
+        v0.voting_start_timestamp = NonZeroU64::new(15);
+
+        let proposal = Proposal::from_v0(&v0, 100);
+        assert_eq!(proposal.author, v0.author);
+        assert_eq!(proposal.cooldown_timestamp, NonZeroU64::new(20));
+        assert_eq!(proposal.creation_timestamp, 10);
+        assert_eq!(
+            proposal.governance_config,
+            Config::from_v0(&v0.governance_config)
+        );
+        assert_eq!(proposal.stake_abstained, 1);
+        assert_eq!(proposal.stake_against, 2);
+        assert_eq!(proposal.stake_for, 3);
+        assert_eq!(proposal.status, ProposalStatus::Voting);
+        assert_eq!(proposal.total_stake, 100);
+        assert_eq!(proposal.voting_start_timestamp, NonZeroU64::new(15));
+        assert_eq!(
+            load_account::<Proposal>(bytemuck::bytes_of(&proposal)).unwrap(),
+            &proposal
+        );
+    }
+
+    #[test]
+    fn test_config_from_v1() {
+        let mut v1 = ConfigV1::zeroed();
+        v1.discriminator = Config::SPL_DISCRIMINATOR.into();
+        v1.cooldown_period_seconds = 1;
+        v1.proposal_acceptance_threshold = 2;
+        v1.proposal_rejection_threshold = 3;
+        v1.quorum_threshold = 4;
+        v1.signer_bump_seed = 5;
+        v1.version = 1;
+        v1.stake_config_address = Pubkey::new_unique();
+        v1.voting_period_seconds = 6;
+        v1.proposal_count = 7;
+        v1.proposal_minimum_stake = 8;
+        v1.execution_window_seconds = 9;
+        v1.veto_authority = OptionalNonZeroPubkey(Pubkey::new_unique());
+
+        let mut expected = Config::new(
+            1,
+            9,
+            2,
+            8,
+            3,
+            4,
+            5,
+            &v1.stake_config_address,
+            Some(&v1.veto_authority.0),
+            6,
+        );
+        expected.proposal_count = 7;
+
+        let config = Config::from_v1(&v1);
+        assert_eq!(config, expected);
+        assert_eq!(config.period_bounds, PeriodBounds::default());
+        assert_eq!(
+            load_account::<Config>(bytemuck::bytes_of(&config)).unwrap(),
+            &expected
+        );
+    }
+
+    #[test]
+    fn test_proposal_from_v1() {
+        let mut v1 = ProposalV1::zeroed();
+        v1.discriminator = Proposal::SPL_DISCRIMINATOR.into();
+        v1.author = Pubkey::new_unique();
+        v1.content_hash = [1; 32];
+        v1.cooldown_timestamp = NonZeroU64::new(20);
+        v1.creation_timestamp = 10;
+        v1.execution_timestamp = NonZeroU64::new(30);
+        v1.governance_config.stake_config_address = Pubkey::new_unique();
+        v1.governance_config.version = 1;
+        v1.stake_abstained = 1;
+        v1.stake_against = 2;
+        v1.stake_for = 3;
+        v1.status = ProposalStatus::Accepted;
+        v1.version = 1;
+        v1.title = [2; MAX_PROPOSAL_TITLE_LEN];
+        v1.total_stake = 100;
+        v1.uri = [3; MAX_PROPOSAL_URI_LEN];
+        v1.voting_start_timestamp = NonZeroU64::new(15);
+
+        let proposal = Proposal::from_v1(&v1);
+        assert_eq!(proposal.author, v1.author);
+        assert_eq!(proposal.content_hash, [1; 32]);
+        assert_eq!(proposal.cooldown_timestamp, NonZeroU64::new(20));
+        assert_eq!(proposal.creation_timestamp, 10);
+        assert_eq!(proposal.execution_timestamp, NonZeroU64::new(30));
+        assert_eq!(
+            proposal.governance_config,
+            Config::from_v1(&v1.governance_config)
+        );
+        assert_eq!(proposal.stake_abstained, 1);
+        assert_eq!(proposal.stake_against, 2);
+        assert_eq!(proposal.stake_for, 3);
+        assert_eq!(proposal.status, ProposalStatus::Accepted);
+        assert_eq!(proposal.title, [2; MAX_PROPOSAL_TITLE_LEN]);
+        assert_eq!(proposal.total_stake, 100);
+        assert_eq!(proposal.uri, [3; MAX_PROPOSAL_URI_LEN]);
+        assert_eq!(proposal.voting_start_timestamp, NonZeroU64::new(15));
+        assert_eq!(
+            load_account::<Proposal>(bytemuck::bytes_of(&proposal)).unwrap(),
+            &proposal
+        );
+    }
+
+    #[test]
+    fn test_proposal_vote_from_v1() {
+        let mut v1 = ProposalVoteV1::zeroed();
+        v1.proposal_address = Pubkey::new_unique();
+        v1.stake = 100;
+        v1.stake_address = Pubkey::new_unique();
+        v1.authority_address = Pubkey::new_unique();
+        v1.vote_timestamp = NonZeroU64::new(10);
+        v1.election = ProposalVoteElection::For;
+        v1.version = 1;
+
+        let proposal_vote = ProposalVote::from_v1(&v1);
+        assert_eq!(
+            proposal_vote,
+            ProposalVote::new(
+                &v1.proposal_address,
+                100,
+                &v1.stake_address,
+                &v1.authority_address,
+                &v1.authority_address,
+                NonZeroU64::new(10),
+                ProposalVoteElection::For,
+            )
+        );
+        assert_eq!(proposal_vote.version, ProposalVote::VERSION);
+    }
+
+    #[test]
+    fn test_proposal_vote_from_v2() {
+        let mut v2 = ProposalVoteV2::zeroed();
+        v2.discriminator = ProposalVote::SPL_DISCRIMINATOR.into();
+        v2.proposal_address = Pubkey::new_unique();
+        v2.stake = 100;
+        v2.stake_address = Pubkey::new_unique();
+        v2.authority_address = Pubkey::new_unique();
+        v2.vote_timestamp = NonZeroU64::new(10);
+        v2.election = ProposalVoteElection::Against;
+        v2.version = 2;
+
+        let proposal_vote = ProposalVote::from_v2(&v2);
+        assert_eq!(
+            proposal_vote,
+            ProposalVote::new(
+                &v2.proposal_address,
+                100,
+                &v2.stake_address,
+                &v2.authority_address,
+                &v2.authority_address,
+                NonZeroU64::new(10),
+                ProposalVoteElection::Against,
+            )
+        );
+        assert_eq!(proposal_vote.version, ProposalVote::VERSION);
+    }
+
+    #[test]
+    fn test_instruction_slot_write() {
+        let mut slot = ProposalInstructionSlot::new(8);
+
+        // Writes beyond the data written so far zero-fill the gap.
+        slot.write(4, &[5, 6]).unwrap();
+        assert_eq!(slot.data, vec![0, 0, 0, 0, 5, 6]);
+
+        slot.write(0, &[1, 2, 3, 4]).unwrap();
+        slot.write(5, &[7, 8, 9]).unwrap();
+        assert_eq!(slot.data, vec![1, 2, 3, 4, 5, 7, 8, 9]);
+
+        assert_eq!(
+            slot.write(6, &[1, 2, 3]).unwrap_err(),
+            PaladinGovernanceError::InstructionSlotWriteOutOfBounds.into()
+        );
+        assert_eq!(
+            slot.write(u32::MAX, &[1]).unwrap_err(),
+            PaladinGovernanceError::InstructionSlotWriteOutOfBounds.into()
+        );
+    }
+
+    #[test]
+    fn test_instruction_slot_to_instruction() {
+        let mut instruction = ProposalInstruction::new(
+            &Pubkey::new_unique(),
+            vec![ProposalAccountMeta {
+                pubkey: Pubkey::new_unique(),
+                is_signer: false,
+                is_writable: true,
+            }],
+            vec![1; 100],
+        );
+        instruction.executed = true;
+        let data = borsh::to_vec(&instruction).unwrap();
+
+        let mut slot = ProposalInstructionSlot::new(data.len() as u32);
+        slot.write(0, &data[..50]).unwrap();
+        assert_eq!(
+            slot.to_instruction().unwrap_err(),
+            PaladinGovernanceError::InstructionSlotIncomplete.into()
+        );
+
+        // The executed flag is not carried over.
+        slot.write(50, &data[50..]).unwrap();
+        instruction.executed = false;
+        assert_eq!(slot.to_instruction().unwrap(), instruction);
+
+        let mut slot = ProposalInstructionSlot::new(4);
+        slot.write(0, &[1, 2, 3, 4]).unwrap();
+        assert_eq!(
+            slot.to_instruction().unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+    }
+
+    #[test]
+    fn test_proposal_transaction_from_v0() {
+        let instructions = vec![ProposalInstruction::new(
+            &Pubkey::new_unique(),
+            vec![],
+            vec![1, 2, 3],
+        )];
+        let data = borsh::to_vec(&ProposalTransactionV0 {
+            instructions: instructions.clone(),
+        })
+        .unwrap();
+
+        assert_eq!(
+            deserialize_account::<ProposalTransaction>(&data).unwrap_err(),
+            PaladinGovernanceError::AccountVersionMismatch.into()
+        );
+
+        let v0 = ProposalTransactionV0::try_from_slice(&data).unwrap();
+        let proposal_transaction = ProposalTransaction::from_v0(v0);
+        assert_eq!(proposal_transaction, ProposalTransaction::new(instructions));
+        assert_eq!(proposal_transaction.instruction_slot, None);
+    }
+
+    #[test]
+    fn test_proposal_transaction_from_v1() {
+        let instructions = vec![ProposalInstruction::new(
+            &Pubkey::new_unique(),
+            vec![],
+            vec![1, 2, 3],
+        )];
+        let data = borsh::to_vec(&ProposalTransactionV1 {
+            discriminator: ProposalTransaction::SPL_DISCRIMINATOR.into(),
+            version: 1,
+            instructions: instructions.clone(),
+        })
+        .unwrap();
+
+        assert!(ProposalTransaction::is_legacy_layout(&data));
+        assert_eq!(
+            deserialize_account::<ProposalTransaction>(&data).unwrap_err(),
+            PaladinGovernanceError::AccountVersionMismatch.into()
+        );
+
+        let v1 = ProposalTransactionV1::try_from_slice(&data).unwrap();
+        let proposal_transaction = ProposalTransaction::from_v1(v1);
+        assert_eq!(proposal_transaction, ProposalTransaction::new(instructions));
+        assert!(!ProposalTransaction::is_legacy_layout(
+            &borsh::to_vec(&proposal_transaction).unwrap()
+        ));
+    }
 }
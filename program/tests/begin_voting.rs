@@ -3,12 +3,17 @@
 mod setup;
 
 use {
+    borsh::BorshDeserialize,
     paladin_governance_program::{
         error::PaladinGovernanceError,
         instruction::begin_voting,
-        state::{Config, Proposal, ProposalStatus},
+        state::{
+            get_proposal_transaction_address, Config, Proposal, ProposalInstructionSlot,
+            ProposalStatus, ProposalTransaction,
+        },
     },
-    setup::{setup, setup_proposal},
+    paladin_stake_program::state::{find_stake_pda, Config as StakeConfig},
+    setup::{setup, setup_proposal, setup_proposal_transaction, setup_stake, setup_stake_config},
     solana_program_test::*,
     solana_sdk::{
         account::AccountSharedData,
@@ -16,18 +21,29 @@ use {
         pubkey::Pubkey,
         signature::Keypair,
         signer::Signer,
+        system_program,
         transaction::{Transaction, TransactionError},
     },
 };
 
+const PROPOSAL_MINIMUM_STAKE: u64 = 1_000_000;
+
 #[tokio::test]
 async fn fail_stake_authority_not_signer() {
     let stake_authority = Keypair::new();
     let proposal = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let stake = Pubkey::new_unique(); // PDA doesn't matter here.
 
     let mut context = setup().start_with_context().await;
 
-    let mut instruction = begin_voting(&stake_authority.pubkey(), &proposal);
+    let mut instruction = begin_voting(
+        &stake_authority.pubkey(),
+        &proposal,
+        &stake_config,
+        &stake,
+        &get_proposal_transaction_address(&proposal, &paladin_governance_program::id()),
+    );
     instruction.accounts[0].is_signer = false; // Stake authority not signer.
 
     let transaction = Transaction::new_signed_with_payer(
@@ -54,6 +70,8 @@ async fn fail_stake_authority_not_signer() {
 async fn fail_proposal_incorrect_owner() {
     let stake_authority = Keypair::new();
     let proposal = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let stake = Pubkey::new_unique(); // PDA doesn't matter here.
 
     let mut context = setup().start_with_context().await;
 
@@ -68,7 +86,13 @@ async fn fail_proposal_incorrect_owner() {
         );
     }
 
-    let instruction = begin_voting(&stake_authority.pubkey(), &proposal);
+    let instruction = begin_voting(
+        &stake_authority.pubkey(),
+        &proposal,
+        &stake_config,
+        &stake,
+        &get_proposal_transaction_address(&proposal, &paladin_governance_program::id()),
+    );
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -94,6 +118,8 @@ async fn fail_proposal_incorrect_owner() {
 async fn fail_proposal_not_initialized() {
     let stake_authority = Keypair::new();
     let proposal = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let stake = Pubkey::new_unique(); // PDA doesn't matter here.
 
     let mut context = setup().start_with_context().await;
 
@@ -108,7 +134,13 @@ async fn fail_proposal_not_initialized() {
         );
     }
 
-    let instruction = begin_voting(&stake_authority.pubkey(), &proposal);
+    let instruction = begin_voting(
+        &stake_authority.pubkey(),
+        &proposal,
+        &stake_config,
+        &stake,
+        &get_proposal_transaction_address(&proposal, &paladin_governance_program::id()),
+    );
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -130,10 +162,73 @@ async fn fail_proposal_not_initialized() {
     );
 }
 
+#[tokio::test]
+async fn fail_proposal_version_mismatch() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let stake = Pubkey::new_unique(); // PDA doesn't matter here.
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        Config::default(),
+        ProposalStatus::Draft,
+    )
+    .await;
+
+    // Set the proposal's layout version to a previous version.
+    {
+        let mut proposal_account = context
+            .banks_client
+            .get_account(proposal)
+            .await
+            .unwrap()
+            .unwrap();
+        bytemuck::from_bytes_mut::<Proposal>(&mut proposal_account.data).version = 0;
+        context.set_account(&proposal, &proposal_account.into());
+    }
+
+    let instruction = begin_voting(
+        &stake_authority.pubkey(),
+        &proposal,
+        &stake_config,
+        &stake,
+        &get_proposal_transaction_address(&proposal, &paladin_governance_program::id()),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::AccountVersionMismatch as u32)
+        )
+    );
+}
+
 #[tokio::test]
 async fn fail_stake_authority_not_author() {
     let stake_authority = Keypair::new();
     let proposal = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let stake = Pubkey::new_unique(); // PDA doesn't matter here.
 
     let mut context = setup().start_with_context().await;
     setup_proposal(
@@ -146,7 +241,13 @@ async fn fail_stake_authority_not_author() {
     )
     .await;
 
-    let instruction = begin_voting(&stake_authority.pubkey(), &proposal);
+    let instruction = begin_voting(
+        &stake_authority.pubkey(),
+        &proposal,
+        &stake_config,
+        &stake,
+        &get_proposal_transaction_address(&proposal, &paladin_governance_program::id()),
+    );
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -172,6 +273,8 @@ async fn fail_stake_authority_not_author() {
 async fn fail_proposal_not_in_draft_stage() {
     let stake_authority = Keypair::new();
     let proposal = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let stake = Pubkey::new_unique(); // PDA doesn't matter here.
 
     let mut context = setup().start_with_context().await;
     setup_proposal(
@@ -184,7 +287,13 @@ async fn fail_proposal_not_in_draft_stage() {
     )
     .await;
 
-    let instruction = begin_voting(&stake_authority.pubkey(), &proposal);
+    let instruction = begin_voting(
+        &stake_authority.pubkey(),
+        &proposal,
+        &stake_config,
+        &stake,
+        &get_proposal_transaction_address(&proposal, &paladin_governance_program::id()),
+    );
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -210,9 +319,11 @@ async fn fail_proposal_not_in_draft_stage() {
 }
 
 #[tokio::test]
-async fn success() {
+async fn fail_stake_config_incorrect_owner() {
     let stake_authority = Keypair::new();
     let proposal = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let stake = Pubkey::new_unique(); // PDA doesn't matter here.
 
     let mut context = setup().start_with_context().await;
     setup_proposal(
@@ -225,7 +336,436 @@ async fn success() {
     )
     .await;
 
-    let instruction = begin_voting(&stake_authority.pubkey(), &proposal);
+    // Set up the stake config account with the incorrect owner.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let space = std::mem::size_of::<StakeConfig>();
+        let lamports = rent.minimum_balance(space);
+        context.set_account(
+            &stake_config,
+            &AccountSharedData::new(lamports, space, &Pubkey::new_unique()), // Incorrect owner.
+        );
+    }
+
+    let instruction = begin_voting(
+        &stake_authority.pubkey(),
+        &proposal,
+        &stake_config,
+        &stake,
+        &get_proposal_transaction_address(&proposal, &paladin_governance_program::id()),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+    );
+}
+
+#[tokio::test]
+async fn fail_stake_config_not_initialized() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let stake = Pubkey::new_unique(); // PDA doesn't matter here.
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        Config::default(),
+        ProposalStatus::Draft,
+    )
+    .await;
+
+    // Set up the stake config account uninitialized.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let space = std::mem::size_of::<StakeConfig>();
+        let lamports = rent.minimum_balance(space);
+        context.set_account(
+            &stake_config,
+            &AccountSharedData::new(lamports, space, &paladin_stake_program::id()),
+        );
+    }
+
+    let instruction = begin_voting(
+        &stake_authority.pubkey(),
+        &proposal,
+        &stake_config,
+        &stake,
+        &get_proposal_transaction_address(&proposal, &paladin_governance_program::id()),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::UninitializedAccount)
+    );
+}
+
+#[tokio::test]
+async fn fail_stake_config_mismatch() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let stake = Pubkey::new_unique(); // PDA doesn't matter here.
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        /* stake_config_address */ &Pubkey::new_unique(), // Mismatch.
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 0,
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_stake_config(&mut context, &stake_config, 0).await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        governance_config,
+        ProposalStatus::Draft,
+    )
+    .await;
+
+    let instruction = begin_voting(
+        &stake_authority.pubkey(),
+        &proposal,
+        &stake_config,
+        &stake,
+        &get_proposal_transaction_address(&proposal, &paladin_governance_program::id()),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::IncorrectStakeConfig as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_stake_incorrect_authority() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ PROPOSAL_MINIMUM_STAKE,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        &stake_config,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 0,
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_stake_config(&mut context, &stake_config, 100_000_000).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &Pubkey::new_unique(), // Incorrect stake authority.,
+        &validator_vote,
+        PROPOSAL_MINIMUM_STAKE,
+    )
+    .await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        governance_config,
+        ProposalStatus::Draft,
+    )
+    .await;
+
+    let instruction = begin_voting(
+        &stake_authority.pubkey(),
+        &proposal,
+        &stake_config,
+        &stake,
+        &get_proposal_transaction_address(&proposal, &paladin_governance_program::id()),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_minimum_stake_not_met() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ PROPOSAL_MINIMUM_STAKE,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        &stake_config,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 0,
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_stake_config(&mut context, &stake_config, 100_000_000).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        PROPOSAL_MINIMUM_STAKE - 1, // Not enough stake.
+    )
+    .await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        governance_config,
+        ProposalStatus::Draft,
+    )
+    .await;
+
+    let instruction = begin_voting(
+        &stake_authority.pubkey(),
+        &proposal,
+        &stake_config,
+        &stake,
+        &get_proposal_transaction_address(&proposal, &paladin_governance_program::id()),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::ProposalMinimumStakeNotMet as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_transaction_incorrect_address() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ PROPOSAL_MINIMUM_STAKE,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        &stake_config,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 0,
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_stake_config(&mut context, &stake_config, 100_000_000).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        PROPOSAL_MINIMUM_STAKE,
+    )
+    .await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        governance_config,
+        ProposalStatus::Draft,
+    )
+    .await;
+
+    let instruction = begin_voting(
+        &stake_authority.pubkey(),
+        &proposal,
+        &stake_config,
+        &stake,
+        &Pubkey::new_unique(), // Incorrect proposal transaction address.
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                PaladinGovernanceError::IncorrectProposalTransactionAddress as u32
+            )
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+
+    let total_stake = 100_000_000;
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ PROPOSAL_MINIMUM_STAKE,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        &stake_config,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 0,
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_stake_config(&mut context, &stake_config, total_stake).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        PROPOSAL_MINIMUM_STAKE,
+    )
+    .await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        governance_config,
+        ProposalStatus::Draft,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &get_proposal_transaction_address(&proposal, &paladin_governance_program::id()),
+        ProposalTransaction::default(),
+    )
+    .await;
+
+    let instruction = begin_voting(
+        &stake_authority.pubkey(),
+        &proposal,
+        &stake_config,
+        &stake,
+        &get_proposal_transaction_address(&proposal, &paladin_governance_program::id()),
+    );
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -249,4 +789,136 @@ async fn success() {
         .unwrap();
     let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
     assert_eq!(proposal_state.status, ProposalStatus::Voting);
+
+    // Assert the total stake was recorded.
+    assert_eq!(proposal_state.total_stake, total_stake);
+}
+
+#[tokio::test]
+async fn success_discard_unfinalized_slot() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+
+    let total_stake = 100_000_000;
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ PROPOSAL_MINIMUM_STAKE,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        &stake_config,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 0,
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_stake_config(&mut context, &stake_config, total_stake).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        PROPOSAL_MINIMUM_STAKE,
+    )
+    .await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        governance_config,
+        ProposalStatus::Draft,
+    )
+    .await;
+
+    // Set up a proposal transaction with a partially written slot.
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+    let mut instruction_slot = ProposalInstructionSlot::new(4_096);
+    instruction_slot.write(0, &[7; 2_048]).unwrap();
+    let mut proposal_transaction_state = ProposalTransaction::default();
+    proposal_transaction_state.instruction_slot = Some(instruction_slot);
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction,
+        proposal_transaction_state,
+    )
+    .await;
+
+    // Fund the stake authority, which is refunded the slot's rent.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    context.set_account(
+        &stake_authority.pubkey(),
+        &AccountSharedData::new(rent.minimum_balance(0), 0, &system_program::id()),
+    );
+    let stake_authority_lamports = rent.minimum_balance(0);
+    let proposal_transaction_lamports = context
+        .banks_client
+        .get_balance(proposal_transaction)
+        .await
+        .unwrap();
+
+    let instruction = begin_voting(
+        &stake_authority.pubkey(),
+        &proposal,
+        &stake_config,
+        &stake,
+        &proposal_transaction,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the slot was discarded.
+    let proposal_transaction_account = context
+        .banks_client
+        .get_account(proposal_transaction)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_transaction_state =
+        ProposalTransaction::try_from_slice(&proposal_transaction_account.data).unwrap();
+    assert_eq!(proposal_transaction_state, ProposalTransaction::default());
+
+    // Assert the excess rent was refunded to the stake authority.
+    assert_eq!(
+        proposal_transaction_account.lamports,
+        rent.minimum_balance(proposal_transaction_account.data.len())
+    );
+    let refund = proposal_transaction_lamports - proposal_transaction_account.lamports;
+    assert!(refund > 0);
+    assert_eq!(
+        context
+            .banks_client
+            .get_balance(stake_authority.pubkey())
+            .await
+            .unwrap(),
+        stake_authority_lamports + refund
+    );
+
+    // Assert the proposal was marked with voting status.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::Voting);
 }
@@ -0,0 +1,365 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::close_proposal_transaction,
+        state::{get_proposal_transaction_address, Config, Proposal, ProposalStatus},
+    },
+    setup::{create_mock_proposal_transaction, setup, setup_proposal, setup_proposal_transaction},
+    solana_program_test::*,
+    solana_sdk::{
+        account::AccountSharedData,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    test_case::test_case,
+};
+
+#[tokio::test]
+async fn fail_stake_authority_not_signer() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    let mut instruction =
+        close_proposal_transaction(&stake_authority.pubkey(), &proposal, &proposal_transaction);
+    instruction.accounts[0].is_signer = false; // Stake authority not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Stake authority not signer.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_incorrect_owner() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    // Set up the proposal account with the incorrect owner.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let space = std::mem::size_of::<Proposal>();
+        let lamports = rent.minimum_balance(space);
+        context.set_account(
+            &proposal,
+            &AccountSharedData::new(lamports, space, &Pubkey::new_unique()), // Incorrect owner.
+        );
+    }
+
+    let instruction =
+        close_proposal_transaction(&stake_authority.pubkey(), &proposal, &proposal_transaction);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+    );
+}
+
+#[tokio::test]
+async fn fail_stake_authority_not_author() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(), // Stake authority not author.
+        0,
+        Config::default(),
+        ProposalStatus::Cancelled,
+    )
+    .await;
+
+    let instruction =
+        close_proposal_transaction(&stake_authority.pubkey(), &proposal, &proposal_transaction);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+}
+
+#[test_case(ProposalStatus::Draft)]
+#[test_case(ProposalStatus::Voting)]
+#[test_case(ProposalStatus::Accepted)]
+#[tokio::test]
+async fn fail_proposal_not_terminal(status: ProposalStatus) {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        Config::default(),
+        status,
+    )
+    .await;
+
+    let instruction =
+        close_proposal_transaction(&stake_authority.pubkey(), &proposal, &proposal_transaction);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::ProposalNotTerminal as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_transaction_incorrect_address() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction = Pubkey::new_unique(); // Incorrect proposal transaction address.
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        Config::default(),
+        ProposalStatus::Cancelled,
+    )
+    .await;
+
+    let instruction =
+        close_proposal_transaction(&stake_authority.pubkey(), &proposal, &proposal_transaction);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                PaladinGovernanceError::IncorrectProposalTransactionAddress as u32
+            )
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_transaction_not_initialized() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        Config::default(),
+        ProposalStatus::Cancelled,
+    )
+    .await;
+
+    // Set up the proposal transaction account uninitialized.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(0);
+        context.set_account(
+            &proposal_transaction,
+            &AccountSharedData::new(lamports, 0, &paladin_governance_program::id()),
+        );
+    }
+
+    let instruction =
+        close_proposal_transaction(&stake_authority.pubkey(), &proposal, &proposal_transaction);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::UninitializedAccount)
+    );
+}
+
+#[test_case(ProposalStatus::Cancelled)]
+#[test_case(ProposalStatus::Rejected)]
+#[test_case(ProposalStatus::Processed)]
+#[test_case(ProposalStatus::QuorumNotMet)]
+#[test_case(ProposalStatus::Expired)]
+#[test_case(ProposalStatus::Vetoed)]
+#[tokio::test]
+async fn success(status: ProposalStatus) {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        Config::default(),
+        status,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction,
+        create_mock_proposal_transaction(&[&Pubkey::new_unique(), &Pubkey::new_unique()]),
+    )
+    .await;
+
+    let proposal_transaction_lamports = context
+        .banks_client
+        .get_account(proposal_transaction)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction =
+        close_proposal_transaction(&stake_authority.pubkey(), &proposal, &proposal_transaction);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal transaction account was closed.
+    assert!(context
+        .banks_client
+        .get_account(proposal_transaction)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Assert the stake authority received the proposal transaction's lamports.
+    assert_eq!(
+        context
+            .banks_client
+            .get_account(stake_authority.pubkey())
+            .await
+            .unwrap()
+            .unwrap()
+            .lamports,
+        proposal_transaction_lamports
+    );
+
+    // Assert the proposal account remains, so it can be closed next.
+    assert!(context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .is_some());
+}
@@ -0,0 +1,909 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::close_proposal_vote,
+        state::{get_proposal_vote_address, Config, ProposalStatus, ProposalVoteElection},
+    },
+    paladin_stake_program::state::{find_stake_pda, Stake},
+    setup::{
+        setup, setup_legacy_proposal_vote, setup_proposal, setup_proposal_vote,
+        setup_proposal_vote_with_payer, setup_stake,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        account::AccountSharedData,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    test_case::test_case,
+};
+
+#[tokio::test]
+async fn fail_stake_authority_not_signer() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    let mut instruction = close_proposal_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &proposal_vote,
+        &proposal,
+        &stake_authority.pubkey(),
+    );
+    instruction.accounts[0].is_signer = false; // Stake authority not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Stake authority not signer.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_not_migrated_stake_incorrect_owner() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    // Proposal votes with a previous layout are closed by the stake authority.
+    setup_legacy_proposal_vote(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        0,
+        &stake,
+        &stake_authority.pubkey(),
+        ProposalVoteElection::For,
+        2,
+    )
+    .await;
+
+    // Set up the stake account with the incorrect owner.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let space = std::mem::size_of::<Stake>();
+        let lamports = rent.minimum_balance(space);
+        context.set_account(
+            &stake,
+            &AccountSharedData::new(lamports, space, &Pubkey::new_unique()), // Incorrect owner.
+        );
+    }
+
+    let instruction = close_proposal_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &proposal_vote,
+        &proposal,
+        &stake_authority.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+    );
+}
+
+#[tokio::test]
+async fn fail_authority_incorrect() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal_vote_with_payer(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        0,
+        &stake,
+        &Pubkey::new_unique(), // Not the voting authority.
+        &Pubkey::new_unique(), // Not the payer.
+        ProposalVoteElection::For,
+    )
+    .await;
+
+    let instruction = close_proposal_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &proposal_vote,
+        &proposal,
+        &stake_authority.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+}
+
+#[tokio::test]
+async fn fail_not_migrated_stake_authority_incorrect() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &Pubkey::new_unique(), // Incorrect stake authority.
+        &validator_vote,
+        0,
+    )
+    .await;
+
+    // Proposal votes with a previous layout are closed by the stake authority.
+    setup_legacy_proposal_vote(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        0,
+        &stake,
+        &stake_authority.pubkey(),
+        ProposalVoteElection::For,
+        2,
+    )
+    .await;
+
+    let instruction = close_proposal_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &proposal_vote,
+        &proposal,
+        &stake_authority.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_vote_incorrect_address() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote = Pubkey::new_unique(); // Incorrect proposal vote address.
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal_vote(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        0,
+        &stake,
+        &stake_authority.pubkey(),
+        ProposalVoteElection::For,
+    )
+    .await;
+
+    let instruction = close_proposal_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &proposal_vote,
+        &proposal,
+        &stake_authority.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::IncorrectProposalVoteAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_vote_not_initialized() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        0,
+    )
+    .await;
+
+    // Set up the proposal vote account uninitialized.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(0);
+        context.set_account(
+            &proposal_vote,
+            &AccountSharedData::new(lamports, 0, &paladin_governance_program::id()),
+        );
+    }
+
+    let instruction = close_proposal_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &proposal_vote,
+        &proposal,
+        &stake_authority.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::UninitializedAccount)
+    );
+}
+
+#[test_case(ProposalStatus::Draft)]
+#[test_case(ProposalStatus::Voting)]
+#[test_case(ProposalStatus::Accepted)]
+#[tokio::test]
+async fn fail_proposal_not_terminal(status: ProposalStatus) {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        0,
+        Config::default(),
+        status,
+    )
+    .await;
+    setup_proposal_vote(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        0,
+        &stake,
+        &stake_authority.pubkey(),
+        ProposalVoteElection::For,
+    )
+    .await;
+
+    let instruction = close_proposal_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &proposal_vote,
+        &proposal,
+        &stake_authority.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::ProposalNotTerminal as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_incorrect_payer() {
+    let stake_authority = Keypair::new();
+    let payer = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique(); // Closed proposal.
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_proposal_vote_with_payer(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        0,
+        &stake,
+        &stake_authority.pubkey(),
+        &payer,
+        ProposalVoteElection::For,
+    )
+    .await;
+
+    let instruction = close_proposal_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &proposal_vote,
+        &proposal,
+        &stake_authority.pubkey(), // Not the recorded payer.
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::IncorrectProposalVotePayer as u32)
+        )
+    );
+}
+
+#[test_case(Some(ProposalStatus::Cancelled); "cancelled")]
+#[test_case(Some(ProposalStatus::Rejected); "rejected")]
+#[test_case(Some(ProposalStatus::Processed); "processed")]
+#[test_case(Some(ProposalStatus::QuorumNotMet); "quorum_not_met")]
+#[test_case(Some(ProposalStatus::Expired); "expired")]
+#[test_case(Some(ProposalStatus::Vetoed); "vetoed")]
+#[test_case(None; "proposal_closed")]
+#[tokio::test]
+async fn success(status: Option<ProposalStatus>) {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    if let Some(status) = status {
+        setup_proposal(
+            &mut context,
+            &proposal,
+            &Pubkey::new_unique(),
+            0,
+            Config::default(),
+            status,
+        )
+        .await;
+    }
+    setup_proposal_vote(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        0,
+        &stake,
+        &stake_authority.pubkey(),
+        ProposalVoteElection::For,
+    )
+    .await;
+
+    let proposal_vote_lamports = context
+        .banks_client
+        .get_account(proposal_vote)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction = close_proposal_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &proposal_vote,
+        &proposal,
+        &stake_authority.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal vote account was closed.
+    assert!(context
+        .banks_client
+        .get_account(proposal_vote)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Assert the stake authority received the proposal vote's lamports.
+    assert_eq!(
+        context
+            .banks_client
+            .get_account(stake_authority.pubkey())
+            .await
+            .unwrap()
+            .unwrap()
+            .lamports,
+        proposal_vote_lamports
+    );
+}
+
+#[test_case(0; "v0")]
+#[test_case(1; "v1")]
+#[test_case(2; "v2")]
+#[tokio::test]
+async fn success_not_migrated(version: u8) {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique(); // Closed proposal.
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        0,
+    )
+    .await;
+
+    // Set up a proposal vote account with a previous layout.
+    setup_legacy_proposal_vote(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        0,
+        &stake,
+        &stake_authority.pubkey(),
+        ProposalVoteElection::For,
+        version,
+    )
+    .await;
+
+    let instruction = close_proposal_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &proposal_vote,
+        &proposal,
+        &stake_authority.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal vote account was closed.
+    assert!(context
+        .banks_client
+        .get_account(proposal_vote)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn success_refunds_payer() {
+    let stake_authority = Keypair::new();
+    let payer = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique(); // Closed proposal.
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_proposal_vote_with_payer(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        0,
+        &stake,
+        &stake_authority.pubkey(),
+        &payer,
+        ProposalVoteElection::For,
+    )
+    .await;
+
+    let proposal_vote_lamports = context
+        .banks_client
+        .get_account(proposal_vote)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction = close_proposal_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &proposal_vote,
+        &proposal,
+        &payer,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal vote account was closed.
+    assert!(context
+        .banks_client
+        .get_account(proposal_vote)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Assert the payer, not the stake authority, received the lamports.
+    assert_eq!(
+        context
+            .banks_client
+            .get_account(payer)
+            .await
+            .unwrap()
+            .unwrap()
+            .lamports,
+        proposal_vote_lamports
+    );
+    assert!(context
+        .banks_client
+        .get_account(stake_authority.pubkey())
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn success_payer_signer() {
+    let payer = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique(); // Closed proposal.
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &Pubkey::new_unique(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_proposal_vote_with_payer(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        0,
+        &stake,
+        &Pubkey::new_unique(), // The voting authority does not sign.
+        &payer.pubkey(),
+        ProposalVoteElection::For,
+    )
+    .await;
+
+    let proposal_vote_lamports = context
+        .banks_client
+        .get_account(proposal_vote)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction = close_proposal_vote(
+        &payer.pubkey(),
+        &stake,
+        &proposal_vote,
+        &proposal,
+        &payer.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal vote account was closed.
+    assert!(context
+        .banks_client
+        .get_account(proposal_vote)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Assert the payer received the proposal vote's lamports.
+    assert_eq!(
+        context
+            .banks_client
+            .get_account(payer.pubkey())
+            .await
+            .unwrap()
+            .unwrap()
+            .lamports,
+        proposal_vote_lamports
+    );
+}
+
+#[tokio::test]
+async fn success_stake_closed() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique(); // Closed proposal.
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    // The stake account is not set up, as it has been closed.
+    let mut context = setup().start_with_context().await;
+    setup_proposal_vote(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        0,
+        &stake,
+        &stake_authority.pubkey(),
+        ProposalVoteElection::For,
+    )
+    .await;
+
+    let proposal_vote_lamports = context
+        .banks_client
+        .get_account(proposal_vote)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction = close_proposal_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &proposal_vote,
+        &proposal,
+        &stake_authority.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal vote account was closed.
+    assert!(context
+        .banks_client
+        .get_account(proposal_vote)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Assert the voting authority, which paid for the vote, received its
+    // lamports.
+    assert_eq!(
+        context
+            .banks_client
+            .get_account(stake_authority.pubkey())
+            .await
+            .unwrap()
+            .unwrap()
+            .lamports,
+        proposal_vote_lamports
+    );
+}
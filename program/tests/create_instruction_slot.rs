@@ -0,0 +1,481 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    borsh::BorshDeserialize,
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::create_instruction_slot,
+        state::{
+            get_proposal_transaction_address, Config, ProposalInstructionSlot, ProposalStatus,
+            ProposalTransaction, MAX_INSTRUCTION_SLOT_LEN,
+        },
+    },
+    setup::{setup, setup_proposal, setup_proposal_transaction},
+    solana_program_test::*,
+    solana_sdk::{
+        account::AccountSharedData,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        system_program,
+        transaction::{Transaction, TransactionError},
+    },
+};
+
+#[tokio::test]
+async fn fail_stake_authority_not_signer() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    let mut instruction = create_instruction_slot(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+        &context.payer.pubkey(),
+        1_024,
+    );
+    instruction.accounts[0].is_signer = false; // Stake authority not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Missing stake authority.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_payer_not_signer() {
+    let stake_authority = Keypair::new();
+    let payer = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    let mut instruction = create_instruction_slot(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+        &payer.pubkey(),
+        1_024,
+    );
+    instruction.accounts[3].is_signer = false; // Payer not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority], // Payer not signer.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_stake_authority_not_author() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &Pubkey::new_unique(), // Stake authority not author.
+        0,
+        Config::default(),
+        ProposalStatus::Draft,
+    )
+    .await;
+
+    let instruction = create_instruction_slot(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+        &context.payer.pubkey(),
+        1_024,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_not_in_draft_stage() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &stake_authority.pubkey(),
+        0,
+        Config::default(),
+        ProposalStatus::Voting, // Not in draft stage.
+    )
+    .await;
+
+    let instruction = create_instruction_slot(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+        &context.payer.pubkey(),
+        1_024,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::ProposalIsImmutable as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_transaction_incorrect_address() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address = Pubkey::new_unique(); // Incorrect proposal transaction address.
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &stake_authority.pubkey(),
+        0,
+        Config::default(),
+        ProposalStatus::Draft,
+    )
+    .await;
+
+    let instruction = create_instruction_slot(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+        &context.payer.pubkey(),
+        1_024,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                PaladinGovernanceError::IncorrectProposalTransactionAddress as u32
+            )
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_instruction_len_too_long() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &stake_authority.pubkey(),
+        0,
+        Config::default(),
+        ProposalStatus::Draft,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        ProposalTransaction::default(),
+    )
+    .await;
+
+    let instruction = create_instruction_slot(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+        &context.payer.pubkey(),
+        MAX_INSTRUCTION_SLOT_LEN + 1, // Too long.
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::InstructionSlotTooLong as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &stake_authority.pubkey(),
+        0,
+        Config::default(),
+        ProposalStatus::Draft,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        ProposalTransaction::default(),
+    )
+    .await;
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let instruction = create_instruction_slot(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+        &context.payer.pubkey(),
+        20_000,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal transaction account has an empty slot of the
+    // declared length, without reserving its space.
+    let proposal_transaction_account = context
+        .banks_client
+        .get_account(proposal_transaction_address)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_transaction_state =
+        ProposalTransaction::try_from_slice(&proposal_transaction_account.data).unwrap();
+
+    assert_eq!(
+        proposal_transaction_account.lamports,
+        rent.minimum_balance(proposal_transaction_account.data.len())
+    );
+    assert!(proposal_transaction_state.instructions.is_empty());
+    assert_eq!(
+        proposal_transaction_state.instruction_slot,
+        Some(ProposalInstructionSlot::new(20_000))
+    );
+}
+
+#[tokio::test]
+async fn success_discard_unfinalized_slot() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &stake_authority.pubkey(),
+        0,
+        Config::default(),
+        ProposalStatus::Draft,
+    )
+    .await;
+
+    // Set up a proposal transaction with a partially written slot.
+    let mut proposal_transaction = ProposalTransaction::default();
+    let mut instruction_slot = ProposalInstructionSlot::new(1_024);
+    instruction_slot.write(0, &[1; 512]).unwrap();
+    proposal_transaction.instruction_slot = Some(instruction_slot);
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        proposal_transaction,
+    )
+    .await;
+
+    // Fund the stake authority, which is refunded the rent freed by
+    // discarding the slot.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    context.set_account(
+        &stake_authority.pubkey(),
+        &AccountSharedData::new(rent.minimum_balance(0), 0, &system_program::id()),
+    );
+
+    let proposal_transaction_lamports = context
+        .banks_client
+        .get_balance(proposal_transaction_address)
+        .await
+        .unwrap();
+    let stake_authority_lamports = context
+        .banks_client
+        .get_balance(stake_authority.pubkey())
+        .await
+        .unwrap();
+
+    let instruction = create_instruction_slot(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+        &context.payer.pubkey(),
+        256,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the partially written slot was replaced.
+    let proposal_transaction_account = context
+        .banks_client
+        .get_account(proposal_transaction_address)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_transaction_state =
+        ProposalTransaction::try_from_slice(&proposal_transaction_account.data).unwrap();
+    assert_eq!(
+        proposal_transaction_state.instruction_slot,
+        Some(ProposalInstructionSlot::new(256))
+    );
+
+    // Assert the excess rent was refunded to the stake authority.
+    assert_eq!(
+        proposal_transaction_account.lamports,
+        rent.minimum_balance(proposal_transaction_account.data.len())
+    );
+    let refund = proposal_transaction_lamports - proposal_transaction_account.lamports;
+    assert!(refund > 0);
+    assert_eq!(
+        context
+            .banks_client
+            .get_balance(stake_authority.pubkey())
+            .await
+            .unwrap(),
+        stake_authority_lamports + refund
+    );
+}
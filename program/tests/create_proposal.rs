@@ -4,15 +4,21 @@ mod setup;
 
 use {
     borsh::BorshDeserialize,
+    bytemuck::Zeroable,
     paladin_governance_program::{
         error::PaladinGovernanceError,
         instruction::create_proposal,
         state::{
-            get_proposal_transaction_address, Config, Proposal, ProposalStatus, ProposalTransaction,
+            get_proposal_address, get_proposal_transaction_address, Config, ConfigV0, Proposal,
+            ProposalStatus, ProposalTransaction, ProposalVote, ProposalVoteElection,
+            MAX_PROPOSAL_TITLE_LEN, MAX_PROPOSAL_URI_LEN,
         },
     },
-    paladin_stake_program::state::Stake,
-    setup::{setup, setup_governance, setup_proposal, setup_proposal_transaction, setup_stake},
+    paladin_stake_program::state::{find_stake_pda, Stake},
+    setup::{
+        setup, setup_governance, setup_legacy_account, setup_proposal, setup_proposal_transaction,
+        setup_proposal_vote, setup_stake,
+    },
     solana_program_test::*,
     solana_sdk::{
         account::AccountSharedData,
@@ -27,6 +33,10 @@ use {
     },
 };
 
+const TITLE: &str = "Raise the proposal acceptance threshold";
+const URI: &str = "https://example.com/proposals/raise-acceptance-threshold.md";
+const CONTENT_HASH: [u8; 32] = [7; 32];
+
 #[tokio::test]
 async fn fail_stake_authority_not_signer() {
     let stake_authority = Keypair::new();
@@ -44,6 +54,10 @@ async fn fail_stake_authority_not_signer() {
         &proposal,
         &proposal_transaction,
         &governance,
+        &context.payer.pubkey(),
+        TITLE.to_string(),
+        URI.to_string(),
+        CONTENT_HASH,
     );
     instruction.accounts[0].is_signer = false; // Stake authority not signer.
 
@@ -67,6 +81,51 @@ async fn fail_stake_authority_not_signer() {
     );
 }
 
+#[tokio::test]
+async fn fail_payer_not_signer() {
+    let stake_authority = Keypair::new();
+    let payer = Keypair::new();
+    let stake = Pubkey::new_unique(); // PDA doesn't matter here.
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+    let governance = Pubkey::new_unique(); // PDA doesn't matter here.
+
+    let mut context = setup().start_with_context().await;
+
+    let mut instruction = create_proposal(
+        &stake_authority.pubkey(),
+        &stake,
+        &proposal,
+        &proposal_transaction,
+        &governance,
+        &payer.pubkey(),
+        TITLE.to_string(),
+        URI.to_string(),
+        CONTENT_HASH,
+    );
+    instruction.accounts[5].is_signer = false; // Payer not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority], // Payer not signer.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
 #[tokio::test]
 async fn fail_stake_incorrect_owner() {
     let stake_authority = Keypair::new();
@@ -77,6 +136,20 @@ async fn fail_stake_incorrect_owner() {
     let governance = Pubkey::new_unique(); // PDA doesn't matter here.
 
     let mut context = setup().start_with_context().await;
+    setup_governance(
+        &mut context,
+        &governance,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        /* stake_config_address */ &Pubkey::new_unique(), // Doesn't matter here.
+        None,
+        0,
+    )
+    .await;
 
     // Set up the stake account with the incorrect owner.
     {
@@ -95,6 +168,10 @@ async fn fail_stake_incorrect_owner() {
         &proposal,
         &proposal_transaction,
         &governance,
+        &context.payer.pubkey(),
+        TITLE.to_string(),
+        URI.to_string(),
+        CONTENT_HASH,
     );
 
     let transaction = Transaction::new_signed_with_payer(
@@ -127,6 +204,20 @@ async fn fail_stake_not_initialized() {
     let governance = Pubkey::new_unique(); // PDA doesn't matter here.
 
     let mut context = setup().start_with_context().await;
+    setup_governance(
+        &mut context,
+        &governance,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        /* stake_config_address */ &Pubkey::new_unique(), // Doesn't matter here.
+        None,
+        0,
+    )
+    .await;
 
     // Set up an uninitialized stake account.
     {
@@ -145,6 +236,10 @@ async fn fail_stake_not_initialized() {
         &proposal,
         &proposal_transaction,
         &governance,
+        &context.payer.pubkey(),
+        TITLE.to_string(),
+        URI.to_string(),
+        CONTENT_HASH,
     );
 
     let transaction = Transaction::new_signed_with_payer(
@@ -177,6 +272,20 @@ async fn fail_stake_incorrect_stake_authority() {
     let governance = Pubkey::new_unique(); // PDA doesn't matter here.
 
     let mut context = setup().start_with_context().await;
+    setup_governance(
+        &mut context,
+        &governance,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        /* stake_config_address */ &Pubkey::new_unique(), // Doesn't matter here.
+        None,
+        0,
+    )
+    .await;
 
     // Set up a stake account with the wrong stake authority address.
     setup_stake(
@@ -194,6 +303,10 @@ async fn fail_stake_incorrect_stake_authority() {
         &proposal,
         &proposal_transaction,
         &governance,
+        &context.payer.pubkey(),
+        TITLE.to_string(),
+        URI.to_string(),
+        CONTENT_HASH,
     );
 
     let transaction = Transaction::new_signed_with_payer(
@@ -216,6 +329,81 @@ async fn fail_stake_incorrect_stake_authority() {
     );
 }
 
+#[tokio::test]
+async fn fail_stake_incorrect_stake_config() {
+    let stake_authority = Keypair::new();
+    let stake_config = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let stake = find_stake_pda(
+        &validator_vote,
+        &Pubkey::new_unique(), // Foreign stake config.
+        &paladin_stake_program::id(),
+    )
+    .0;
+    let governance = Pubkey::new_unique(); // PDA doesn't matter here.
+    let proposal = get_proposal_address(&governance, 0, &paladin_governance_program::id());
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_governance(
+        &mut context,
+        &governance,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        &stake_config,
+        None,
+        0,
+    )
+    .await;
+
+    let instruction = create_proposal(
+        &stake_authority.pubkey(),
+        &stake,
+        &proposal,
+        &proposal_transaction,
+        &governance,
+        &context.payer.pubkey(),
+        TITLE.to_string(),
+        URI.to_string(),
+        CONTENT_HASH,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::StakeConfigMismatch as u32)
+        )
+    );
+}
+
 #[tokio::test]
 async fn fail_governance_incorrect_owner() {
     let stake_authority = Keypair::new();
@@ -252,6 +440,10 @@ async fn fail_governance_incorrect_owner() {
         &proposal,
         &proposal_transaction,
         &governance,
+        &context.payer.pubkey(),
+        TITLE.to_string(),
+        URI.to_string(),
+        CONTENT_HASH,
     );
 
     let transaction = Transaction::new_signed_with_payer(
@@ -309,6 +501,10 @@ async fn fail_governance_not_initialized() {
         &proposal,
         &proposal_transaction,
         &governance,
+        &context.payer.pubkey(),
+        TITLE.to_string(),
+        URI.to_string(),
+        CONTENT_HASH,
     );
 
     let transaction = Transaction::new_signed_with_payer(
@@ -332,7 +528,7 @@ async fn fail_governance_not_initialized() {
 }
 
 #[tokio::test]
-async fn fail_proposal_incorrect_owner() {
+async fn fail_governance_not_migrated() {
     let stake_authority = Keypair::new();
     let stake = Pubkey::new_unique(); // PDA doesn't matter here.
     let proposal = Pubkey::new_unique();
@@ -349,25 +545,174 @@ async fn fail_proposal_incorrect_owner() {
         0,
     )
     .await;
-    setup_governance(
-        &mut context,
+
+    // Set up the governance account with a previous layout.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(std::mem::size_of::<ConfigV0>());
+        setup_legacy_account(&mut context, &governance, &ConfigV0::zeroed(), lamports).await;
+    }
+
+    let instruction = create_proposal(
+        &stake_authority.pubkey(),
+        &stake,
+        &proposal,
+        &proposal_transaction,
         &governance,
+        &context.payer.pubkey(),
+        TITLE.to_string(),
+        URI.to_string(),
+        CONTENT_HASH,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::AccountVersionMismatch as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_governance_incorrect_account_type() {
+    let stake_authority = Keypair::new();
+    let stake = Pubkey::new_unique(); // PDA doesn't matter here.
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+    let governance = Pubkey::new_unique(); // PDA doesn't matter here.
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        /* validator_vote_address */ &Pubkey::new_unique(), // Unused here.
         0,
+    )
+    .await;
+
+    // Set up a proposal vote in place of the governance account. The two are
+    // the same size, so only the discriminator tells them apart.
+    assert_eq!(
+        std::mem::size_of::<ProposalVote>(),
+        std::mem::size_of::<Config>()
+    );
+    setup_proposal_vote(
+        &mut context,
+        &governance,
+        &proposal,
         0,
-        0,
-        /* stake_config_address */ &Pubkey::new_unique(), // Doesn't matter here.
-        0,
+        &stake,
+        &stake_authority.pubkey(),
+        ProposalVoteElection::For,
     )
     .await;
 
-    // Set up the proposal account with the incorrect owner.
+    let instruction = create_proposal(
+        &stake_authority.pubkey(),
+        &stake,
+        &proposal,
+        &proposal_transaction,
+        &governance,
+        &context.payer.pubkey(),
+        TITLE.to_string(),
+        URI.to_string(),
+        CONTENT_HASH,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::UninitializedAccount)
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_minimum_stake_not_met() {
+    let stake_authority = Keypair::new();
+    let stake_config = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let governance = Pubkey::new_unique(); // PDA doesn't matter here.
+    let proposal = get_proposal_address(&governance, 0, &paladin_governance_program::id());
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 100_000_000,
+        /* execution_window_seconds */ 0,
+        /* proposal_acceptance_threshold */ 500_000_000, // 50%
+        /* proposal_minimum_stake */ 1_000_000,
+        /* proposal_rejection_threshold */ 500_000_000, // 50%
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        &stake_config,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 100_000_000,
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        /* amount */ 999_999, // Not enough stake.
+    )
+    .await;
+    setup_governance(
+        &mut context,
+        &governance,
+        governance_config.cooldown_period_seconds,
+        governance_config.execution_window_seconds,
+        governance_config.proposal_acceptance_threshold,
+        governance_config.proposal_minimum_stake,
+        governance_config.proposal_rejection_threshold,
+        governance_config.quorum_threshold,
+        &governance_config.stake_config_address,
+        None,
+        governance_config.voting_period_seconds,
+    )
+    .await;
+
+    // Fund the proposal account.
     {
         let rent = context.banks_client.get_rent().await.unwrap();
+
         let space = std::mem::size_of::<Proposal>();
         let lamports = rent.minimum_balance(space);
         context.set_account(
             &proposal,
-            &AccountSharedData::new(lamports, space, &Pubkey::new_unique()), // Incorrect owner.
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
         );
     }
 
@@ -377,6 +722,10 @@ async fn fail_proposal_incorrect_owner() {
         &proposal,
         &proposal_transaction,
         &governance,
+        &context.payer.pubkey(),
+        TITLE.to_string(),
+        URI.to_string(),
+        CONTENT_HASH,
     );
 
     let transaction = Transaction::new_signed_with_payer(
@@ -395,15 +744,20 @@ async fn fail_proposal_incorrect_owner() {
 
     assert_eq!(
         err,
-        TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::ProposalMinimumStakeNotMet as u32)
+        )
     );
 }
 
 #[tokio::test]
-async fn fail_proposal_not_enough_space() {
+async fn fail_proposal_incorrect_address() {
     let stake_authority = Keypair::new();
-    let stake = Pubkey::new_unique(); // PDA doesn't matter here.
-    let proposal = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal = Pubkey::new_unique(); // Incorrect proposal address.
     let proposal_transaction =
         get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
     let governance = Pubkey::new_unique(); // PDA doesn't matter here.
@@ -413,7 +767,7 @@ async fn fail_proposal_not_enough_space() {
         &mut context,
         &stake,
         &stake_authority.pubkey(),
-        /* validator_vote_address */ &Pubkey::new_unique(), // Unused here.
+        &validator_vote,
         0,
     )
     .await;
@@ -423,28 +777,25 @@ async fn fail_proposal_not_enough_space() {
         0,
         0,
         0,
-        /* stake_config_address */ &Pubkey::new_unique(), // Doesn't matter here.
+        0,
+        0,
+        0,
+        &stake_config,
+        None,
         0,
     )
     .await;
 
-    // Set up the proposal account with not enough space.
-    {
-        let rent = context.banks_client.get_rent().await.unwrap();
-        let space = std::mem::size_of::<Proposal>() - 1; // Not enough space.
-        let lamports = rent.minimum_balance(space);
-        context.set_account(
-            &proposal,
-            &AccountSharedData::new(lamports, space, &paladin_governance_program::id()),
-        );
-    }
-
     let instruction = create_proposal(
         &stake_authority.pubkey(),
         &stake,
         &proposal,
         &proposal_transaction,
         &governance,
+        &context.payer.pubkey(),
+        TITLE.to_string(),
+        URI.to_string(),
+        CONTENT_HASH,
     );
 
     let transaction = Transaction::new_signed_with_payer(
@@ -463,25 +814,30 @@ async fn fail_proposal_not_enough_space() {
 
     assert_eq!(
         err,
-        TransactionError::InstructionError(0, InstructionError::InvalidAccountData)
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::IncorrectProposalAddress as u32)
+        )
     );
 }
 
 #[tokio::test]
 async fn fail_proposal_already_initialized() {
     let stake_authority = Keypair::new();
-    let stake = Pubkey::new_unique(); // PDA doesn't matter here.
-    let proposal = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let governance = Pubkey::new_unique(); // PDA doesn't matter here.
+    let proposal = get_proposal_address(&governance, 0, &paladin_governance_program::id());
     let proposal_transaction =
         get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
-    let governance = Pubkey::new_unique(); // PDA doesn't matter here.
 
     let mut context = setup().start_with_context().await;
     setup_stake(
         &mut context,
         &stake,
         &stake_authority.pubkey(),
-        /* validator_vote_address */ &Pubkey::new_unique(), // Unused here.
+        &validator_vote,
         0,
     )
     .await;
@@ -491,7 +847,11 @@ async fn fail_proposal_already_initialized() {
         0,
         0,
         0,
-        /* stake_config_address */ &Pubkey::new_unique(), // Doesn't matter here.
+        0,
+        0,
+        0,
+        &stake_config,
+        None,
         0,
     )
     .await;
@@ -513,6 +873,10 @@ async fn fail_proposal_already_initialized() {
         &proposal,
         &proposal_transaction,
         &governance,
+        &context.payer.pubkey(),
+        TITLE.to_string(),
+        URI.to_string(),
+        CONTENT_HASH,
     );
 
     let transaction = Transaction::new_signed_with_payer(
@@ -538,17 +902,23 @@ async fn fail_proposal_already_initialized() {
 #[tokio::test]
 async fn fail_proposal_transaction_incorrect_address() {
     let stake_authority = Keypair::new();
-    let stake = Pubkey::new_unique(); // PDA doesn't matter here.
-    let proposal = Pubkey::new_unique();
-    let proposal_transaction = Pubkey::new_unique(); // Incorrect address.
+    let stake_config = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
     let governance = Pubkey::new_unique(); // PDA doesn't matter here.
+    let proposal = get_proposal_address(&governance, 0, &paladin_governance_program::id());
+    let proposal_transaction = Pubkey::new_unique(); // Incorrect address.
 
     let governance_config = Config::new(
         /* cooldown_period_seconds */ 100_000_000,
+        /* execution_window_seconds */ 0,
         /* proposal_acceptance_threshold */ 500_000_000, // 50%
+        /* proposal_minimum_stake */ 0,
         /* proposal_rejection_threshold */ 500_000_000, // 50%
+        /* quorum_threshold */ 0,
         /* signer_bump_seed */ 0,
-        /* stake_config_address */ &Pubkey::new_unique(), // Doesn't matter here.
+        &stake_config,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 100_000_000,
     );
 
@@ -557,7 +927,7 @@ async fn fail_proposal_transaction_incorrect_address() {
         &mut context,
         &stake,
         &stake_authority.pubkey(),
-        /* validator_vote_address */ &Pubkey::new_unique(), // Unused here.
+        &validator_vote,
         0,
     )
     .await;
@@ -565,9 +935,13 @@ async fn fail_proposal_transaction_incorrect_address() {
         &mut context,
         &governance,
         governance_config.cooldown_period_seconds,
+        governance_config.execution_window_seconds,
         governance_config.proposal_acceptance_threshold,
+        governance_config.proposal_minimum_stake,
         governance_config.proposal_rejection_threshold,
+        governance_config.quorum_threshold,
         &governance_config.stake_config_address,
+        None,
         governance_config.voting_period_seconds,
     )
     .await;
@@ -580,7 +954,7 @@ async fn fail_proposal_transaction_incorrect_address() {
         let lamports = rent.minimum_balance(space);
         context.set_account(
             &proposal,
-            &AccountSharedData::new(lamports, space, &paladin_governance_program::id()),
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
         );
     }
 
@@ -590,6 +964,10 @@ async fn fail_proposal_transaction_incorrect_address() {
         &proposal,
         &proposal_transaction,
         &governance,
+        &context.payer.pubkey(),
+        TITLE.to_string(),
+        URI.to_string(),
+        CONTENT_HASH,
     );
 
     let transaction = Transaction::new_signed_with_payer(
@@ -620,18 +998,24 @@ async fn fail_proposal_transaction_incorrect_address() {
 #[tokio::test]
 async fn fail_proposal_transaction_already_initialized() {
     let stake_authority = Keypair::new();
-    let stake = Pubkey::new_unique(); // PDA doesn't matter here.
-    let proposal = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let governance = Pubkey::new_unique(); // PDA doesn't matter here.
+    let proposal = get_proposal_address(&governance, 0, &paladin_governance_program::id());
     let proposal_transaction =
         get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
-    let governance = Pubkey::new_unique(); // PDA doesn't matter here.
 
     let governance_config = Config::new(
         /* cooldown_period_seconds */ 100_000_000,
+        /* execution_window_seconds */ 0,
         /* proposal_acceptance_threshold */ 500_000_000, // 50%
+        /* proposal_minimum_stake */ 0,
         /* proposal_rejection_threshold */ 500_000_000, // 50%
+        /* quorum_threshold */ 0,
         /* signer_bump_seed */ 0,
-        /* stake_config_address */ &Pubkey::new_unique(), // Doesn't matter here.
+        &stake_config,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 100_000_000,
     );
 
@@ -640,7 +1024,7 @@ async fn fail_proposal_transaction_already_initialized() {
         &mut context,
         &stake,
         &stake_authority.pubkey(),
-        /* validator_vote_address */ &Pubkey::new_unique(), // Unused here.
+        &validator_vote,
         0,
     )
     .await;
@@ -648,9 +1032,13 @@ async fn fail_proposal_transaction_already_initialized() {
         &mut context,
         &governance,
         governance_config.cooldown_period_seconds,
+        governance_config.execution_window_seconds,
         governance_config.proposal_acceptance_threshold,
+        governance_config.proposal_minimum_stake,
         governance_config.proposal_rejection_threshold,
+        governance_config.quorum_threshold,
         &governance_config.stake_config_address,
+        None,
         governance_config.voting_period_seconds,
     )
     .await;
@@ -671,7 +1059,7 @@ async fn fail_proposal_transaction_already_initialized() {
         let lamports = rent.minimum_balance(space);
         context.set_account(
             &proposal,
-            &AccountSharedData::new(lamports, space, &paladin_governance_program::id()),
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
         );
     }
 
@@ -681,6 +1069,10 @@ async fn fail_proposal_transaction_already_initialized() {
         &proposal,
         &proposal_transaction,
         &governance,
+        &context.payer.pubkey(),
+        TITLE.to_string(),
+        URI.to_string(),
+        CONTENT_HASH,
     );
 
     let transaction = Transaction::new_signed_with_payer(
@@ -704,20 +1096,26 @@ async fn fail_proposal_transaction_already_initialized() {
 }
 
 #[tokio::test]
-async fn success() {
+async fn fail_title_too_long() {
     let stake_authority = Keypair::new();
-    let stake = Pubkey::new_unique(); // PDA doesn't matter here.
-    let proposal = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let governance = Pubkey::new_unique(); // PDA doesn't matter here.
+    let proposal = get_proposal_address(&governance, 0, &paladin_governance_program::id());
     let proposal_transaction =
         get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
-    let governance = Pubkey::new_unique(); // PDA doesn't matter here.
 
     let governance_config = Config::new(
         /* cooldown_period_seconds */ 100_000_000,
+        /* execution_window_seconds */ 0,
         /* proposal_acceptance_threshold */ 500_000_000, // 50%
+        /* proposal_minimum_stake */ 0,
         /* proposal_rejection_threshold */ 500_000_000, // 50%
+        /* quorum_threshold */ 0,
         /* signer_bump_seed */ 0,
-        /* stake_config_address */ &Pubkey::new_unique(), // Doesn't matter here.
+        &stake_config,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 100_000_000,
     );
 
@@ -726,7 +1124,7 @@ async fn success() {
         &mut context,
         &stake,
         &stake_authority.pubkey(),
-        /* validator_vote_address */ &Pubkey::new_unique(), // Unused here.
+        &validator_vote,
         0,
     )
     .await;
@@ -734,14 +1132,18 @@ async fn success() {
         &mut context,
         &governance,
         governance_config.cooldown_period_seconds,
+        governance_config.execution_window_seconds,
         governance_config.proposal_acceptance_threshold,
+        governance_config.proposal_minimum_stake,
         governance_config.proposal_rejection_threshold,
+        governance_config.quorum_threshold,
         &governance_config.stake_config_address,
+        None,
         governance_config.voting_period_seconds,
     )
     .await;
 
-    // Fund the proposal and proposal transaction accounts.
+    // Fund the proposal account.
     {
         let rent = context.banks_client.get_rent().await.unwrap();
 
@@ -749,18 +1151,190 @@ async fn success() {
         let lamports = rent.minimum_balance(space);
         context.set_account(
             &proposal,
-            &AccountSharedData::new(lamports, space, &paladin_governance_program::id()),
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
         );
+    }
+
+    let instruction = create_proposal(
+        &stake_authority.pubkey(),
+        &stake,
+        &proposal,
+        &proposal_transaction,
+        &governance,
+        &context.payer.pubkey(),
+        "a".repeat(MAX_PROPOSAL_TITLE_LEN + 1), // Title too long.
+        URI.to_string(),
+        CONTENT_HASH,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
 
-        let space = get_instance_packed_len(&ProposalTransaction::default()).unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::ProposalTitleTooLong as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_uri_too_long() {
+    let stake_authority = Keypair::new();
+    let stake_config = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let governance = Pubkey::new_unique(); // PDA doesn't matter here.
+    let proposal = get_proposal_address(&governance, 0, &paladin_governance_program::id());
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 100_000_000,
+        /* execution_window_seconds */ 0,
+        /* proposal_acceptance_threshold */ 500_000_000, // 50%
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 500_000_000, // 50%
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        &stake_config,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 100_000_000,
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_governance(
+        &mut context,
+        &governance,
+        governance_config.cooldown_period_seconds,
+        governance_config.execution_window_seconds,
+        governance_config.proposal_acceptance_threshold,
+        governance_config.proposal_minimum_stake,
+        governance_config.proposal_rejection_threshold,
+        governance_config.quorum_threshold,
+        &governance_config.stake_config_address,
+        None,
+        governance_config.voting_period_seconds,
+    )
+    .await;
+
+    // Fund the proposal account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+
+        let space = std::mem::size_of::<Proposal>();
         let lamports = rent.minimum_balance(space);
         context.set_account(
-            &proposal_transaction,
+            &proposal,
             &AccountSharedData::new(lamports, 0, &system_program::id()),
         );
     }
 
+    let instruction = create_proposal(
+        &stake_authority.pubkey(),
+        &stake,
+        &proposal,
+        &proposal_transaction,
+        &governance,
+        &context.payer.pubkey(),
+        TITLE.to_string(),
+        "a".repeat(MAX_PROPOSAL_URI_LEN + 1), // URI too long.
+        CONTENT_HASH,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::ProposalUriTooLong as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let stake_authority = Keypair::new();
+    let stake_config = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let governance = Pubkey::new_unique(); // PDA doesn't matter here.
+    let proposal = get_proposal_address(&governance, 0, &paladin_governance_program::id());
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 100_000_000,
+        /* execution_window_seconds */ 0,
+        /* proposal_acceptance_threshold */ 500_000_000, // 50%
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 500_000_000, // 50%
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        &stake_config,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 100_000_000,
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_governance(
+        &mut context,
+        &governance,
+        governance_config.cooldown_period_seconds,
+        governance_config.execution_window_seconds,
+        governance_config.proposal_acceptance_threshold,
+        governance_config.proposal_minimum_stake,
+        governance_config.proposal_rejection_threshold,
+        governance_config.quorum_threshold,
+        &governance_config.stake_config_address,
+        None,
+        governance_config.voting_period_seconds,
+    )
+    .await;
+
     // For checks later.
+    let rent = context.banks_client.get_rent().await.unwrap();
     let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
     let timestamp = clock.unix_timestamp;
 
@@ -770,6 +1344,10 @@ async fn success() {
         &proposal,
         &proposal_transaction,
         &governance,
+        &context.payer.pubkey(),
+        TITLE.to_string(),
+        URI.to_string(),
+        CONTENT_HASH,
     );
 
     let transaction = Transaction::new_signed_with_payer(
@@ -792,9 +1370,18 @@ async fn success() {
         .await
         .unwrap()
         .unwrap();
+    let mut expected_proposal_state =
+        Proposal::new(&stake_authority.pubkey(), timestamp, governance_config);
+    expected_proposal_state
+        .set_metadata(TITLE, URI, CONTENT_HASH)
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state, &expected_proposal_state);
+    assert_eq!(proposal_state.title_str(), TITLE);
+    assert_eq!(proposal_state.uri_str(), URI);
     assert_eq!(
-        bytemuck::from_bytes::<Proposal>(&proposal_account.data),
-        &Proposal::new(&stake_authority.pubkey(), timestamp, governance_config)
+        proposal_account.lamports,
+        rent.minimum_balance(std::mem::size_of::<Proposal>())
     );
 
     // Assert the proposal transaction was created.
@@ -806,4 +1393,18 @@ async fn success() {
         .unwrap();
     let state = ProposalTransaction::try_from_slice(&proposal_transaction_account.data).unwrap();
     assert_eq!(state, ProposalTransaction::default());
+    assert_eq!(
+        proposal_transaction_account.lamports,
+        rent.minimum_balance(get_instance_packed_len(&ProposalTransaction::default()).unwrap())
+    );
+
+    // Assert the governance config's proposal count was incremented.
+    let governance_account = context
+        .banks_client
+        .get_account(governance)
+        .await
+        .unwrap()
+        .unwrap();
+    let governance_state = bytemuck::from_bytes::<Config>(&governance_account.data);
+    assert_eq!(governance_state.proposal_count, 1);
 }
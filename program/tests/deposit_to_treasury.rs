@@ -0,0 +1,307 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::{deposit_to_treasury, deposit_tokens_to_treasury},
+        state::{get_governance_address, get_treasury_address},
+    },
+    setup::{setup, setup_governance, setup_mint, setup_token_account},
+    solana_program_test::*,
+    solana_sdk::{
+        account::AccountSharedData,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        system_program,
+        transaction::{Transaction, TransactionError},
+    },
+    spl_associated_token_account::get_associated_token_address_with_program_id,
+    spl_token_2022::{extension::StateWithExtensions, state::Account as TokenAccount},
+    test_case::test_case,
+};
+
+async fn setup_governance_for_treasury(context: &mut ProgramTestContext, stake_config: &Pubkey) {
+    let governance = get_governance_address(stake_config, &paladin_governance_program::id());
+    setup_governance(
+        context,
+        &governance,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        stake_config,
+        None,
+        0,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn fail_depositor_not_signer() {
+    let depositor = Keypair::new();
+    let stake_config = Pubkey::new_unique();
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_governance_for_treasury(&mut context, &stake_config).await;
+
+    let mut instruction = deposit_to_treasury(&depositor.pubkey(), &treasury, &governance, 1);
+    instruction.accounts[0].is_signer = false; // Depositor not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Depositor not signer.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_treasury_incorrect_address() {
+    let depositor = Keypair::new();
+    let stake_config = Pubkey::new_unique();
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let treasury = Pubkey::new_unique(); // Incorrect treasury address.
+
+    let mut context = setup().start_with_context().await;
+    setup_governance_for_treasury(&mut context, &stake_config).await;
+
+    let instruction = deposit_to_treasury(&depositor.pubkey(), &treasury, &governance, 1);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &depositor],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::IncorrectTreasuryAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_treasury_token_account_incorrect_address() {
+    let depositor = Keypair::new();
+    let depositor_token_account = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_governance_for_treasury(&mut context, &stake_config).await;
+    setup_mint(&mut context, &mint, &spl_token_2022::id(), 6, 1_000).await;
+    setup_token_account(
+        &mut context,
+        &depositor_token_account,
+        &spl_token_2022::id(),
+        &mint,
+        &depositor.pubkey(),
+        1_000,
+    )
+    .await;
+
+    let mut instruction = deposit_tokens_to_treasury(
+        &depositor.pubkey(),
+        &depositor_token_account,
+        &treasury,
+        &governance,
+        &mint,
+        &spl_token_2022::id(),
+        1_000,
+    );
+    instruction.accounts[5].pubkey = Pubkey::new_unique(); // Incorrect treasury token account.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &depositor],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                PaladinGovernanceError::IncorrectTreasuryTokenAccountAddress as u32
+            )
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let depositor = Keypair::new();
+    let stake_config = Pubkey::new_unique();
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_governance_for_treasury(&mut context, &stake_config).await;
+
+    context.set_account(
+        &depositor.pubkey(),
+        &AccountSharedData::new(10_000_000_000, 0, &system_program::id()),
+    );
+
+    let instruction =
+        deposit_to_treasury(&depositor.pubkey(), &treasury, &governance, 5_000_000_000);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &depositor],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the lamports were moved to the treasury.
+    assert_eq!(
+        context
+            .banks_client
+            .get_account(treasury)
+            .await
+            .unwrap()
+            .unwrap()
+            .lamports,
+        5_000_000_000
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .get_account(depositor.pubkey())
+            .await
+            .unwrap()
+            .unwrap()
+            .lamports,
+        5_000_000_000
+    );
+}
+
+#[test_case(spl_token::id(); "token")]
+#[test_case(spl_token_2022::id(); "token_2022")]
+#[tokio::test]
+async fn success_tokens(token_program_id: Pubkey) {
+    let depositor = Keypair::new();
+    let depositor_token_account = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&stake_config, &paladin_governance_program::id());
+    let treasury_token_account =
+        get_associated_token_address_with_program_id(&treasury, &mint, &token_program_id);
+
+    let mut context = setup().start_with_context().await;
+    setup_governance_for_treasury(&mut context, &stake_config).await;
+    setup_mint(&mut context, &mint, &token_program_id, 6, 1_000).await;
+    setup_token_account(
+        &mut context,
+        &depositor_token_account,
+        &token_program_id,
+        &mint,
+        &depositor.pubkey(),
+        1_000,
+    )
+    .await;
+    setup_token_account(
+        &mut context,
+        &treasury_token_account,
+        &token_program_id,
+        &mint,
+        &treasury,
+        0,
+    )
+    .await;
+
+    let instruction = deposit_tokens_to_treasury(
+        &depositor.pubkey(),
+        &depositor_token_account,
+        &treasury,
+        &governance,
+        &mint,
+        &token_program_id,
+        600,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &depositor],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the tokens were moved to the treasury token account.
+    let treasury_token_account = context
+        .banks_client
+        .get_account(treasury_token_account)
+        .await
+        .unwrap()
+        .unwrap();
+    let treasury_token_account_state =
+        StateWithExtensions::<TokenAccount>::unpack(&treasury_token_account.data)
+            .unwrap()
+            .base;
+    assert_eq!(treasury_token_account_state.amount, 600);
+
+    let depositor_token_account = context
+        .banks_client
+        .get_account(depositor_token_account)
+        .await
+        .unwrap()
+        .unwrap();
+    let depositor_token_account_state =
+        StateWithExtensions::<TokenAccount>::unpack(&depositor_token_account.data)
+            .unwrap()
+            .base;
+    assert_eq!(depositor_token_account_state.amount, 400);
+}
@@ -0,0 +1,555 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    borsh::BorshDeserialize,
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::{create_instruction_slot, finalize_instruction_slot, write_instruction_slot},
+        state::{
+            get_proposal_transaction_address, Config, ProposalAccountMeta, ProposalInstruction,
+            ProposalInstructionSlot, ProposalStatus, ProposalTransaction,
+        },
+    },
+    setup::{setup, setup_proposal, setup_proposal_transaction},
+    solana_program_test::*,
+    solana_sdk::{
+        account::AccountSharedData,
+        entrypoint::MAX_PERMITTED_DATA_INCREASE,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        system_program,
+        transaction::{Transaction, TransactionError},
+    },
+};
+
+fn proposal_transaction_with_slot(data: &[u8], instruction_len: u32) -> ProposalTransaction {
+    let mut instruction_slot = ProposalInstructionSlot::new(instruction_len);
+    instruction_slot.write(0, data).unwrap();
+    let mut proposal_transaction = ProposalTransaction::default();
+    proposal_transaction.instruction_slot = Some(instruction_slot);
+    proposal_transaction
+}
+
+fn mock_instruction(data_len: usize) -> ProposalInstruction {
+    ProposalInstruction::new(
+        &Pubkey::new_unique(),
+        vec![
+            ProposalAccountMeta {
+                pubkey: Pubkey::new_unique(),
+                is_signer: false,
+                is_writable: true,
+            },
+            ProposalAccountMeta {
+                pubkey: Pubkey::new_unique(),
+                is_signer: false,
+                is_writable: false,
+            },
+        ],
+        vec![7; data_len],
+    )
+}
+
+#[tokio::test]
+async fn fail_stake_authority_not_signer() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    let mut instruction = finalize_instruction_slot(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+    );
+    instruction.accounts[0].is_signer = false; // Stake authority not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Missing stake authority.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_stake_authority_not_author() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &Pubkey::new_unique(), // Stake authority not author.
+        0,
+        Config::default(),
+        ProposalStatus::Draft,
+    )
+    .await;
+
+    let instruction = finalize_instruction_slot(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_not_in_draft_stage() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let data = borsh::to_vec(&mock_instruction(8)).unwrap();
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &stake_authority.pubkey(),
+        0,
+        Config::default(),
+        ProposalStatus::Voting, // Not in draft stage.
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        proposal_transaction_with_slot(&data, data.len() as u32),
+    )
+    .await;
+
+    let instruction = finalize_instruction_slot(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::ProposalIsImmutable as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_instruction_slot_not_created() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &stake_authority.pubkey(),
+        0,
+        Config::default(),
+        ProposalStatus::Draft,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        ProposalTransaction::default(), // No instruction slot.
+    )
+    .await;
+
+    let instruction = finalize_instruction_slot(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::InstructionSlotNotCreated as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_instruction_slot_incomplete() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let data = borsh::to_vec(&mock_instruction(8)).unwrap();
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &stake_authority.pubkey(),
+        0,
+        Config::default(),
+        ProposalStatus::Draft,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        proposal_transaction_with_slot(
+            &data[..data.len() - 1], // Last byte not written.
+            data.len() as u32,
+        ),
+    )
+    .await;
+
+    let instruction = finalize_instruction_slot(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::InstructionSlotIncomplete as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_instruction_slot_invalid_instruction() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &stake_authority.pubkey(),
+        0,
+        Config::default(),
+        ProposalStatus::Draft,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        proposal_transaction_with_slot(&[1; 16], 16), // Not a serialized instruction.
+    )
+    .await;
+
+    let instruction = finalize_instruction_slot(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountData)
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let existing_instruction = mock_instruction(3);
+    let new_instruction = mock_instruction(64);
+    let data = borsh::to_vec(&new_instruction).unwrap();
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &stake_authority.pubkey(),
+        0,
+        Config::default(),
+        ProposalStatus::Draft,
+    )
+    .await;
+
+    let mut proposal_transaction = proposal_transaction_with_slot(&data, data.len() as u32);
+    proposal_transaction
+        .instructions
+        .push(existing_instruction.clone());
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        proposal_transaction,
+    )
+    .await;
+
+    // Fund the stake authority, which is refunded the rent freed by
+    // finalizing the slot.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    context.set_account(
+        &stake_authority.pubkey(),
+        &AccountSharedData::new(rent.minimum_balance(0), 0, &system_program::id()),
+    );
+
+    let proposal_transaction_lamports = context
+        .banks_client
+        .get_balance(proposal_transaction_address)
+        .await
+        .unwrap();
+    let stake_authority_lamports = context
+        .banks_client
+        .get_balance(stake_authority.pubkey())
+        .await
+        .unwrap();
+
+    let instruction = finalize_instruction_slot(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the slot's instruction was appended.
+    let proposal_transaction_account = context
+        .banks_client
+        .get_account(proposal_transaction_address)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_transaction_state =
+        ProposalTransaction::try_from_slice(&proposal_transaction_account.data).unwrap();
+    assert_eq!(
+        proposal_transaction_state,
+        ProposalTransaction::new(vec![existing_instruction, new_instruction])
+    );
+
+    // Assert the excess rent was refunded to the stake authority.
+    assert_eq!(
+        proposal_transaction_account.lamports,
+        rent.minimum_balance(proposal_transaction_account.data.len())
+    );
+    let refund = proposal_transaction_lamports - proposal_transaction_account.lamports;
+    assert!(refund > 0);
+    assert_eq!(
+        context
+            .banks_client
+            .get_balance(stake_authority.pubkey())
+            .await
+            .unwrap(),
+        stake_authority_lamports + refund
+    );
+}
+
+#[tokio::test]
+async fn success_instruction_larger_than_realloc_limit() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    // The instruction is too large to push, or even to allocate, in a single
+    // transaction.
+    let new_instruction = mock_instruction(MAX_PERMITTED_DATA_INCREASE + 1_000);
+    let data = borsh::to_vec(&new_instruction).unwrap();
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &stake_authority.pubkey(),
+        0,
+        Config::default(),
+        ProposalStatus::Draft,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        ProposalTransaction::default(),
+    )
+    .await;
+
+    // Fund the stake authority, which is refunded the rent freed by
+    // finalizing the slot.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    context.set_account(
+        &stake_authority.pubkey(),
+        &AccountSharedData::new(rent.minimum_balance(0), 0, &system_program::id()),
+    );
+
+    let mut instructions = vec![create_instruction_slot(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+        &context.payer.pubkey(),
+        data.len() as u32,
+    )];
+    for (index, chunk) in data.chunks(800).enumerate() {
+        instructions.push(write_instruction_slot(
+            &stake_authority.pubkey(),
+            &proposal_address,
+            &proposal_transaction_address,
+            &context.payer.pubkey(),
+            (index * 800) as u32,
+            chunk.to_vec(),
+        ));
+    }
+    instructions.push(finalize_instruction_slot(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+    ));
+
+    // Send each instruction in its own transaction.
+    for instruction in instructions {
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &stake_authority],
+            context.last_blockhash,
+        );
+
+        context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+            .unwrap();
+    }
+
+    // Assert the instruction was appended in full.
+    let proposal_transaction_account = context
+        .banks_client
+        .get_account(proposal_transaction_address)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_transaction_state =
+        ProposalTransaction::try_from_slice(&proposal_transaction_account.data).unwrap();
+    assert_eq!(
+        proposal_transaction_state,
+        ProposalTransaction::new(vec![new_instruction])
+    );
+    assert_eq!(
+        proposal_transaction_account.lamports,
+        rent.minimum_balance(proposal_transaction_account.data.len())
+    );
+}
@@ -0,0 +1,951 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::finalize_proposal,
+        state::{
+            get_proposal_transaction_address, Config, Proposal, ProposalInstruction,
+            ProposalStatus, ProposalTransaction,
+        },
+    },
+    setup::{
+        setup, setup_proposal, setup_proposal_transaction, setup_proposal_with_stake_and_cooldown,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        account::AccountSharedData,
+        clock::Clock,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    std::num::NonZeroU64,
+    test_case::test_case,
+};
+
+async fn setup_proposal_transaction_with_instruction(
+    context: &mut ProgramTestContext,
+    proposal_transaction_address: &Pubkey,
+) {
+    setup_proposal_transaction(
+        context,
+        proposal_transaction_address,
+        ProposalTransaction::new(vec![ProposalInstruction::new(
+            &Pubkey::new_unique(),
+            vec![],
+            vec![],
+        )]),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn fail_proposal_incorrect_owner() {
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    // Set up the proposal account with the incorrect owner.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let space = std::mem::size_of::<Proposal>();
+        let lamports = rent.minimum_balance(space);
+        context.set_account(
+            &proposal,
+            &AccountSharedData::new(lamports, space, &Pubkey::new_unique()), // Incorrect owner.
+        );
+    }
+
+    let instruction = finalize_proposal(&proposal, &proposal_transaction);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_not_initialized() {
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    // Set up the proposal account uninitialized.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let space = std::mem::size_of::<Proposal>();
+        let lamports = rent.minimum_balance(space);
+        context.set_account(
+            &proposal,
+            &AccountSharedData::new(lamports, space, &paladin_governance_program::id()),
+        );
+    }
+
+    let instruction = finalize_proposal(&proposal, &proposal_transaction);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::UninitializedAccount)
+    );
+}
+
+#[test_case(ProposalStatus::Draft)]
+#[test_case(ProposalStatus::Cancelled)]
+#[test_case(ProposalStatus::Rejected)]
+#[test_case(ProposalStatus::Processed)]
+#[test_case(ProposalStatus::QuorumNotMet)]
+#[test_case(ProposalStatus::Expired)]
+#[test_case(ProposalStatus::Vetoed)]
+#[tokio::test]
+async fn fail_proposal_not_voting(status: ProposalStatus) {
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        0,
+        Config::default(),
+        status,
+    )
+    .await;
+
+    let instruction = finalize_proposal(&proposal, &proposal_transaction);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::ProposalNotInVotingStage as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_voting_period_active() {
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 10,
+        /* execution_window_seconds */ 1_000,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        /* stake_config_address */ &Pubkey::new_unique(),
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 1_000,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    // Set up a proposal whose voting period began just now, with no cooldown.
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        /* stake_for */ 0,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ 0,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+        /* cooldown_timestamp */ None,
+    )
+    .await;
+
+    let instruction = finalize_proposal(&proposal, &proposal_transaction);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::ProposalVotingNotEnded as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_voting_period_ended_but_cooldown_active() {
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 1_000,
+        /* execution_window_seconds */ 1_000,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        /* stake_config_address */ &Pubkey::new_unique(),
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 10,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    // Set up a proposal whose voting period has expired, but whose cooldown
+    // period is still active.
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        /* stake_for */ 0,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ 0,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(1), // Wayyy earlier.
+        /* cooldown_timestamp */
+        NonZeroU64::new(clock.unix_timestamp.saturating_sub(10) as u64), // Still active.
+    )
+    .await;
+
+    let instruction = finalize_proposal(&proposal, &proposal_transaction);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::ProposalVotingNotEnded as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_execution_window_active() {
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 10,
+        /* execution_window_seconds */ 1_000,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        /* stake_config_address */ &Pubkey::new_unique(),
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 1_000,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    // Set up an accepted proposal whose execution window is still active.
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        /* stake_for */ 0,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ 0,
+        ProposalStatus::Accepted,
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+        /* cooldown_timestamp */
+        NonZeroU64::new(clock.unix_timestamp.saturating_sub(10) as u64), // Now - 10 seconds.
+    )
+    .await;
+    setup_proposal_transaction_with_instruction(&mut context, &proposal_transaction).await;
+
+    let instruction = finalize_proposal(&proposal, &proposal_transaction);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                PaladinGovernanceError::ProposalExecutionWindowNotEnded as u32
+            )
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_accepted() {
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 10,
+        /* execution_window_seconds */ 1_000,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        /* stake_config_address */ &Pubkey::new_unique(),
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 1_000,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    // Set up a proposal whose cooldown period has ended.
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        /* stake_for */ 0,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ 0,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+        /* cooldown_timestamp */
+        NonZeroU64::new(clock.unix_timestamp.saturating_sub(10) as u64), // Now - 10 seconds.
+    )
+    .await;
+    setup_proposal_transaction_with_instruction(&mut context, &proposal_transaction).await;
+
+    let instruction = finalize_proposal(&proposal, &proposal_transaction);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal was accepted.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::Accepted);
+}
+
+#[test_case(ProposalStatus::Voting; "voting")]
+#[test_case(ProposalStatus::Accepted; "accepted")]
+#[tokio::test]
+async fn success_expired(status: ProposalStatus) {
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 10,
+        /* execution_window_seconds */ 1_000,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        /* stake_config_address */ &Pubkey::new_unique(),
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 1_000,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    // Set up a proposal whose cooldown period and execution window have both
+    // ended.
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        /* stake_for */ 0,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ 0,
+        status,
+        /* voting_start_timestamp */ NonZeroU64::new(1), // Wayyy earlier.
+        /* cooldown_timestamp */
+        NonZeroU64::new(clock.unix_timestamp.saturating_sub(1_010) as u64), // Now - 1,010 seconds.
+    )
+    .await;
+    setup_proposal_transaction_with_instruction(&mut context, &proposal_transaction).await;
+
+    let instruction = finalize_proposal(&proposal, &proposal_transaction);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal expired.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::Expired);
+}
+
+#[tokio::test]
+async fn success_rejected() {
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 10,
+        /* execution_window_seconds */ 1_000,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        /* stake_config_address */ &Pubkey::new_unique(),
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 10,
+    );
+
+    let mut context = setup().start_with_context().await;
+
+    // Set up a proposal whose voting period has expired, with no cooldown.
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        /* stake_for */ 0,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ 0,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(1), // Wayyy earlier.
+        /* cooldown_timestamp */ None,
+    )
+    .await;
+
+    let instruction = finalize_proposal(&proposal, &proposal_transaction);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal was rejected.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::Rejected);
+}
+
+#[test_case(true; "cooldown_ended")]
+#[test_case(false; "voting_ended")]
+#[tokio::test]
+async fn success_quorum_not_met(cooldown_ended: bool) {
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 10,
+        /* execution_window_seconds */ 1_000,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 500_000_000, // 50%
+        /* signer_bump_seed */ 0,
+        /* stake_config_address */ &Pubkey::new_unique(),
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 10,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    let cooldown_timestamp =
+        if cooldown_ended {
+            NonZeroU64::new(clock.unix_timestamp.saturating_sub(10) as u64) // Now - 10 seconds.
+        } else {
+            None
+        };
+
+    // Set up a concluded proposal with only 40% participation.
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        /* stake_for */ 200,
+        /* stake_against */ 100,
+        /* stake_abstained */ 100,
+        /* total_stake */ 1_000,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(1), // Wayyy earlier.
+        cooldown_timestamp,
+    )
+    .await;
+
+    let instruction = finalize_proposal(&proposal, &proposal_transaction);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal failed to reach quorum.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::QuorumNotMet);
+}
+
+#[test_case(true, ProposalStatus::Accepted; "cooldown_ended")]
+#[test_case(false, ProposalStatus::Rejected; "voting_ended")]
+#[tokio::test]
+async fn success_quorum_met(cooldown_ended: bool, expected_status: ProposalStatus) {
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 10,
+        /* execution_window_seconds */ 1_000,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 500_000_000, // 50%
+        /* signer_bump_seed */ 0,
+        /* stake_config_address */ &Pubkey::new_unique(),
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 10,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    let cooldown_timestamp =
+        if cooldown_ended {
+            NonZeroU64::new(clock.unix_timestamp.saturating_sub(10) as u64) // Now - 10 seconds.
+        } else {
+            None
+        };
+
+    // Set up a concluded proposal with exactly 50% participation.
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        /* stake_for */ 300,
+        /* stake_against */ 100,
+        /* stake_abstained */ 100,
+        /* total_stake */ 1_000,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(1), // Wayyy earlier.
+        cooldown_timestamp,
+    )
+    .await;
+    setup_proposal_transaction_with_instruction(&mut context, &proposal_transaction).await;
+
+    let instruction = finalize_proposal(&proposal, &proposal_transaction);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal concluded normally.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, expected_status);
+}
+
+#[test_case(true; "cooldown_ended")]
+#[test_case(false; "voting_ended")]
+#[tokio::test]
+async fn fail_proposal_transaction_incorrect_address(cooldown_ended: bool) {
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction = Pubkey::new_unique(); // Incorrect proposal transaction address.
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 10,
+        /* execution_window_seconds */ 1_000,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        /* stake_config_address */ &Pubkey::new_unique(),
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 1_000,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    // Set up a proposal whose cooldown period has ended, which is accepted, or
+    // whose voting period has ended without a cooldown, which is rejected.
+    let (voting_start_timestamp, cooldown_timestamp) = if cooldown_ended {
+        (
+            NonZeroU64::new(clock.unix_timestamp as u64),
+            NonZeroU64::new(clock.unix_timestamp.saturating_sub(10) as u64), // Now - 10 seconds.
+        )
+    } else {
+        (NonZeroU64::new(1), None) // Wayyy earlier.
+    };
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        /* stake_for */ 0,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ 0,
+        ProposalStatus::Voting,
+        voting_start_timestamp,
+        cooldown_timestamp,
+    )
+    .await;
+    setup_proposal_transaction_with_instruction(&mut context, &proposal_transaction).await;
+
+    let instruction = finalize_proposal(&proposal, &proposal_transaction);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                PaladinGovernanceError::IncorrectProposalTransactionAddress as u32
+            )
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_transaction_incorrect_owner() {
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 10,
+        /* execution_window_seconds */ 1_000,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        /* stake_config_address */ &Pubkey::new_unique(),
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 1_000,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    // Set up a proposal whose cooldown period has ended.
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        /* stake_for */ 0,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ 0,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+        /* cooldown_timestamp */
+        NonZeroU64::new(clock.unix_timestamp.saturating_sub(10) as u64), // Now - 10 seconds.
+    )
+    .await;
+
+    // Set up the proposal transaction account with the incorrect owner.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let space = borsh::to_vec(&ProposalTransaction::default())
+            .unwrap()
+            .len();
+        let lamports = rent.minimum_balance(space);
+        context.set_account(
+            &proposal_transaction,
+            &AccountSharedData::new(lamports, space, &Pubkey::new_unique()), // Incorrect owner.
+        );
+    }
+
+    let instruction = finalize_proposal(&proposal, &proposal_transaction);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+    );
+}
+
+#[test_case(ProposalStatus::Voting; "voting")]
+#[test_case(ProposalStatus::Accepted; "accepted")]
+#[tokio::test]
+async fn success_processed_without_instructions(status: ProposalStatus) {
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 10,
+        /* execution_window_seconds */ 1_000,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        /* stake_config_address */ &Pubkey::new_unique(),
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 1_000,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    // Set up a proposal whose cooldown period has ended, within its execution
+    // window, without any instructions.
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        /* stake_for */ 0,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ 0,
+        status,
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+        /* cooldown_timestamp */
+        NonZeroU64::new(clock.unix_timestamp.saturating_sub(10) as u64), // Now - 10 seconds.
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction,
+        ProposalTransaction::default(),
+    )
+    .await;
+
+    let instruction = finalize_proposal(&proposal, &proposal_transaction);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal was processed.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::Processed);
+    assert!(proposal_state.execution_timestamp.is_some());
+}
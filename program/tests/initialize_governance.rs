@@ -6,24 +6,32 @@ use {
     paladin_governance_program::{
         error::PaladinGovernanceError,
         instruction::initialize_governance,
-        state::{get_governance_address, Config},
+        state::{
+            get_governance_address, Config, PeriodBounds, DEFAULT_MAX_COOLDOWN_PERIOD_SECONDS,
+            DEFAULT_MAX_EXECUTION_WINDOW_SECONDS, DEFAULT_MAX_VOTING_PERIOD_SECONDS,
+            DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS, DEFAULT_MIN_EXECUTION_WINDOW_SECONDS,
+            DEFAULT_MIN_VOTING_PERIOD_SECONDS,
+        },
     },
     paladin_stake_program::state::Config as StakeConfig,
-    setup::{setup, setup_governance, setup_stake_config},
+    setup::{setup, setup_governance, setup_program_data, setup_stake_config_with_authority},
     solana_program_test::*,
     solana_sdk::{
         account::AccountSharedData,
         instruction::InstructionError,
         pubkey::Pubkey,
+        signature::Keypair,
         signer::Signer,
         system_program,
         transaction::{Transaction, TransactionError},
     },
+    test_case::test_case,
 };
 
 #[tokio::test]
 async fn fail_stake_config_incorrect_owner() {
     let stake_config = Pubkey::new_unique();
+    let authority = Keypair::new();
     let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
 
     let mut context = setup().start_with_context().await;
@@ -42,16 +50,22 @@ async fn fail_stake_config_incorrect_owner() {
     let instruction = initialize_governance(
         &governance,
         &stake_config,
+        &authority.pubkey(),
         /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
         /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
         /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 0,
+        /* period_bounds */ PeriodBounds::default(),
     );
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
         Some(&context.payer.pubkey()),
-        &[&context.payer],
+        &[&context.payer, &authority],
         context.last_blockhash,
     );
 
@@ -71,6 +85,7 @@ async fn fail_stake_config_incorrect_owner() {
 #[tokio::test]
 async fn fail_stake_config_not_initialized() {
     let stake_config = Pubkey::new_unique();
+    let authority = Keypair::new();
     let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
 
     let mut context = setup().start_with_context().await;
@@ -89,16 +104,22 @@ async fn fail_stake_config_not_initialized() {
     let instruction = initialize_governance(
         &governance,
         &stake_config,
+        &authority.pubkey(),
         /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
         /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
         /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 0,
+        /* period_bounds */ PeriodBounds::default(),
     );
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
         Some(&context.payer.pubkey()),
-        &[&context.payer],
+        &[&context.payer, &authority],
         context.last_blockhash,
     );
 
@@ -119,23 +140,36 @@ async fn fail_stake_config_not_initialized() {
 async fn fail_governance_incorrect_address() {
     let governance = Pubkey::new_unique(); // Incorrect governance address.
     let stake_config = Pubkey::new_unique();
+    let authority = Keypair::new();
 
     let mut context = setup().start_with_context().await;
-    setup_stake_config(&mut context, &stake_config, /* total_stake */ 100).await;
+    setup_stake_config_with_authority(
+        &mut context,
+        &stake_config,
+        &authority.pubkey(),
+        /* total_stake */ 100,
+    )
+    .await;
 
     let instruction = initialize_governance(
         &governance,
         /* stake_config_address */ &stake_config,
+        &authority.pubkey(),
         /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
         /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
         /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 0,
+        /* period_bounds */ PeriodBounds::default(),
     );
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
         Some(&context.payer.pubkey()),
-        &[&context.payer],
+        &[&context.payer, &authority],
         context.last_blockhash,
     );
 
@@ -160,27 +194,53 @@ async fn fail_governance_incorrect_address() {
 #[tokio::test]
 async fn fail_governance_already_initialized() {
     let stake_config = Pubkey::new_unique();
+    let authority = Keypair::new();
     let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
 
     let mut context = setup().start_with_context().await;
-    setup_stake_config(&mut context, &stake_config, /* total_stake */ 100).await;
+    setup_stake_config_with_authority(
+        &mut context,
+        &stake_config,
+        &authority.pubkey(),
+        /* total_stake */ 100,
+    )
+    .await;
 
     // Set up an already initialized governance account.
-    setup_governance(&mut context, &governance, 0, 0, 0, &stake_config, 0).await;
+    setup_governance(
+        &mut context,
+        &governance,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        &stake_config,
+        None,
+        0,
+    )
+    .await;
 
     let instruction = initialize_governance(
         &governance,
         &stake_config,
+        &authority.pubkey(),
         /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
         /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
         /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 0,
+        /* period_bounds */ PeriodBounds::default(),
     );
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
         Some(&context.payer.pubkey()),
-        &[&context.payer],
+        &[&context.payer, &authority],
         context.last_blockhash,
     );
 
@@ -197,13 +257,524 @@ async fn fail_governance_already_initialized() {
     );
 }
 
+#[tokio::test]
+async fn fail_authority_not_signer() {
+    let stake_config = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake_config_with_authority(
+        &mut context,
+        &stake_config,
+        &authority.pubkey(),
+        /* total_stake */ 100,
+    )
+    .await;
+
+    // Fund the governance account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(std::mem::size_of::<Config>());
+        context.set_account(
+            &governance,
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
+        );
+    }
+
+    let mut instruction = initialize_governance(
+        &governance,
+        &stake_config,
+        &authority.pubkey(),
+        /* cooldown_period_seconds */ 86_400, // 1 day
+        /* execution_window_seconds */ 604_800, // 7 days
+        /* proposal_acceptance_threshold */ 500_000_000, // 50%
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 400_000_000, // 40%
+        /* quorum_threshold */ 0,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 172_800, // 2 days
+        /* period_bounds */ PeriodBounds::default(),
+    );
+    instruction.accounts[3].is_signer = false; // Authority not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Authority not signer.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_incorrect_authority() {
+    let stake_config = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake_config_with_authority(
+        &mut context,
+        &stake_config,
+        &Pubkey::new_unique(), // Incorrect stake config authority.
+        /* total_stake */ 100,
+    )
+    .await;
+
+    // Fund the governance account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(std::mem::size_of::<Config>());
+        context.set_account(
+            &governance,
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
+        );
+    }
+
+    let instruction = initialize_governance(
+        &governance,
+        &stake_config,
+        &authority.pubkey(),
+        /* cooldown_period_seconds */ 86_400, // 1 day
+        /* execution_window_seconds */ 604_800, // 7 days
+        /* proposal_acceptance_threshold */ 500_000_000, // 50%
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 400_000_000, // 40%
+        /* quorum_threshold */ 0,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 172_800, // 2 days
+        /* period_bounds */ PeriodBounds::default(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+}
+
+#[tokio::test]
+async fn fail_incorrect_upgrade_authority() {
+    let stake_config = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake_config_with_authority(
+        &mut context,
+        &stake_config,
+        &Pubkey::new_unique(), // Incorrect stake config authority.
+        /* total_stake */ 100,
+    )
+    .await;
+    setup_program_data(
+        &mut context,
+        /* upgrade_authority_address */
+        Some(&Pubkey::new_unique()), // Incorrect upgrade authority.
+    )
+    .await;
+
+    // Fund the governance account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(std::mem::size_of::<Config>());
+        context.set_account(
+            &governance,
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
+        );
+    }
+
+    let instruction = initialize_governance(
+        &governance,
+        &stake_config,
+        &authority.pubkey(),
+        /* cooldown_period_seconds */ 86_400, // 1 day
+        /* execution_window_seconds */ 604_800, // 7 days
+        /* proposal_acceptance_threshold */ 500_000_000, // 50%
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 400_000_000, // 40%
+        /* quorum_threshold */ 0,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 172_800, // 2 days
+        /* period_bounds */ PeriodBounds::default(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+}
+
+#[tokio::test]
+async fn fail_program_data_incorrect_address() {
+    let stake_config = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake_config_with_authority(
+        &mut context,
+        &stake_config,
+        &Pubkey::new_unique(), // Incorrect stake config authority.
+        /* total_stake */ 100,
+    )
+    .await;
+    setup_program_data(
+        &mut context,
+        /* upgrade_authority_address */ Some(&authority.pubkey()),
+    )
+    .await;
+
+    // Fund the governance account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(std::mem::size_of::<Config>());
+        context.set_account(
+            &governance,
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
+        );
+    }
+
+    let mut instruction = initialize_governance(
+        &governance,
+        &stake_config,
+        &authority.pubkey(),
+        /* cooldown_period_seconds */ 86_400, // 1 day
+        /* execution_window_seconds */ 604_800, // 7 days
+        /* proposal_acceptance_threshold */ 500_000_000, // 50%
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 400_000_000, // 40%
+        /* quorum_threshold */ 0,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 172_800, // 2 days
+        /* period_bounds */ PeriodBounds::default(),
+    );
+    instruction.accounts[4].pubkey = Pubkey::new_unique(); // Incorrect program data address.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::IncorrectProgramDataAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_upgrade_authority() {
+    let stake_config = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake_config_with_authority(
+        &mut context,
+        &stake_config,
+        &Pubkey::new_unique(), // Incorrect stake config authority.
+        /* total_stake */ 100,
+    )
+    .await;
+    setup_program_data(
+        &mut context,
+        /* upgrade_authority_address */ Some(&authority.pubkey()),
+    )
+    .await;
+
+    // Fund the governance account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(std::mem::size_of::<Config>());
+        context.set_account(
+            &governance,
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
+        );
+    }
+
+    let instruction = initialize_governance(
+        &governance,
+        &stake_config,
+        &authority.pubkey(),
+        /* cooldown_period_seconds */ 86_400, // 1 day
+        /* execution_window_seconds */ 604_800, // 7 days
+        /* proposal_acceptance_threshold */ 500_000_000, // 50%
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 400_000_000, // 40%
+        /* quorum_threshold */ 0,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 172_800, // 2 days
+        /* period_bounds */ PeriodBounds::default(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the governance account was created.
+    let governance_account = context
+        .banks_client
+        .get_account(governance)
+        .await
+        .unwrap()
+        .unwrap();
+    let governance_state = bytemuck::from_bytes::<Config>(&governance_account.data);
+    assert_eq!(governance_state.stake_config_address, stake_config);
+}
+
+#[test_case(
+    DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS,
+    DEFAULT_MIN_EXECUTION_WINDOW_SECONDS,
+    0,
+    500_000_000,
+    0,
+    DEFAULT_MIN_VOTING_PERIOD_SECONDS,
+    PaladinGovernanceError::InvalidProposalAcceptanceThreshold;
+    "acceptance_threshold_zero"
+)]
+#[test_case(
+    DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS,
+    DEFAULT_MIN_EXECUTION_WINDOW_SECONDS,
+    1_000_000_001,
+    500_000_000,
+    0,
+    DEFAULT_MIN_VOTING_PERIOD_SECONDS,
+    PaladinGovernanceError::InvalidProposalAcceptanceThreshold;
+    "acceptance_threshold_above_scale"
+)]
+#[test_case(
+    DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS,
+    DEFAULT_MIN_EXECUTION_WINDOW_SECONDS,
+    500_000_000,
+    0,
+    0,
+    DEFAULT_MIN_VOTING_PERIOD_SECONDS,
+    PaladinGovernanceError::InvalidProposalRejectionThreshold;
+    "rejection_threshold_zero"
+)]
+#[test_case(
+    DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS,
+    DEFAULT_MIN_EXECUTION_WINDOW_SECONDS,
+    500_000_000,
+    1_000_000_001,
+    0,
+    DEFAULT_MIN_VOTING_PERIOD_SECONDS,
+    PaladinGovernanceError::InvalidProposalRejectionThreshold;
+    "rejection_threshold_above_scale"
+)]
+#[test_case(
+    DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS,
+    DEFAULT_MIN_EXECUTION_WINDOW_SECONDS,
+    500_000_000,
+    500_000_000,
+    1_000_000_001,
+    DEFAULT_MIN_VOTING_PERIOD_SECONDS,
+    PaladinGovernanceError::InvalidQuorumThreshold;
+    "quorum_threshold_above_scale"
+)]
+#[test_case(
+    DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS - 1,
+    DEFAULT_MIN_EXECUTION_WINDOW_SECONDS,
+    500_000_000,
+    500_000_000,
+    0,
+    DEFAULT_MIN_VOTING_PERIOD_SECONDS,
+    PaladinGovernanceError::InvalidCooldownPeriod;
+    "cooldown_period_too_short"
+)]
+#[test_case(
+    DEFAULT_MAX_COOLDOWN_PERIOD_SECONDS + 1,
+    DEFAULT_MIN_EXECUTION_WINDOW_SECONDS,
+    500_000_000,
+    500_000_000,
+    0,
+    DEFAULT_MIN_VOTING_PERIOD_SECONDS,
+    PaladinGovernanceError::InvalidCooldownPeriod;
+    "cooldown_period_too_long"
+)]
+#[test_case(
+    DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS,
+    DEFAULT_MIN_EXECUTION_WINDOW_SECONDS - 1,
+    500_000_000,
+    500_000_000,
+    0,
+    DEFAULT_MIN_VOTING_PERIOD_SECONDS,
+    PaladinGovernanceError::InvalidExecutionWindow;
+    "execution_window_too_short"
+)]
+#[test_case(
+    DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS,
+    DEFAULT_MAX_EXECUTION_WINDOW_SECONDS + 1,
+    500_000_000,
+    500_000_000,
+    0,
+    DEFAULT_MIN_VOTING_PERIOD_SECONDS,
+    PaladinGovernanceError::InvalidExecutionWindow;
+    "execution_window_too_long"
+)]
+#[test_case(
+    DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS,
+    DEFAULT_MIN_EXECUTION_WINDOW_SECONDS,
+    500_000_000,
+    500_000_000,
+    0,
+    DEFAULT_MIN_VOTING_PERIOD_SECONDS - 1,
+    PaladinGovernanceError::InvalidVotingPeriod;
+    "voting_period_too_short"
+)]
+#[test_case(
+    DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS,
+    DEFAULT_MIN_EXECUTION_WINDOW_SECONDS,
+    500_000_000,
+    500_000_000,
+    0,
+    DEFAULT_MAX_VOTING_PERIOD_SECONDS + 1,
+    PaladinGovernanceError::InvalidVotingPeriod;
+    "voting_period_too_long"
+)]
+#[tokio::test]
+async fn fail_invalid_params(
+    cooldown_period_seconds: u64,
+    execution_window_seconds: u64,
+    proposal_acceptance_threshold: u32,
+    proposal_rejection_threshold: u32,
+    quorum_threshold: u32,
+    voting_period_seconds: u64,
+    error: PaladinGovernanceError,
+) {
+    let stake_config = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake_config_with_authority(
+        &mut context,
+        &stake_config,
+        &authority.pubkey(),
+        /* total_stake */ 100,
+    )
+    .await;
+
+    // Fund the governance account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(std::mem::size_of::<Config>());
+        context.set_account(
+            &governance,
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
+        );
+    }
+
+    let instruction = initialize_governance(
+        &governance,
+        &stake_config,
+        &authority.pubkey(),
+        cooldown_period_seconds,
+        execution_window_seconds,
+        proposal_acceptance_threshold,
+        /* proposal_minimum_stake */ 0,
+        proposal_rejection_threshold,
+        quorum_threshold,
+        /* veto_authority */ None,
+        voting_period_seconds,
+        /* period_bounds */ PeriodBounds::default(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(error as u32))
+    );
+}
+
 #[tokio::test]
 async fn success() {
     let stake_config = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let veto_authority = Pubkey::new_unique();
     let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
 
     let mut context = setup().start_with_context().await;
-    setup_stake_config(&mut context, &stake_config, /* total_stake */ 100).await;
+    setup_stake_config_with_authority(
+        &mut context,
+        &stake_config,
+        &authority.pubkey(),
+        /* total_stake */ 100,
+    )
+    .await;
 
     // Fund the governance account.
     {
@@ -218,16 +789,22 @@ async fn success() {
     let instruction = initialize_governance(
         &governance,
         &stake_config,
-        /* cooldown_period_seconds */ 0,
-        /* proposal_acceptance_threshold */ 0,
-        /* proposal_rejection_threshold */ 0,
-        /* voting_period_seconds */ 0,
+        &authority.pubkey(),
+        /* cooldown_period_seconds */ 86_400, // 1 day
+        /* execution_window_seconds */ 604_800, // 7 days
+        /* proposal_acceptance_threshold */ 500_000_000, // 50%
+        /* proposal_minimum_stake */ 1_000_000,
+        /* proposal_rejection_threshold */ 400_000_000, // 40%
+        /* quorum_threshold */ 300_000_000, // 30%
+        /* veto_authority */ Some(&veto_authority),
+        /* voting_period_seconds */ 172_800, // 2 days
+        /* period_bounds */ PeriodBounds::default(),
     );
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
         Some(&context.payer.pubkey()),
-        &[&context.payer],
+        &[&context.payer, &authority],
         context.last_blockhash,
     );
 
@@ -245,8 +822,168 @@ async fn success() {
         .unwrap()
         .unwrap();
     let governance_state = bytemuck::from_bytes::<Config>(&governance_account.data);
-    assert_eq!(governance_state.cooldown_period_seconds, 0);
-    assert_eq!(governance_state.proposal_acceptance_threshold, 0);
-    assert_eq!(governance_state.proposal_rejection_threshold, 0);
+    assert_eq!(governance_state.cooldown_period_seconds, 86_400);
+    assert_eq!(governance_state.execution_window_seconds, 604_800);
+    assert_eq!(governance_state.proposal_acceptance_threshold, 500_000_000);
+    assert_eq!(governance_state.proposal_minimum_stake, 1_000_000);
+    assert_eq!(governance_state.proposal_rejection_threshold, 400_000_000);
+    assert_eq!(governance_state.quorum_threshold, 300_000_000);
     assert_eq!(governance_state.stake_config_address, stake_config);
+    assert_eq!(
+        Option::<Pubkey>::from(governance_state.veto_authority),
+        Some(veto_authority)
+    );
+    assert_eq!(governance_state.voting_period_seconds, 172_800);
+    assert_eq!(governance_state.period_bounds, PeriodBounds::default());
+}
+
+fn period_bounds(min_seconds: [u64; 3], max_seconds: [u64; 3]) -> PeriodBounds {
+    PeriodBounds {
+        min_cooldown_period_seconds: min_seconds[0],
+        max_cooldown_period_seconds: max_seconds[0],
+        min_execution_window_seconds: min_seconds[1],
+        max_execution_window_seconds: max_seconds[1],
+        min_voting_period_seconds: min_seconds[2],
+        max_voting_period_seconds: max_seconds[2],
+    }
+}
+
+#[test_case(period_bounds([0, 60, 60], [120, 120, 120]); "cooldown_min_zero")]
+#[test_case(period_bounds([121, 60, 60], [120, 120, 120]); "cooldown_min_above_max")]
+#[test_case(period_bounds([60, 0, 60], [120, 120, 120]); "execution_window_min_zero")]
+#[test_case(period_bounds([60, 121, 60], [120, 120, 120]); "execution_window_min_above_max")]
+#[test_case(period_bounds([60, 60, 0], [120, 120, 120]); "voting_min_zero")]
+#[test_case(period_bounds([60, 60, 121], [120, 120, 120]); "voting_min_above_max")]
+#[tokio::test]
+async fn fail_invalid_period_bounds(period_bounds: PeriodBounds) {
+    let stake_config = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake_config_with_authority(
+        &mut context,
+        &stake_config,
+        &authority.pubkey(),
+        /* total_stake */ 100,
+    )
+    .await;
+
+    // Fund the governance account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(std::mem::size_of::<Config>());
+        context.set_account(
+            &governance,
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
+        );
+    }
+
+    let instruction = initialize_governance(
+        &governance,
+        &stake_config,
+        &authority.pubkey(),
+        /* cooldown_period_seconds */ 120,
+        /* execution_window_seconds */ 120,
+        /* proposal_acceptance_threshold */ 500_000_000,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 500_000_000,
+        /* quorum_threshold */ 0,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 120,
+        period_bounds,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::InvalidPeriodBounds as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_custom_period_bounds() {
+    let stake_config = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake_config_with_authority(
+        &mut context,
+        &stake_config,
+        &authority.pubkey(),
+        /* total_stake */ 100,
+    )
+    .await;
+
+    // Fund the governance account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(std::mem::size_of::<Config>());
+        context.set_account(
+            &governance,
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
+        );
+    }
+
+    // Periods shorter than the default minimums are accepted within the
+    // provided bounds.
+    let period_bounds = period_bounds([60, 60, 60], [600, 600, 600]);
+
+    let instruction = initialize_governance(
+        &governance,
+        &stake_config,
+        &authority.pubkey(),
+        /* cooldown_period_seconds */ 60,
+        /* execution_window_seconds */ 300,
+        /* proposal_acceptance_threshold */ 500_000_000,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 500_000_000,
+        /* quorum_threshold */ 0,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 600,
+        period_bounds,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the governance account was created with the provided bounds.
+    let governance_account = context
+        .banks_client
+        .get_account(governance)
+        .await
+        .unwrap()
+        .unwrap();
+    let governance_state = bytemuck::from_bytes::<Config>(&governance_account.data);
+    assert_eq!(governance_state.cooldown_period_seconds, 60);
+    assert_eq!(governance_state.execution_window_seconds, 300);
+    assert_eq!(governance_state.voting_period_seconds, 600);
+    assert_eq!(governance_state.period_bounds, period_bounds);
 }
@@ -0,0 +1,305 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::{initialize_treasury, initialize_treasury_token_account},
+        state::{get_governance_address, get_treasury_address},
+    },
+    setup::{setup, setup_governance, setup_mint},
+    solana_program_test::*,
+    solana_sdk::{
+        account::AccountSharedData,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signer::Signer,
+        system_program,
+        transaction::{Transaction, TransactionError},
+    },
+    spl_associated_token_account::get_associated_token_address_with_program_id,
+    spl_token_2022::{extension::StateWithExtensions, state::Account as TokenAccount},
+    test_case::test_case,
+};
+
+async fn setup_governance_for_treasury(context: &mut ProgramTestContext, stake_config: &Pubkey) {
+    let governance = get_governance_address(stake_config, &paladin_governance_program::id());
+    setup_governance(
+        context,
+        &governance,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        stake_config,
+        None,
+        0,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn fail_payer_not_signer() {
+    let stake_config = Pubkey::new_unique();
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_governance_for_treasury(&mut context, &stake_config).await;
+
+    let payer = Pubkey::new_unique();
+    let mut instruction = initialize_treasury(&payer, &treasury, &governance);
+    instruction.accounts[0].is_signer = false; // Payer not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Payer not signer.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_governance_incorrect_address() {
+    let stake_config = Pubkey::new_unique();
+    let governance = Pubkey::new_unique(); // Incorrect governance address.
+    let treasury = get_treasury_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(
+        &mut context,
+        &governance,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        &stake_config,
+        None,
+        0,
+    )
+    .await;
+
+    let instruction = initialize_treasury(&context.payer.pubkey(), &treasury, &governance);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                PaladinGovernanceError::IncorrectGovernanceConfigAddress as u32
+            )
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_treasury_incorrect_address() {
+    let stake_config = Pubkey::new_unique();
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let treasury = Pubkey::new_unique(); // Incorrect treasury address.
+
+    let mut context = setup().start_with_context().await;
+    setup_governance_for_treasury(&mut context, &stake_config).await;
+
+    let instruction = initialize_treasury(&context.payer.pubkey(), &treasury, &governance);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::IncorrectTreasuryAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_treasury_token_account_incorrect_address() {
+    let stake_config = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_governance_for_treasury(&mut context, &stake_config).await;
+    setup_mint(&mut context, &mint, &spl_token_2022::id(), 6, 0).await;
+
+    let mut instruction = initialize_treasury_token_account(
+        &context.payer.pubkey(),
+        &treasury,
+        &governance,
+        &mint,
+        &spl_token_2022::id(),
+    );
+    instruction.accounts[4].pubkey = Pubkey::new_unique(); // Incorrect treasury token account.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                PaladinGovernanceError::IncorrectTreasuryTokenAccountAddress as u32
+            )
+        )
+    );
+}
+
+#[test_case(0; "unfunded")]
+#[test_case(1; "partially_funded")]
+#[test_case(1_000_000_000; "already_funded")]
+#[tokio::test]
+async fn success(treasury_starting_lamports: u64) {
+    let stake_config = Pubkey::new_unique();
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_governance_for_treasury(&mut context, &stake_config).await;
+
+    if treasury_starting_lamports > 0 {
+        context.set_account(
+            &treasury,
+            &AccountSharedData::new(treasury_starting_lamports, 0, &system_program::id()),
+        );
+    }
+
+    let instruction = initialize_treasury(&context.payer.pubkey(), &treasury, &governance);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the treasury holds at least the rent-exempt minimum.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let treasury_account = context
+        .banks_client
+        .get_account(treasury)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(treasury_account.owner, system_program::id());
+    assert_eq!(
+        treasury_account.lamports,
+        rent.minimum_balance(0).max(treasury_starting_lamports)
+    );
+}
+
+#[test_case(spl_token::id(); "token")]
+#[test_case(spl_token_2022::id(); "token_2022")]
+#[tokio::test]
+async fn success_token_account(token_program_id: Pubkey) {
+    let stake_config = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&stake_config, &paladin_governance_program::id());
+    let treasury_token_account =
+        get_associated_token_address_with_program_id(&treasury, &mint, &token_program_id);
+
+    let mut context = setup().start_with_context().await;
+    setup_governance_for_treasury(&mut context, &stake_config).await;
+    setup_mint(&mut context, &mint, &token_program_id, 6, 0).await;
+
+    let instruction = initialize_treasury_token_account(
+        &context.payer.pubkey(),
+        &treasury,
+        &governance,
+        &mint,
+        &token_program_id,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the treasury token account was created, owned by the treasury.
+    let treasury_token_account = context
+        .banks_client
+        .get_account(treasury_token_account)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(treasury_token_account.owner, token_program_id);
+    let treasury_token_account_state =
+        StateWithExtensions::<TokenAccount>::unpack(&treasury_token_account.data)
+            .unwrap()
+            .base;
+    assert_eq!(treasury_token_account_state.mint, mint);
+    assert_eq!(treasury_token_account_state.owner, treasury);
+    assert_eq!(treasury_token_account_state.amount, 0);
+}
@@ -9,14 +9,13 @@ use {
         instruction::push_instruction,
         state::{
             get_proposal_transaction_address, Config, Proposal, ProposalAccountMeta,
-            ProposalInstruction, ProposalStatus, ProposalTransaction,
+            ProposalInstruction, ProposalStatus, ProposalTransaction, ProposalTransactionV0,
         },
     },
     setup::{setup, setup_proposal, setup_proposal_transaction},
     solana_program_test::*,
     solana_sdk::{
-        account::AccountSharedData,
-        borsh1::get_instance_packed_len,
+        account::{Account, AccountSharedData},
         instruction::InstructionError,
         pubkey::Pubkey,
         signature::Keypair,
@@ -39,6 +38,7 @@ async fn fail_stake_authority_not_signer() {
         &stake_authority.pubkey(),
         &proposal_address,
         &proposal_transaction_address,
+        &context.payer.pubkey(),
         &Pubkey::new_unique(),
         vec![],
         vec![],
@@ -65,6 +65,48 @@ async fn fail_stake_authority_not_signer() {
     );
 }
 
+#[tokio::test]
+async fn fail_payer_not_signer() {
+    let stake_authority = Keypair::new();
+    let payer = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    let mut instruction = push_instruction(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+        &payer.pubkey(),
+        &Pubkey::new_unique(),
+        vec![],
+        vec![],
+    );
+    instruction.accounts[3].is_signer = false; // Payer not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority], // Payer not signer.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
 #[tokio::test]
 async fn fail_proposal_incorrect_owner() {
     let stake_authority = Keypair::new();
@@ -90,6 +132,7 @@ async fn fail_proposal_incorrect_owner() {
         &stake_authority.pubkey(),
         &proposal_address,
         &proposal_transaction_address,
+        &context.payer.pubkey(),
         &Pubkey::new_unique(),
         vec![],
         vec![],
@@ -140,6 +183,7 @@ async fn fail_proposal_not_initialized() {
         &stake_authority.pubkey(),
         &proposal_address,
         &proposal_transaction_address,
+        &context.payer.pubkey(),
         &Pubkey::new_unique(),
         vec![],
         vec![],
@@ -188,6 +232,7 @@ async fn fail_stake_authority_not_author() {
         &stake_authority.pubkey(),
         &proposal_address,
         &proposal_transaction_address,
+        &context.payer.pubkey(),
         &Pubkey::new_unique(),
         vec![],
         vec![],
@@ -236,6 +281,7 @@ async fn fail_proposal_not_in_draft_stage() {
         &stake_authority.pubkey(),
         &proposal_address,
         &proposal_transaction_address,
+        &context.payer.pubkey(),
         &Pubkey::new_unique(),
         vec![],
         vec![],
@@ -286,6 +332,7 @@ async fn fail_proposal_transaction_incorrect_address() {
         &stake_authority.pubkey(),
         &proposal_address,
         &proposal_transaction_address,
+        &context.payer.pubkey(),
         &Pubkey::new_unique(),
         vec![],
         vec![],
@@ -350,6 +397,7 @@ async fn fail_proposal_transaction_incorrect_owner() {
         &stake_authority.pubkey(),
         &proposal_address,
         &proposal_transaction_address,
+        &context.payer.pubkey(),
         &Pubkey::new_unique(),
         vec![],
         vec![],
@@ -409,6 +457,7 @@ async fn fail_proposal_transaction_not_initialized() {
         &stake_authority.pubkey(),
         &proposal_address,
         &proposal_transaction_address,
+        &context.payer.pubkey(),
         &Pubkey::new_unique(),
         vec![],
         vec![],
@@ -434,6 +483,74 @@ async fn fail_proposal_transaction_not_initialized() {
     );
 }
 
+#[tokio::test]
+async fn fail_proposal_transaction_not_migrated() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &stake_authority.pubkey(),
+        0,
+        Config::default(),
+        ProposalStatus::Draft,
+    )
+    .await;
+
+    // Set up the proposal transaction account with the legacy layout.
+    {
+        let data = borsh::to_vec(&ProposalTransactionV0::default()).unwrap();
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(data.len());
+        context.set_account(
+            &proposal_transaction_address,
+            &AccountSharedData::from(Account {
+                lamports,
+                data,
+                owner: paladin_governance_program::id(),
+                ..Account::default()
+            }),
+        );
+    }
+
+    let instruction = push_instruction(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+        &context.payer.pubkey(),
+        &Pubkey::new_unique(),
+        vec![],
+        vec![],
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::AccountVersionMismatch as u32)
+        )
+    );
+}
+
 #[tokio::test]
 async fn success() {
     let stake_authority = Keypair::new();
@@ -474,32 +591,13 @@ async fn success() {
     )
     .await;
 
-    // Fund the proposal transaction account to cover the new rent-exemption.
-    #[allow(clippy::arithmetic_side_effects)]
-    {
-        let rent = context.banks_client.get_rent().await.unwrap();
-        let new_instruction_len = get_instance_packed_len(&instruction_program_id).unwrap()
-            + get_instance_packed_len(&instruction_account_metas).unwrap()
-            + get_instance_packed_len(&instruction_data).unwrap();
-        let additional_lamports = rent.minimum_balance(new_instruction_len);
-
-        let mut proposal_transaction_account = context
-            .banks_client
-            .get_account(proposal_transaction_address)
-            .await
-            .unwrap()
-            .unwrap();
-        proposal_transaction_account.lamports += additional_lamports;
-        context.set_account(
-            &proposal_transaction_address,
-            &proposal_transaction_account.into(),
-        );
-    }
+    let rent = context.banks_client.get_rent().await.unwrap();
 
     let instruction = push_instruction(
         &stake_authority.pubkey(),
         &proposal_address,
         &proposal_transaction_address,
+        &context.payer.pubkey(),
         &instruction_program_id,
         instruction_account_metas.clone(),
         instruction_data.clone(),
@@ -527,6 +625,12 @@ async fn success() {
         .unwrap();
     let proposal_transaction_state =
         ProposalTransaction::try_from_slice(&proposal_transaction_account.data).unwrap();
+
+    // Assert the payer topped up the account to exactly its new rent-exemption.
+    assert_eq!(
+        proposal_transaction_account.lamports,
+        rent.minimum_balance(proposal_transaction_account.data.len())
+    );
     assert_eq!(proposal_transaction_state.instructions.len(), 1);
     assert_eq!(
         proposal_transaction_state.instructions[0],
@@ -555,32 +659,11 @@ async fn success() {
     ];
     let instruction_data = vec![4, 5, 6];
 
-    // Fund the proposal transaction account to cover the new rent-exemption.
-    #[allow(clippy::arithmetic_side_effects)]
-    {
-        let rent = context.banks_client.get_rent().await.unwrap();
-        let new_instruction_len = get_instance_packed_len(&instruction_program_id).unwrap()
-            + get_instance_packed_len(&instruction_account_metas).unwrap()
-            + get_instance_packed_len(&instruction_data).unwrap();
-        let additional_lamports = rent.minimum_balance(new_instruction_len);
-
-        let mut proposal_transaction_account = context
-            .banks_client
-            .get_account(proposal_transaction_address)
-            .await
-            .unwrap()
-            .unwrap();
-        proposal_transaction_account.lamports += additional_lamports;
-        context.set_account(
-            &proposal_transaction_address,
-            &proposal_transaction_account.into(),
-        );
-    }
-
     let instruction = push_instruction(
         &stake_authority.pubkey(),
         &proposal_address,
         &proposal_transaction_address,
+        &context.payer.pubkey(),
         &instruction_program_id,
         instruction_account_metas.clone(),
         instruction_data.clone(),
@@ -608,6 +691,12 @@ async fn success() {
         .unwrap();
     let proposal_transaction_state =
         ProposalTransaction::try_from_slice(&proposal_transaction_account.data).unwrap();
+
+    // Assert the payer topped up the account to exactly its new rent-exemption.
+    assert_eq!(
+        proposal_transaction_account.lamports,
+        rent.minimum_balance(proposal_transaction_account.data.len())
+    );
     assert_eq!(proposal_transaction_state.instructions.len(), 2);
     assert_eq!(
         proposal_transaction_state.instructions[1],
@@ -0,0 +1,1231 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    borsh::BorshDeserialize,
+    bytemuck::Zeroable,
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::{
+            migrate_account, migrate_proposal_transaction_account, migrate_proposal_vote_account,
+            migrate_vote_delegation_account, migrate_voting_proposal_account,
+        },
+        state::{
+            get_governance_address, get_proposal_transaction_address, get_proposal_vote_address,
+            get_vote_delegation_address, AccountType, Config, ConfigV0, ConfigV1, PeriodBounds,
+            Proposal, ProposalInstruction, ProposalStatus, ProposalTransaction,
+            ProposalTransactionV0, ProposalTransactionV1, ProposalV0, ProposalV1, ProposalVote,
+            ProposalVoteElection, ProposalVoteV0, ProposalVoteV1, ProposalVoteV2, VoteDelegation,
+            VoteDelegationV1, DEFAULT_MAX_EXECUTION_WINDOW_SECONDS,
+        },
+    },
+    paladin_stake_program::state::find_stake_pda,
+    setup::{
+        setup, setup_governance, setup_legacy_account, setup_proposal_vote_v0, setup_stake,
+        setup_stake_config,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        account::{Account, AccountSharedData},
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    spl_discriminator::SplDiscriminate,
+    std::num::NonZeroU64,
+    test_case::test_case,
+};
+
+fn config_v0(stake_config_address: &Pubkey) -> ConfigV0 {
+    let mut state = ConfigV0::zeroed();
+    state.cooldown_period_seconds = 100;
+    state.proposal_acceptance_threshold = 500_000_000;
+    state.proposal_rejection_threshold = 500_000_000;
+    state.stake_config_address = *stake_config_address;
+    state.voting_period_seconds = 100;
+    state
+}
+
+fn config_v1(stake_config_address: &Pubkey) -> ConfigV1 {
+    let mut state = ConfigV1::zeroed();
+    state.discriminator = Config::SPL_DISCRIMINATOR.into();
+    state.cooldown_period_seconds = 100;
+    state.proposal_acceptance_threshold = 500_000_000;
+    state.proposal_rejection_threshold = 500_000_000;
+    state.quorum_threshold = 100_000_000;
+    state.version = 1;
+    state.stake_config_address = *stake_config_address;
+    state.voting_period_seconds = 100;
+    state.proposal_count = 3;
+    state.proposal_minimum_stake = 1_000;
+    state.execution_window_seconds = 100;
+    state
+}
+
+fn proposal_v0(stake_config_address: &Pubkey, status: ProposalStatus) -> ProposalV0 {
+    let mut state = ProposalV0::zeroed();
+    state
+        .discriminator
+        .copy_from_slice(Proposal::SPL_DISCRIMINATOR_SLICE);
+    state.author = Pubkey::new_unique();
+    state.creation_timestamp = 1_000;
+    state.governance_config = config_v0(stake_config_address);
+    state.stake_for = 300;
+    state.stake_against = 200;
+    state.stake_abstained = 100;
+    state.status = status;
+    state.voting_start_timestamp = NonZeroU64::new(1_000);
+    state
+}
+
+async fn setup_proposal_transaction_v0(
+    context: &mut ProgramTestContext,
+    proposal_transaction_address: &Pubkey,
+    instructions: Vec<ProposalInstruction>,
+) {
+    let data = borsh::to_vec(&ProposalTransactionV0 { instructions }).unwrap();
+    let lamports = minimum_balance(context, data.len()).await;
+    context.set_account(
+        proposal_transaction_address,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: paladin_governance_program::id(),
+            ..Account::default()
+        }),
+    );
+}
+
+async fn setup_proposal_transaction_v1(
+    context: &mut ProgramTestContext,
+    proposal_transaction_address: &Pubkey,
+    instructions: Vec<ProposalInstruction>,
+) {
+    let data = borsh::to_vec(&ProposalTransactionV1 {
+        discriminator: ProposalTransaction::SPL_DISCRIMINATOR.into(),
+        version: 1,
+        instructions,
+    })
+    .unwrap();
+    let lamports = minimum_balance(context, data.len()).await;
+    context.set_account(
+        proposal_transaction_address,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: paladin_governance_program::id(),
+            ..Account::default()
+        }),
+    );
+}
+
+async fn minimum_balance(context: &mut ProgramTestContext, space: usize) -> u64 {
+    context
+        .banks_client
+        .get_rent()
+        .await
+        .unwrap()
+        .minimum_balance(space)
+}
+
+#[tokio::test]
+async fn fail_payer_not_signer() {
+    let stake_config = Pubkey::new_unique();
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    let lamports = minimum_balance(&mut context, std::mem::size_of::<ConfigV0>()).await;
+    setup_legacy_account(
+        &mut context,
+        &governance,
+        &config_v0(&stake_config),
+        lamports,
+    )
+    .await;
+
+    let payer = Keypair::new();
+
+    let mut instruction = migrate_account(&governance, &payer.pubkey());
+    instruction.accounts[1].is_signer = false; // Payer not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_incorrect_owner() {
+    let stake_config = Pubkey::new_unique();
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    // Set up a governance account with the incorrect owner.
+    {
+        let space = std::mem::size_of::<ConfigV0>();
+        let lamports = minimum_balance(&mut context, space).await;
+        let mut account = AccountSharedData::new(
+            lamports,
+            space,
+            &Pubkey::new_unique(), // Incorrect owner.
+        );
+        account.set_data_from_slice(bytemuck::bytes_of(&config_v0(&stake_config)));
+        context.set_account(&governance, &account);
+    }
+
+    let instruction = migrate_account(&governance, &context.payer.pubkey());
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+    );
+}
+
+#[tokio::test]
+async fn fail_already_migrated() {
+    let stake_config = Pubkey::new_unique();
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(
+        &mut context,
+        &governance,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        &stake_config,
+        None,
+        0,
+    )
+    .await;
+
+    let instruction = migrate_account(&governance, &context.payer.pubkey());
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::AccountAlreadyInitialized)
+    );
+}
+
+#[tokio::test]
+async fn fail_unrecognized_layout() {
+    let stake_config = Pubkey::new_unique();
+    let account = Pubkey::new_unique(); // Not the governance address.
+
+    let mut context = setup().start_with_context().await;
+
+    let lamports = minimum_balance(&mut context, std::mem::size_of::<ConfigV0>()).await;
+    setup_legacy_account(&mut context, &account, &config_v0(&stake_config), lamports).await;
+
+    let instruction = migrate_account(&account, &context.payer.pubkey());
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountData)
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_vote_v0_missing_stake() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    let lamports = minimum_balance(&mut context, std::mem::size_of::<ProposalVoteV0>()).await;
+    setup_proposal_vote_v0(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        100,
+        &stake_authority.pubkey(),
+        ProposalVoteElection::For,
+        lamports,
+    )
+    .await;
+
+    // Omit the stake account.
+    let instruction = migrate_account(&proposal_vote, &context.payer.pubkey());
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_vote_v0_incorrect_stake() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    let lamports = minimum_balance(&mut context, std::mem::size_of::<ProposalVoteV0>()).await;
+    setup_proposal_vote_v0(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        100,
+        &stake_authority.pubkey(),
+        ProposalVoteElection::For,
+        lamports,
+    )
+    .await;
+
+    let instruction = migrate_proposal_vote_account(
+        &proposal_vote,
+        &context.payer.pubkey(),
+        &Pubkey::new_unique(), // Incorrect stake account.
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::IncorrectProposalVoteAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_governance() {
+    let stake_config = Pubkey::new_unique();
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    // Set up a governance account with the legacy layout, funded only for
+    // that layout.
+    let v0_state = config_v0(&stake_config);
+    let lamports = minimum_balance(&mut context, std::mem::size_of::<ConfigV0>()).await;
+    setup_legacy_account(&mut context, &governance, &v0_state, lamports).await;
+
+    let instruction = migrate_account(&governance, &context.payer.pubkey());
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the governance account was migrated and funded for the current
+    // layout by the payer.
+    let governance_account = context
+        .banks_client
+        .get_account(governance)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        governance_account.lamports,
+        minimum_balance(&mut context, std::mem::size_of::<Config>()).await
+    );
+    let governance_state = bytemuck::from_bytes::<Config>(&governance_account.data);
+    assert_eq!(governance_state.version, Config::VERSION);
+    assert_eq!(governance_state, &Config::from_v0(&v0_state));
+    assert_eq!(governance_state.proposal_count, 0);
+    assert_eq!(
+        governance_state.execution_window_seconds,
+        DEFAULT_MAX_EXECUTION_WINDOW_SECONDS
+    );
+}
+
+#[tokio::test]
+async fn success_governance_v1() {
+    let stake_config = Pubkey::new_unique();
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    // Set up a governance account with the version 1 layout, funded only for
+    // that layout.
+    let v1_state = config_v1(&stake_config);
+    let lamports = minimum_balance(&mut context, std::mem::size_of::<ConfigV1>()).await;
+    setup_legacy_account(&mut context, &governance, &v1_state, lamports).await;
+
+    let instruction = migrate_account(&governance, &context.payer.pubkey());
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the governance account was migrated and funded for the current
+    // layout, keeping its settings and taking the default period bounds.
+    let governance_account = context
+        .banks_client
+        .get_account(governance)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        governance_account.lamports,
+        minimum_balance(&mut context, std::mem::size_of::<Config>()).await
+    );
+    let governance_state = bytemuck::from_bytes::<Config>(&governance_account.data);
+    assert_eq!(governance_state.version, Config::VERSION);
+    assert_eq!(governance_state, &Config::from_v1(&v1_state));
+    assert_eq!(governance_state.proposal_count, 3);
+    assert_eq!(governance_state.period_bounds, PeriodBounds::default());
+}
+
+#[tokio::test]
+async fn fail_proposal_voting_missing_stake_config() {
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    let lamports = minimum_balance(&mut context, std::mem::size_of::<ProposalV0>()).await;
+    setup_legacy_account(
+        &mut context,
+        &proposal,
+        &proposal_v0(&stake_config, ProposalStatus::Voting),
+        lamports,
+    )
+    .await;
+
+    // Omit the stake config account.
+    let instruction = migrate_account(&proposal, &context.payer.pubkey());
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_voting_incorrect_stake_config() {
+    let stake_config = Pubkey::new_unique();
+    let other_stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_stake_config(&mut context, &other_stake_config, 1_000).await;
+
+    let lamports = minimum_balance(&mut context, std::mem::size_of::<ProposalV0>()).await;
+    setup_legacy_account(
+        &mut context,
+        &proposal,
+        &proposal_v0(&stake_config, ProposalStatus::Voting),
+        lamports,
+    )
+    .await;
+
+    let instruction = migrate_voting_proposal_account(
+        &proposal,
+        &context.payer.pubkey(),
+        &other_stake_config, // Incorrect stake config.
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::IncorrectStakeConfig as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_proposal() {
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Set up an accepted proposal account with the legacy layout.
+    let v0_state = proposal_v0(&stake_config, ProposalStatus::Accepted);
+    let lamports = minimum_balance(&mut context, std::mem::size_of::<ProposalV0>()).await;
+    setup_legacy_account(&mut context, &proposal, &v0_state, lamports).await;
+
+    let instruction = migrate_account(&proposal, &context.payer.pubkey());
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal was migrated and funded for the current layout.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        proposal_account.lamports,
+        minimum_balance(&mut context, std::mem::size_of::<Proposal>()).await
+    );
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.version, Proposal::VERSION);
+    assert_eq!(proposal_state, &Proposal::from_v0(&v0_state, 0));
+    assert_eq!(proposal_state.status, ProposalStatus::Accepted);
+}
+
+#[tokio::test]
+async fn success_proposal_v1() {
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Set up a proposal in the voting stage with the version 1 layout. No
+    // stake config is needed, since it already records its total stake.
+    let mut v1_state = ProposalV1::zeroed();
+    v1_state.discriminator = Proposal::SPL_DISCRIMINATOR.into();
+    v1_state.author = Pubkey::new_unique();
+    v1_state.creation_timestamp = 1_000;
+    v1_state.governance_config = config_v1(&stake_config);
+    v1_state.stake_for = 300;
+    v1_state.stake_against = 200;
+    v1_state.stake_abstained = 100;
+    v1_state.status = ProposalStatus::Voting;
+    v1_state.version = 1;
+    v1_state.total_stake = 1_000;
+    v1_state.voting_start_timestamp = NonZeroU64::new(1_000);
+    let lamports = minimum_balance(&mut context, std::mem::size_of::<ProposalV1>()).await;
+    setup_legacy_account(&mut context, &proposal, &v1_state, lamports).await;
+
+    let instruction = migrate_account(&proposal, &context.payer.pubkey());
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal was migrated and funded for the current layout.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        proposal_account.lamports,
+        minimum_balance(&mut context, std::mem::size_of::<Proposal>()).await
+    );
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.version, Proposal::VERSION);
+    assert_eq!(proposal_state, &Proposal::from_v1(&v1_state));
+    assert_eq!(proposal_state.total_stake, 1_000);
+    assert_eq!(
+        proposal_state.governance_config.period_bounds,
+        PeriodBounds::default()
+    );
+}
+
+#[tokio::test]
+async fn success_proposal_voting() {
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_stake_config(&mut context, &stake_config, 1_000).await;
+
+    // Set up a proposal account in the voting stage with the legacy layout.
+    let v0_state = proposal_v0(&stake_config, ProposalStatus::Voting);
+    let lamports = minimum_balance(&mut context, std::mem::size_of::<ProposalV0>()).await;
+    setup_legacy_account(&mut context, &proposal, &v0_state, lamports).await;
+
+    let instruction =
+        migrate_voting_proposal_account(&proposal, &context.payer.pubkey(), &stake_config);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal was migrated, recording the stake config's current
+    // delegated stake as its total stake.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.version, Proposal::VERSION);
+    assert_eq!(proposal_state, &Proposal::from_v0(&v0_state, 1_000));
+    assert_eq!(proposal_state.status, ProposalStatus::Voting);
+    assert_eq!(proposal_state.stake_for, 300);
+    assert_eq!(proposal_state.total_stake, 1_000);
+}
+
+#[tokio::test]
+async fn success_proposal_vote_v1() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    // Set up a proposal vote account with the version 1 layout.
+    let mut v1_state = ProposalVoteV1::zeroed();
+    v1_state.proposal_address = proposal;
+    v1_state.stake = 100;
+    v1_state.stake_address = stake;
+    v1_state.authority_address = stake_authority.pubkey();
+    v1_state.vote_timestamp = NonZeroU64::new(1_000);
+    v1_state.election = ProposalVoteElection::Against;
+    v1_state.version = 1;
+    let lamports = minimum_balance(&mut context, std::mem::size_of::<ProposalVoteV1>()).await;
+    setup_legacy_account(&mut context, &proposal_vote, &v1_state, lamports).await;
+
+    let instruction = migrate_account(&proposal_vote, &context.payer.pubkey());
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal vote was migrated and funded for the current layout.
+    let proposal_vote_account = context
+        .banks_client
+        .get_account(proposal_vote)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        proposal_vote_account.lamports,
+        minimum_balance(&mut context, std::mem::size_of::<ProposalVote>()).await
+    );
+    let proposal_vote_state = bytemuck::from_bytes::<ProposalVote>(&proposal_vote_account.data);
+    assert_eq!(proposal_vote_state.version, ProposalVote::VERSION);
+    assert_eq!(
+        proposal_vote_state,
+        &ProposalVote::new(
+            &proposal,
+            100,
+            &stake,
+            &stake_authority.pubkey(),
+            &stake_authority.pubkey(),
+            NonZeroU64::new(1_000),
+            ProposalVoteElection::Against,
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_proposal_vote_v2() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    // Set up a proposal vote account with the version 2 layout.
+    let mut v2_state = ProposalVoteV2::zeroed();
+    v2_state.discriminator = ProposalVote::SPL_DISCRIMINATOR.into();
+    v2_state.proposal_address = proposal;
+    v2_state.stake = 100;
+    v2_state.stake_address = stake;
+    v2_state.authority_address = stake_authority.pubkey();
+    v2_state.vote_timestamp = NonZeroU64::new(1_000);
+    v2_state.election = ProposalVoteElection::For;
+    v2_state.version = 2;
+    let lamports = minimum_balance(&mut context, std::mem::size_of::<ProposalVoteV2>()).await;
+    setup_legacy_account(&mut context, &proposal_vote, &v2_state, lamports).await;
+
+    let instruction = migrate_account(&proposal_vote, &context.payer.pubkey());
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal vote was migrated, recording the voting authority
+    // as the payer.
+    let proposal_vote_account = context
+        .banks_client
+        .get_account(proposal_vote)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        proposal_vote_account.lamports,
+        minimum_balance(&mut context, std::mem::size_of::<ProposalVote>()).await
+    );
+    let proposal_vote_state = bytemuck::from_bytes::<ProposalVote>(&proposal_vote_account.data);
+    assert_eq!(proposal_vote_state.version, ProposalVote::VERSION);
+    assert_eq!(
+        proposal_vote_state,
+        &ProposalVote::new(
+            &proposal,
+            100,
+            &stake,
+            &stake_authority.pubkey(),
+            &stake_authority.pubkey(),
+            NonZeroU64::new(1_000),
+            ProposalVoteElection::For,
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_proposal_vote_v0() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    // Set up a proposal vote account with the unversioned layout.
+    let lamports = minimum_balance(&mut context, std::mem::size_of::<ProposalVoteV0>()).await;
+    setup_proposal_vote_v0(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        100,
+        &stake_authority.pubkey(),
+        ProposalVoteElection::For,
+        lamports,
+    )
+    .await;
+
+    let instruction =
+        migrate_proposal_vote_account(&proposal_vote, &context.payer.pubkey(), &stake);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal vote was migrated and funded for the current layout.
+    let proposal_vote_account = context
+        .banks_client
+        .get_account(proposal_vote)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        proposal_vote_account.lamports,
+        minimum_balance(&mut context, std::mem::size_of::<ProposalVote>()).await
+    );
+    let proposal_vote_state = bytemuck::from_bytes::<ProposalVote>(&proposal_vote_account.data);
+    assert_eq!(proposal_vote_state.version, ProposalVote::VERSION);
+    assert_eq!(
+        proposal_vote_state,
+        &ProposalVote::new(
+            &proposal,
+            100,
+            &stake,
+            &stake_authority.pubkey(),
+            &stake_authority.pubkey(),
+            None,
+            ProposalVoteElection::For,
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_transaction_missing_proposal() {
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal_transaction_v0(&mut context, &proposal_transaction, vec![]).await;
+
+    // Omit the proposal account.
+    let instruction = migrate_account(&proposal_transaction, &context.payer.pubkey());
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_transaction_incorrect_proposal() {
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal_transaction_v0(&mut context, &proposal_transaction, vec![]).await;
+
+    let instruction = migrate_proposal_transaction_account(
+        &proposal_transaction,
+        &context.payer.pubkey(),
+        &Pubkey::new_unique(), // Incorrect proposal.
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                PaladinGovernanceError::IncorrectProposalTransactionAddress as u32
+            )
+        )
+    );
+}
+
+#[test_case(0; "empty")]
+#[test_case(2; "two_instructions")]
+#[tokio::test]
+async fn success_proposal_transaction(instruction_count: u8) {
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    // Set up a proposal transaction account with the legacy layout.
+    let instructions = (0..instruction_count)
+        .map(|i| ProposalInstruction::new(&Pubkey::new_unique(), vec![], vec![i; 3]))
+        .collect::<Vec<_>>();
+    setup_proposal_transaction_v0(&mut context, &proposal_transaction, instructions.clone()).await;
+
+    let instruction = migrate_proposal_transaction_account(
+        &proposal_transaction,
+        &context.payer.pubkey(),
+        &proposal,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal transaction was migrated and funded for the current
+    // layout.
+    let proposal_transaction_account = context
+        .banks_client
+        .get_account(proposal_transaction)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        proposal_transaction_account.lamports,
+        minimum_balance(&mut context, proposal_transaction_account.data.len()).await
+    );
+    let proposal_transaction_state =
+        ProposalTransaction::try_from_slice(&proposal_transaction_account.data).unwrap();
+    assert_eq!(
+        proposal_transaction_state.version,
+        ProposalTransaction::VERSION
+    );
+    assert_eq!(
+        proposal_transaction_state,
+        ProposalTransaction::new(instructions)
+    );
+}
+
+#[test_case(0; "empty")]
+#[test_case(2; "two_instructions")]
+#[tokio::test]
+async fn success_proposal_transaction_v1(instruction_count: u8) {
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    // Set up a proposal transaction account with the version 1 layout, which
+    // predates the instruction slot.
+    let instructions = (0..instruction_count)
+        .map(|i| ProposalInstruction::new(&Pubkey::new_unique(), vec![], vec![i; 3]))
+        .collect::<Vec<_>>();
+    setup_proposal_transaction_v1(&mut context, &proposal_transaction, instructions.clone()).await;
+
+    // The discriminator identifies the account, so no proposal is required.
+    let instruction = migrate_account(&proposal_transaction, &context.payer.pubkey());
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal transaction was migrated and funded for the current
+    // layout.
+    let proposal_transaction_account = context
+        .banks_client
+        .get_account(proposal_transaction)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        proposal_transaction_account.lamports,
+        minimum_balance(&mut context, proposal_transaction_account.data.len()).await
+    );
+    let proposal_transaction_state =
+        ProposalTransaction::try_from_slice(&proposal_transaction_account.data).unwrap();
+    assert_eq!(
+        proposal_transaction_state.version,
+        ProposalTransaction::VERSION
+    );
+    assert_eq!(
+        proposal_transaction_state,
+        ProposalTransaction::new(instructions)
+    );
+}
+
+fn vote_delegation_v1(governance_address: &Pubkey, stake_address: &Pubkey) -> VoteDelegationV1 {
+    let mut state = VoteDelegationV1::zeroed();
+    state.discriminator = VoteDelegation::SPL_DISCRIMINATOR.into();
+    state.delegate = Pubkey::new_unique();
+    state.expiry_timestamp = NonZeroU64::new(10_000);
+    state.governance_config_address = *governance_address;
+    state.stake_address = *stake_address;
+    state.version = 1;
+    state
+}
+
+#[tokio::test]
+async fn fail_vote_delegation_v1_incorrect_stake() {
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let vote_delegation =
+        get_vote_delegation_address(&stake, &governance, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    let v1_state = vote_delegation_v1(&governance, &stake);
+    let lamports = minimum_balance(&mut context, std::mem::size_of::<VoteDelegationV1>()).await;
+    setup_legacy_account(&mut context, &vote_delegation, &v1_state, lamports).await;
+
+    let instruction = migrate_vote_delegation_account(
+        &vote_delegation,
+        &context.payer.pubkey(),
+        &Pubkey::new_unique(), // Incorrect stake account.
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::IncorrectVoteDelegationAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_vote_delegation_v1() {
+    let stake_authority = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let vote_delegation =
+        get_vote_delegation_address(&stake, &governance, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(&mut context, &stake, &stake_authority, &validator_vote, 0).await;
+
+    // Set up a vote delegation account with the version 1 layout, funded only
+    // for that layout.
+    let v1_state = vote_delegation_v1(&governance, &stake);
+    let lamports = minimum_balance(&mut context, std::mem::size_of::<VoteDelegationV1>()).await;
+    setup_legacy_account(&mut context, &vote_delegation, &v1_state, lamports).await;
+
+    let instruction =
+        migrate_vote_delegation_account(&vote_delegation, &context.payer.pubkey(), &stake);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the vote delegation was migrated and funded for the current
+    // layout, recording the stake authority as its payer.
+    let vote_delegation_account = context
+        .banks_client
+        .get_account(vote_delegation)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        vote_delegation_account.lamports,
+        minimum_balance(&mut context, std::mem::size_of::<VoteDelegation>()).await
+    );
+    let vote_delegation_state =
+        bytemuck::from_bytes::<VoteDelegation>(&vote_delegation_account.data);
+    assert_eq!(vote_delegation_state.version, VoteDelegation::VERSION);
+    assert_eq!(
+        vote_delegation_state,
+        &VoteDelegation::from_v1(&v1_state, &stake_authority)
+    );
+    assert_eq!(vote_delegation_state.payer_address, stake_authority);
+}
@@ -0,0 +1,290 @@
+#![cfg(feature = "test-sbf")]
+// `MigrateProposalVote` is deprecated in favor of `MigrateAccount`, but is
+// still processed.
+#![allow(deprecated)]
+
+mod setup;
+
+use {
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::migrate_proposal_vote,
+        state::{
+            get_proposal_vote_address, AccountType, ProposalVote, ProposalVoteElection,
+            ProposalVoteV0,
+        },
+    },
+    paladin_stake_program::state::find_stake_pda,
+    setup::{setup, setup_proposal_vote, setup_proposal_vote_v0},
+    solana_program_test::*,
+    solana_sdk::{
+        account::AccountSharedData,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+};
+
+#[tokio::test]
+async fn fail_proposal_vote_incorrect_owner() {
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    // Set up a proposal vote account with the incorrect owner.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let space = std::mem::size_of::<ProposalVoteV0>();
+        let lamports = rent.minimum_balance(space);
+        context.set_account(
+            &proposal_vote,
+            &AccountSharedData::new(lamports, space, &Pubkey::new_unique()), // Incorrect owner.
+        );
+    }
+
+    let instruction = migrate_proposal_vote(&stake, &proposal_vote);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_vote_not_initialized() {
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    // Set up an uninitialized proposal vote account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(std::mem::size_of::<ProposalVote>());
+        context.set_account(
+            &proposal_vote,
+            &AccountSharedData::new(lamports, 0, &paladin_governance_program::id()),
+        );
+    }
+
+    let instruction = migrate_proposal_vote(&stake, &proposal_vote);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::UninitializedAccount)
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_vote_already_migrated() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal_vote(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        0,
+        &stake,
+        &stake_authority.pubkey(),
+        ProposalVoteElection::For,
+    )
+    .await;
+
+    let instruction = migrate_proposal_vote(&stake, &proposal_vote);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::AccountAlreadyInitialized)
+    );
+}
+
+#[tokio::test]
+async fn fail_stake_incorrect_address() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    let lamports = context
+        .banks_client
+        .get_rent()
+        .await
+        .unwrap()
+        .minimum_balance(std::mem::size_of::<ProposalVote>());
+    setup_proposal_vote_v0(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        0,
+        &stake_authority.pubkey(),
+        ProposalVoteElection::For,
+        lamports,
+    )
+    .await;
+
+    let instruction = migrate_proposal_vote(
+        &Pubkey::new_unique(), // Incorrect stake address.
+        &proposal_vote,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::IncorrectProposalVoteAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    // Set up a proposal vote account with the unversioned layout, funded for
+    // the current layout.
+    let lamports = context
+        .banks_client
+        .get_rent()
+        .await
+        .unwrap()
+        .minimum_balance(std::mem::size_of::<ProposalVote>());
+    setup_proposal_vote_v0(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        100,
+        &stake_authority.pubkey(),
+        ProposalVoteElection::Against,
+        lamports,
+    )
+    .await;
+
+    let instruction = migrate_proposal_vote(&stake, &proposal_vote);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal vote was migrated, recording the stake account
+    // alongside the authority that cast the vote.
+    let proposal_vote_account = context
+        .banks_client
+        .get_account(proposal_vote)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_vote_state = bytemuck::from_bytes::<ProposalVote>(&proposal_vote_account.data);
+    assert_eq!(proposal_vote_state.version, ProposalVote::VERSION);
+    assert_eq!(
+        proposal_vote_state,
+        &ProposalVote::new(
+            &proposal,
+            100,
+            &stake,
+            &stake_authority.pubkey(),
+            &stake_authority.pubkey(),
+            None,
+            ProposalVoteElection::Against,
+        )
+    );
+}
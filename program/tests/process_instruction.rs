@@ -11,11 +11,15 @@ use {
             ProposalStatus, ProposalTransaction,
         },
     },
-    setup::{create_mock_proposal_transaction, setup, setup_proposal, setup_proposal_transaction},
+    setup::{
+        create_mock_proposal_transaction, setup, setup_proposal, setup_proposal_transaction,
+        setup_proposal_with_stake_and_cooldown,
+    },
     solana_program_test::*,
     solana_sdk::{
         account::AccountSharedData,
         borsh1::get_instance_packed_len,
+        clock::Clock,
         instruction::{AccountMeta, InstructionError},
         pubkey::Pubkey,
         signature::Keypair,
@@ -23,6 +27,7 @@ use {
         system_instruction, system_program,
         transaction::{Transaction, TransactionError},
     },
+    std::num::NonZeroU64,
 };
 
 #[tokio::test]
@@ -170,6 +175,77 @@ async fn fail_proposal_not_accepted() {
     );
 }
 
+#[tokio::test]
+async fn fail_execution_window_ended() {
+    let proposal_address = Pubkey::new_unique();
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let instruction_index = 0u32;
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 10,
+        /* execution_window_seconds */ 1_000,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        /* stake_config_address */ &Pubkey::new_unique(),
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 0,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal_address,
+        &Pubkey::new_unique(),
+        0,
+        governance_config,
+        0,
+        0,
+        0,
+        /* total_stake */ 0,
+        ProposalStatus::Accepted,
+        /* voting_start_timestamp */ NonZeroU64::new(1),
+        /* cooldown_timestamp */
+        NonZeroU64::new(clock.unix_timestamp.saturating_sub(1_010) as u64), // Window ended.
+    )
+    .await;
+
+    let instruction = process_instruction(
+        &proposal_address,
+        &proposal_transaction_address,
+        &[],
+        instruction_index,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::ProposalExecutionWindowEnded as u32)
+        )
+    );
+}
+
 #[tokio::test]
 async fn fail_proposal_transaction_incorrect_address() {
     let proposal_address = Pubkey::new_unique();
@@ -519,10 +595,14 @@ async fn success() {
     let stake_config_address = Pubkey::new_unique();
     let governance_config = Config::new(
         /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
         /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
         /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
         /* signer_bump_seed */ 0,
         /* stake_config_address */ &stake_config_address,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 0,
     );
 
@@ -536,14 +616,12 @@ async fn success() {
     let treasury_to_alice_lamports = 100_000_000;
     let alice_to_treasury_lamports = 50_000_000;
 
-    let proposal_transaction = ProposalTransaction {
-        instructions: vec![
-            (&system_instruction::transfer(&treasury, &alice.pubkey(), treasury_to_alice_lamports))
-                .into(),
-            (&system_instruction::transfer(&alice.pubkey(), &treasury, alice_to_treasury_lamports))
-                .into(),
-        ],
-    };
+    let proposal_transaction = ProposalTransaction::new(vec![
+        (&system_instruction::transfer(&treasury, &alice.pubkey(), treasury_to_alice_lamports))
+            .into(),
+        (&system_instruction::transfer(&alice.pubkey(), &treasury, alice_to_treasury_lamports))
+            .into(),
+    ]);
 
     let mut context = setup().start_with_context().await;
     setup_proposal(
@@ -621,6 +699,17 @@ async fn success() {
                 .lamports,
             alice_starting_lamports + treasury_to_alice_lamports
         );
+
+        // Assert the proposal is not yet processed.
+        let proposal_account = context
+            .banks_client
+            .get_account(proposal_address)
+            .await
+            .unwrap()
+            .unwrap();
+        let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+        assert_eq!(proposal_state.status, ProposalStatus::Accepted);
+        assert!(proposal_state.execution_timestamp.is_none());
     }
 
     // Execute the second instruction.
@@ -672,5 +761,16 @@ async fn success() {
                 .lamports,
             alice_starting_lamports + treasury_to_alice_lamports - alice_to_treasury_lamports
         );
+
+        // Assert the proposal was marked as processed.
+        let proposal_account = context
+            .banks_client
+            .get_account(proposal_address)
+            .await
+            .unwrap()
+            .unwrap();
+        let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+        assert_eq!(proposal_state.status, ProposalStatus::Processed);
+        assert!(proposal_state.execution_timestamp.is_some());
     }
 }
@@ -20,6 +20,7 @@ use {
         pubkey::Pubkey,
         signature::Keypair,
         signer::Signer,
+        system_program,
         transaction::{Transaction, TransactionError},
     },
 };
@@ -532,8 +533,27 @@ async fn success() {
     )
     .await;
 
+    // Fund the stake authority, which is refunded the rent freed by each
+    // removal.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    context.set_account(
+        &stake_authority.pubkey(),
+        &AccountSharedData::new(rent.minimum_balance(0), 0, &system_program::id()),
+    );
+
     // Remove instruction zero.
     {
+        let proposal_transaction_lamports = context
+            .banks_client
+            .get_balance(proposal_transaction_address)
+            .await
+            .unwrap();
+        let stake_authority_lamports = context
+            .banks_client
+            .get_balance(stake_authority.pubkey())
+            .await
+            .unwrap();
+
         let instruction = remove_instruction(
             &stake_authority.pubkey(),
             &proposal_address,
@@ -566,6 +586,22 @@ async fn success() {
 
         assert_eq!(proposal_transaction_state.instructions.len(), 4);
 
+        // Assert the excess rent was refunded to the stake authority.
+        assert_eq!(
+            proposal_transaction_account.lamports,
+            rent.minimum_balance(proposal_transaction_account.data.len())
+        );
+        let refund = proposal_transaction_lamports - proposal_transaction_account.lamports;
+        assert!(refund > 0);
+        assert_eq!(
+            context
+                .banks_client
+                .get_balance(stake_authority.pubkey())
+                .await
+                .unwrap(),
+            stake_authority_lamports + refund
+        );
+
         // Assert program ID 0 was removed.
         assert_eq!(
             proposal_transaction_state
@@ -0,0 +1,450 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::revoke_vote_delegation,
+        state::{get_governance_address, get_vote_delegation_address},
+    },
+    paladin_stake_program::state::find_stake_pda,
+    setup::{setup, setup_stake, setup_vote_delegation, setup_vote_delegation_with_payer},
+    solana_program_test::*,
+    solana_sdk::{
+        account::AccountSharedData,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        system_program,
+        transaction::{Transaction, TransactionError},
+    },
+};
+
+#[tokio::test]
+async fn fail_stake_authority_not_signer() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let vote_delegation =
+        get_vote_delegation_address(&stake, &governance, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    let mut instruction = revoke_vote_delegation(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &vote_delegation,
+        &stake_authority.pubkey(),
+    );
+    instruction.accounts[0].is_signer = false; // Stake authority not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Stake authority not signer.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_stake_incorrect_stake_authority() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let vote_delegation =
+        get_vote_delegation_address(&stake, &governance, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    // Set up a stake account with the wrong stake authority address.
+    setup_stake(
+        &mut context,
+        &stake,
+        &Pubkey::new_unique(), // Incorrect stake authority.
+        &validator_vote,
+        0,
+    )
+    .await;
+
+    let instruction = revoke_vote_delegation(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &vote_delegation,
+        &stake_authority.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+}
+
+#[tokio::test]
+async fn fail_vote_delegation_incorrect_address() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let vote_delegation = Pubkey::new_unique(); // Incorrect vote delegation address.
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        0,
+    )
+    .await;
+
+    let instruction = revoke_vote_delegation(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &vote_delegation,
+        &stake_authority.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::IncorrectVoteDelegationAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_vote_delegation_not_initialized() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let vote_delegation =
+        get_vote_delegation_address(&stake, &governance, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        0,
+    )
+    .await;
+
+    // Set up an uninitialized vote delegation account.
+    context.set_account(
+        &vote_delegation,
+        &AccountSharedData::new(100_000_000, 0, &system_program::id()),
+    );
+
+    let instruction = revoke_vote_delegation(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &vote_delegation,
+        &stake_authority.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let vote_delegation =
+        get_vote_delegation_address(&stake, &governance, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_vote_delegation_with_payer(
+        &mut context,
+        &vote_delegation,
+        &Pubkey::new_unique(),
+        None,
+        &governance,
+        &stake,
+        &stake_authority.pubkey(),
+    )
+    .await;
+
+    let vote_delegation_lamports = context
+        .banks_client
+        .get_account(vote_delegation)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction = revoke_vote_delegation(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &vote_delegation,
+        &stake_authority.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the vote delegation was closed, and its lamports returned to the
+    // stake authority, which funded it.
+    assert!(context
+        .banks_client
+        .get_account(vote_delegation)
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        context
+            .banks_client
+            .get_account(stake_authority.pubkey())
+            .await
+            .unwrap()
+            .unwrap()
+            .lamports,
+        vote_delegation_lamports
+    );
+}
+
+#[tokio::test]
+async fn fail_incorrect_payer() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let vote_delegation =
+        get_vote_delegation_address(&stake, &governance, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_vote_delegation(
+        &mut context,
+        &vote_delegation,
+        &Pubkey::new_unique(),
+        None,
+        &governance,
+        &stake,
+    )
+    .await;
+
+    let instruction = revoke_vote_delegation(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &vote_delegation,
+        &stake_authority.pubkey(), // Not the recorded payer.
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::IncorrectVoteDelegationPayer as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_refunds_payer() {
+    let stake_authority = Keypair::new();
+    let payer = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let vote_delegation =
+        get_vote_delegation_address(&stake, &governance, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_vote_delegation_with_payer(
+        &mut context,
+        &vote_delegation,
+        &Pubkey::new_unique(),
+        None,
+        &governance,
+        &stake,
+        &payer,
+    )
+    .await;
+
+    let vote_delegation_lamports = context
+        .banks_client
+        .get_account(vote_delegation)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction = revoke_vote_delegation(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &vote_delegation,
+        &payer,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the vote delegation was closed.
+    assert!(context
+        .banks_client
+        .get_account(vote_delegation)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Assert the payer, not the stake authority, received the lamports.
+    assert_eq!(
+        context
+            .banks_client
+            .get_account(payer)
+            .await
+            .unwrap()
+            .unwrap()
+            .lamports,
+        vote_delegation_lamports
+    );
+    assert!(context
+        .banks_client
+        .get_account(stake_authority.pubkey())
+        .await
+        .unwrap()
+        .is_none());
+}
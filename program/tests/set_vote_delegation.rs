@@ -0,0 +1,666 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::set_vote_delegation,
+        state::{get_governance_address, get_vote_delegation_address, VoteDelegation},
+    },
+    paladin_stake_program::state::find_stake_pda,
+    setup::{
+        setup, setup_governance, setup_stake, setup_stake_config, setup_vote_delegation_with_payer,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        account::AccountSharedData,
+        clock::Clock,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        system_program,
+        transaction::{Transaction, TransactionError},
+    },
+    std::num::NonZeroU64,
+    test_case::test_case,
+};
+
+#[tokio::test]
+async fn fail_stake_authority_not_signer() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let vote_delegation =
+        get_vote_delegation_address(&stake, &governance, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    let mut instruction = set_vote_delegation(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &governance,
+        &vote_delegation,
+        &context.payer.pubkey(),
+        &Pubkey::new_unique(),
+        None,
+    );
+    instruction.accounts[0].is_signer = false; // Stake authority not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Stake authority not signer.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_payer_not_signer() {
+    let stake_authority = Keypair::new();
+    let payer = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let vote_delegation =
+        get_vote_delegation_address(&stake, &governance, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    let mut instruction = set_vote_delegation(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &governance,
+        &vote_delegation,
+        &payer.pubkey(),
+        &Pubkey::new_unique(),
+        None,
+    );
+    instruction.accounts[5].is_signer = false; // Payer not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority], // Payer not signer.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_stake_incorrect_stake_authority() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let vote_delegation =
+        get_vote_delegation_address(&stake, &governance, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    // Set up a stake account with the wrong stake authority address.
+    setup_stake(
+        &mut context,
+        &stake,
+        &Pubkey::new_unique(), // Incorrect stake authority.
+        &validator_vote,
+        0,
+    )
+    .await;
+
+    let instruction = set_vote_delegation(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &governance,
+        &vote_delegation,
+        &context.payer.pubkey(),
+        &Pubkey::new_unique(),
+        None,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+}
+
+#[tokio::test]
+async fn fail_governance_incorrect_address() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let governance = Pubkey::new_unique(); // Incorrect governance address.
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let vote_delegation =
+        get_vote_delegation_address(&stake, &governance, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_stake_config(&mut context, &stake_config, 0).await;
+    setup_governance(
+        &mut context,
+        &governance,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        &stake_config,
+        None,
+        0,
+    )
+    .await;
+
+    let instruction = set_vote_delegation(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &governance,
+        &vote_delegation,
+        &context.payer.pubkey(),
+        &Pubkey::new_unique(),
+        None,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                PaladinGovernanceError::IncorrectGovernanceConfigAddress as u32
+            )
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_expiry_not_in_future() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let vote_delegation =
+        get_vote_delegation_address(&stake, &governance, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_stake_config(&mut context, &stake_config, 0).await;
+    setup_governance(
+        &mut context,
+        &governance,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        &stake_config,
+        None,
+        0,
+    )
+    .await;
+
+    let instruction = set_vote_delegation(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &governance,
+        &vote_delegation,
+        &context.payer.pubkey(),
+        &Pubkey::new_unique(),
+        NonZeroU64::new(clock.unix_timestamp as u64), // Not in the future.
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::InvalidVoteDelegationExpiry as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_vote_delegation_incorrect_address() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let vote_delegation = Pubkey::new_unique(); // Incorrect vote delegation address.
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_stake_config(&mut context, &stake_config, 0).await;
+    setup_governance(
+        &mut context,
+        &governance,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        &stake_config,
+        None,
+        0,
+    )
+    .await;
+
+    let instruction = set_vote_delegation(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &governance,
+        &vote_delegation,
+        &context.payer.pubkey(),
+        &Pubkey::new_unique(),
+        None,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::IncorrectVoteDelegationAddress as u32)
+        )
+    );
+}
+
+#[test_case(None; "no_expiry")]
+#[test_case(Some(86_400); "with_expiry")]
+#[tokio::test]
+async fn success(expires_in_seconds: Option<u64>) {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let delegate = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let vote_delegation =
+        get_vote_delegation_address(&stake, &governance, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    let expiry_timestamp = expires_in_seconds
+        .and_then(|seconds| NonZeroU64::new((clock.unix_timestamp as u64).saturating_add(seconds)));
+
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_stake_config(&mut context, &stake_config, 0).await;
+    setup_governance(
+        &mut context,
+        &governance,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        &stake_config,
+        None,
+        0,
+    )
+    .await;
+
+    // Fund the vote delegation account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(std::mem::size_of::<VoteDelegation>());
+        context.set_account(
+            &vote_delegation,
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
+        );
+    }
+
+    let instruction = set_vote_delegation(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &governance,
+        &vote_delegation,
+        &context.payer.pubkey(),
+        &delegate,
+        expiry_timestamp,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the vote delegation was created.
+    let vote_delegation_account = context
+        .banks_client
+        .get_account(vote_delegation)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        vote_delegation_account.owner,
+        paladin_governance_program::id()
+    );
+    assert_eq!(
+        bytemuck::from_bytes::<VoteDelegation>(&vote_delegation_account.data),
+        &VoteDelegation::new(
+            &delegate,
+            expiry_timestamp,
+            &governance,
+            &stake,
+            &context.payer.pubkey()
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_replace_delegate() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let new_delegate = Pubkey::new_unique();
+    let previous_payer = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let vote_delegation =
+        get_vote_delegation_address(&stake, &governance, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_stake_config(&mut context, &stake_config, 0).await;
+    setup_governance(
+        &mut context,
+        &governance,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        &stake_config,
+        None,
+        0,
+    )
+    .await;
+    setup_vote_delegation_with_payer(
+        &mut context,
+        &vote_delegation,
+        &Pubkey::new_unique(), // Previous delegate.
+        NonZeroU64::new(1),    // Long expired.
+        &governance,
+        &stake,
+        &previous_payer,
+    )
+    .await;
+
+    let instruction = set_vote_delegation(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &governance,
+        &vote_delegation,
+        &context.payer.pubkey(),
+        &new_delegate,
+        None,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the vote delegation was replaced, keeping the payer that funded
+    // it.
+    let vote_delegation_account = context
+        .banks_client
+        .get_account(vote_delegation)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        bytemuck::from_bytes::<VoteDelegation>(&vote_delegation_account.data),
+        &VoteDelegation::new(&new_delegate, None, &governance, &stake, &previous_payer)
+    );
+}
+
+#[tokio::test]
+async fn success_payer_funds_rent() {
+    let stake_authority = Keypair::new();
+    let payer = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let delegate = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let vote_delegation =
+        get_vote_delegation_address(&stake, &governance, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_stake_config(&mut context, &stake_config, 0).await;
+    setup_governance(
+        &mut context,
+        &governance,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        &stake_config,
+        None,
+        0,
+    )
+    .await;
+
+    // Fund the payer, but leave the vote delegation account unfunded.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let rent_exempt_lamports = rent.minimum_balance(std::mem::size_of::<VoteDelegation>());
+    let payer_lamports = 1_000_000_000;
+    context.set_account(
+        &payer.pubkey(),
+        &AccountSharedData::new(payer_lamports, 0, &system_program::id()),
+    );
+
+    let instruction = set_vote_delegation(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &governance,
+        &vote_delegation,
+        &payer.pubkey(),
+        &delegate,
+        None,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority, &payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the payer funded the vote delegation account's rent exemption.
+    let vote_delegation_account = context
+        .banks_client
+        .get_account(vote_delegation)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(vote_delegation_account.lamports, rent_exempt_lamports);
+    assert_eq!(
+        context
+            .banks_client
+            .get_balance(payer.pubkey())
+            .await
+            .unwrap(),
+        payer_lamports - rent_exempt_lamports
+    );
+    assert_eq!(
+        bytemuck::from_bytes::<VoteDelegation>(&vote_delegation_account.data),
+        &VoteDelegation::new(&delegate, None, &governance, &stake, &payer.pubkey())
+    );
+}
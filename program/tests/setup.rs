@@ -3,18 +3,24 @@
 
 use {
     borsh::BorshSerialize,
+    bytemuck::Zeroable,
     paladin_governance_program::state::{
-        Config, Proposal, ProposalAccountMeta, ProposalInstruction, ProposalStatus,
-        ProposalTransaction, ProposalVote, ProposalVoteElection,
+        Config, PeriodBounds, Proposal, ProposalAccountMeta, ProposalInstruction, ProposalStatus,
+        ProposalTransaction, ProposalVote, ProposalVoteElection, ProposalVoteV0, ProposalVoteV1,
+        ProposalVoteV2, VoteDelegation,
     },
     paladin_stake_program::state::{Config as StakeConfig, Stake},
     solana_program_test::*,
     solana_sdk::{
         account::{Account, AccountSharedData},
+        bpf_loader_upgradeable::{self, get_program_data_address, UpgradeableLoaderState},
         clock::UnixTimestamp,
+        program_pack::Pack,
         pubkey::Pubkey,
     },
     spl_discriminator::SplDiscriminate,
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+    spl_token_2022::state::{Account as TokenAccount, AccountState, Mint},
     std::num::NonZeroU64,
 };
 
@@ -55,9 +61,34 @@ pub async fn setup_stake_config(
     context: &mut ProgramTestContext,
     stake_config_address: &Pubkey,
     total_stake: u64,
+) {
+    _setup_stake_config_inner(context, stake_config_address, None, total_stake).await;
+}
+
+pub async fn setup_stake_config_with_authority(
+    context: &mut ProgramTestContext,
+    stake_config_address: &Pubkey,
+    authority_address: &Pubkey,
+    total_stake: u64,
+) {
+    _setup_stake_config_inner(
+        context,
+        stake_config_address,
+        Some(authority_address),
+        total_stake,
+    )
+    .await;
+}
+
+async fn _setup_stake_config_inner(
+    context: &mut ProgramTestContext,
+    stake_config_address: &Pubkey,
+    authority_address: Option<&Pubkey>,
+    total_stake: u64,
 ) {
     let state = StakeConfig {
         discriminator: StakeConfig::SPL_DISCRIMINATOR.into(),
+        authority: OptionalNonZeroPubkey(authority_address.copied().unwrap_or_default()),
         token_amount_delegated: total_stake,
         ..Default::default()
     };
@@ -77,23 +108,92 @@ pub async fn setup_stake_config(
     );
 }
 
+pub async fn setup_program_data(
+    context: &mut ProgramTestContext,
+    upgrade_authority_address: Option<&Pubkey>,
+) {
+    let program_data_address = get_program_data_address(&paladin_governance_program::id());
+
+    // Serialized `UpgradeableLoaderState::ProgramData`, with no program bytes.
+    let mut data = vec![0; UpgradeableLoaderState::size_of_programdata_metadata()];
+    data[0..4].copy_from_slice(&3u32.to_le_bytes());
+    if let Some(upgrade_authority_address) = upgrade_authority_address {
+        data[12] = 1;
+        data[13..45].copy_from_slice(upgrade_authority_address.as_ref());
+    }
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(data.len());
+
+    context.set_account(
+        &program_data_address,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: bpf_loader_upgradeable::id(),
+            ..Account::default()
+        }),
+    );
+}
+
 pub async fn setup_governance(
     context: &mut ProgramTestContext,
     governance_address: &Pubkey,
     cooldown_period_seconds: u64,
+    execution_window_seconds: u64,
+    proposal_acceptance_threshold: u32,
+    proposal_minimum_stake: u64,
+    proposal_rejection_threshold: u32,
+    quorum_threshold: u32,
+    stake_config_address: &Pubkey,
+    veto_authority: Option<&Pubkey>,
+    voting_period_seconds: u64,
+) {
+    setup_governance_with_period_bounds(
+        context,
+        governance_address,
+        cooldown_period_seconds,
+        execution_window_seconds,
+        proposal_acceptance_threshold,
+        proposal_minimum_stake,
+        proposal_rejection_threshold,
+        quorum_threshold,
+        stake_config_address,
+        veto_authority,
+        voting_period_seconds,
+        PeriodBounds::default(),
+    )
+    .await;
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn setup_governance_with_period_bounds(
+    context: &mut ProgramTestContext,
+    governance_address: &Pubkey,
+    cooldown_period_seconds: u64,
+    execution_window_seconds: u64,
     proposal_acceptance_threshold: u32,
+    proposal_minimum_stake: u64,
     proposal_rejection_threshold: u32,
+    quorum_threshold: u32,
     stake_config_address: &Pubkey,
+    veto_authority: Option<&Pubkey>,
     voting_period_seconds: u64,
+    period_bounds: PeriodBounds,
 ) {
-    let state = Config::new(
+    let mut state = Config::new(
         cooldown_period_seconds,
+        execution_window_seconds,
         proposal_acceptance_threshold,
+        proposal_minimum_stake,
         proposal_rejection_threshold,
+        quorum_threshold,
         /* signer_bump_seed */ 0, // TODO: Unused right now.
         stake_config_address,
+        veto_authority,
         voting_period_seconds,
     );
+    state.period_bounds = period_bounds;
     let data = bytemuck::bytes_of(&state).to_vec();
 
     let rent = context.banks_client.get_rent().await.unwrap();
@@ -120,6 +220,7 @@ async fn _setup_proposal_inner(
     stake_for: u64,
     stake_against: u64,
     stake_abstained: u64,
+    total_stake: u64,
     status: ProposalStatus,
     voting_start_timestamp: Option<NonZeroU64>,
     cooldown_timestamp: Option<NonZeroU64>,
@@ -129,6 +230,7 @@ async fn _setup_proposal_inner(
     state.stake_for = stake_for;
     state.stake_against = stake_against;
     state.stake_abstained = stake_abstained;
+    state.total_stake = total_stake;
     state.status = status;
     state.voting_start_timestamp = voting_start_timestamp;
 
@@ -158,6 +260,7 @@ pub async fn setup_proposal_with_stake_and_cooldown(
     stake_for: u64,
     stake_against: u64,
     stake_abstained: u64,
+    total_stake: u64,
     status: ProposalStatus,
     voting_start_timestamp: Option<NonZeroU64>,
     cooldown_timestamp: Option<NonZeroU64>,
@@ -171,6 +274,7 @@ pub async fn setup_proposal_with_stake_and_cooldown(
         stake_for,
         stake_against,
         stake_abstained,
+        total_stake,
         status,
         voting_start_timestamp,
         cooldown_timestamp,
@@ -188,6 +292,7 @@ pub async fn setup_proposal_with_stake(
     stake_for: u64,
     stake_against: u64,
     stake_abstained: u64,
+    total_stake: u64,
     status: ProposalStatus,
     voting_start_timestamp: Option<NonZeroU64>,
 ) {
@@ -200,6 +305,7 @@ pub async fn setup_proposal_with_stake(
         stake_for,
         stake_against,
         stake_abstained,
+        total_stake,
         status,
         voting_start_timestamp,
         None,
@@ -224,6 +330,7 @@ pub async fn setup_proposal(
         0,
         0,
         0,
+        0,
         status,
         None,
     )
@@ -253,16 +360,129 @@ pub async fn setup_proposal_transaction(
 }
 
 pub async fn setup_proposal_vote(
+    context: &mut ProgramTestContext,
+    proposal_vote_address: &Pubkey,
+    proposal_address: &Pubkey,
+    stake: u64,
+    stake_address: &Pubkey,
+    stake_authority_address: &Pubkey,
+    election: ProposalVoteElection,
+) {
+    setup_proposal_vote_with_payer(
+        context,
+        proposal_vote_address,
+        proposal_address,
+        stake,
+        stake_address,
+        stake_authority_address,
+        /* payer_address */ stake_authority_address,
+        election,
+    )
+    .await;
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn setup_proposal_vote_with_payer(
+    context: &mut ProgramTestContext,
+    proposal_vote_address: &Pubkey,
+    proposal_address: &Pubkey,
+    stake: u64,
+    stake_address: &Pubkey,
+    stake_authority_address: &Pubkey,
+    payer_address: &Pubkey,
+    election: ProposalVoteElection,
+) {
+    let state = ProposalVote::new(
+        proposal_address,
+        stake,
+        stake_address,
+        stake_authority_address,
+        payer_address,
+        None,
+        election,
+    );
+    let data = bytemuck::bytes_of(&state).to_vec();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(data.len());
+
+    context.set_account(
+        proposal_vote_address,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: paladin_governance_program::id(),
+            ..Account::default()
+        }),
+    );
+}
+
+pub async fn setup_proposal_vote_v0(
     context: &mut ProgramTestContext,
     proposal_vote_address: &Pubkey,
     proposal_address: &Pubkey,
     stake: u64,
     stake_authority_address: &Pubkey,
     election: ProposalVoteElection,
+    lamports: u64,
 ) {
-    let state = ProposalVote::new(proposal_address, stake, stake_authority_address, election);
+    let state = ProposalVoteV0::new(proposal_address, stake, stake_authority_address, election);
     let data = bytemuck::bytes_of(&state).to_vec();
 
+    context.set_account(
+        proposal_vote_address,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: paladin_governance_program::id(),
+            ..Account::default()
+        }),
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn setup_legacy_proposal_vote(
+    context: &mut ProgramTestContext,
+    proposal_vote_address: &Pubkey,
+    proposal_address: &Pubkey,
+    stake: u64,
+    stake_address: &Pubkey,
+    authority_address: &Pubkey,
+    election: ProposalVoteElection,
+    version: u8,
+) {
+    let data = match version {
+        0 => bytemuck::bytes_of(&ProposalVoteV0::new(
+            proposal_address,
+            stake,
+            authority_address,
+            election,
+        ))
+        .to_vec(),
+        1 => {
+            let mut state = ProposalVoteV1::zeroed();
+            state.proposal_address = *proposal_address;
+            state.stake = stake;
+            state.stake_address = *stake_address;
+            state.authority_address = *authority_address;
+            state.election = election;
+            state.version = 1;
+            bytemuck::bytes_of(&state).to_vec()
+        }
+        2 => {
+            let mut state = ProposalVoteV2::zeroed();
+            state.discriminator = ProposalVote::SPL_DISCRIMINATOR.into();
+            state.proposal_address = *proposal_address;
+            state.stake = stake;
+            state.stake_address = *stake_address;
+            state.authority_address = *authority_address;
+            state.election = election;
+            state.version = 2;
+            bytemuck::bytes_of(&state).to_vec()
+        }
+        _ => panic!("unknown proposal vote layout version {version}"),
+    };
+
     let rent = context.banks_client.get_rent().await.unwrap();
     let lamports = rent.minimum_balance(data.len());
 
@@ -277,6 +497,139 @@ pub async fn setup_proposal_vote(
     );
 }
 
+pub async fn setup_legacy_account<T: bytemuck::Pod>(
+    context: &mut ProgramTestContext,
+    address: &Pubkey,
+    state: &T,
+    lamports: u64,
+) {
+    let data = bytemuck::bytes_of(state).to_vec();
+
+    context.set_account(
+        address,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: paladin_governance_program::id(),
+            ..Account::default()
+        }),
+    );
+}
+
+pub async fn setup_vote_delegation(
+    context: &mut ProgramTestContext,
+    vote_delegation_address: &Pubkey,
+    delegate_address: &Pubkey,
+    expiry_timestamp: Option<NonZeroU64>,
+    governance_config_address: &Pubkey,
+    stake_address: &Pubkey,
+) {
+    setup_vote_delegation_with_payer(
+        context,
+        vote_delegation_address,
+        delegate_address,
+        expiry_timestamp,
+        governance_config_address,
+        stake_address,
+        /* payer_address */ &Pubkey::new_unique(),
+    )
+    .await;
+}
+
+pub async fn setup_vote_delegation_with_payer(
+    context: &mut ProgramTestContext,
+    vote_delegation_address: &Pubkey,
+    delegate_address: &Pubkey,
+    expiry_timestamp: Option<NonZeroU64>,
+    governance_config_address: &Pubkey,
+    stake_address: &Pubkey,
+    payer_address: &Pubkey,
+) {
+    let state = VoteDelegation::new(
+        delegate_address,
+        expiry_timestamp,
+        governance_config_address,
+        stake_address,
+        payer_address,
+    );
+    let data = bytemuck::bytes_of(&state).to_vec();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(data.len());
+
+    context.set_account(
+        vote_delegation_address,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: paladin_governance_program::id(),
+            ..Account::default()
+        }),
+    );
+}
+
+pub async fn setup_mint(
+    context: &mut ProgramTestContext,
+    mint_address: &Pubkey,
+    token_program_id: &Pubkey,
+    decimals: u8,
+    supply: u64,
+) {
+    let state = Mint {
+        supply,
+        decimals,
+        is_initialized: true,
+        ..Mint::default()
+    };
+    let mut data = vec![0; Mint::LEN];
+    Mint::pack(state, &mut data).unwrap();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(data.len());
+
+    context.set_account(
+        mint_address,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: *token_program_id,
+            ..Account::default()
+        }),
+    );
+}
+
+pub async fn setup_token_account(
+    context: &mut ProgramTestContext,
+    token_account_address: &Pubkey,
+    token_program_id: &Pubkey,
+    mint_address: &Pubkey,
+    owner_address: &Pubkey,
+    amount: u64,
+) {
+    let state = TokenAccount {
+        mint: *mint_address,
+        owner: *owner_address,
+        amount,
+        state: AccountState::Initialized,
+        ..TokenAccount::default()
+    };
+    let mut data = vec![0; TokenAccount::LEN];
+    TokenAccount::pack(state, &mut data).unwrap();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(data.len());
+
+    context.set_account(
+        token_account_address,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: *token_program_id,
+            ..Account::default()
+        }),
+    );
+}
+
 pub fn create_mock_proposal_transaction(program_ids: &[&Pubkey]) -> ProposalTransaction {
     let mut instructions = Vec::new();
     for instruction_program_id in program_ids {
@@ -299,5 +652,5 @@ pub fn create_mock_proposal_transaction(program_ids: &[&Pubkey]) -> ProposalTran
             instruction_data,
         ));
     }
-    ProposalTransaction { instructions }
+    ProposalTransaction::new(instructions)
 }
@@ -5,15 +5,17 @@ mod setup;
 use {
     paladin_governance_program::{
         error::PaladinGovernanceError,
+        instruction::switch_vote_as_delegate,
         state::{
-            get_proposal_vote_address, Config, Proposal, ProposalStatus, ProposalVote,
-            ProposalVoteElection,
+            get_governance_address, get_proposal_vote_address, get_vote_delegation_address, Config,
+            Proposal, ProposalStatus, ProposalVote, ProposalVoteElection,
         },
     },
     paladin_stake_program::state::{find_stake_pda, Config as StakeConfig, Stake},
     setup::{
-        setup, setup_proposal, setup_proposal_vote, setup_proposal_with_stake,
-        setup_proposal_with_stake_and_cooldown, setup_stake, setup_stake_config,
+        setup, setup_legacy_proposal_vote, setup_proposal, setup_proposal_vote,
+        setup_proposal_with_stake, setup_proposal_with_stake_and_cooldown, setup_stake,
+        setup_stake_config, setup_vote_delegation,
     },
     solana_program_test::*,
     solana_sdk::{
@@ -507,10 +509,14 @@ async fn fail_proposal_not_voting() {
 
     let governance_config = Config::new(
         /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
         /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
         /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
         /* signer_bump_seed */ 0,
         /* stake_config_address */ &stake_config,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 0,
     );
 
@@ -578,10 +584,14 @@ async fn fail_proposal_vote_incorrect_address() {
 
     let governance_config = Config::new(
         /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
         /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
         /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
         /* signer_bump_seed */ 0,
         /* stake_config_address */ &stake_config,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 0,
     );
 
@@ -650,10 +660,14 @@ async fn fail_proposal_vote_not_initialized() {
 
     let governance_config = Config::new(
         /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
         /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
         /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
         /* signer_bump_seed */ 0,
         /* stake_config_address */ &stake_config,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 0,
     );
 
@@ -716,6 +730,166 @@ async fn fail_proposal_vote_not_initialized() {
     );
 }
 
+#[test_case(0; "v0")]
+#[test_case(1; "v1")]
+#[test_case(2; "v2")]
+#[tokio::test]
+async fn fail_proposal_vote_not_migrated(version: u8) {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        /* stake_config_address */ &stake_config,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 0,
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_stake_config(&mut context, &stake_config, 0).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        governance_config,
+        ProposalStatus::Voting,
+    )
+    .await;
+
+    // Set up a proposal vote account with a previous layout.
+    setup_legacy_proposal_vote(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        0,
+        &stake,
+        &stake_authority.pubkey(),
+        ProposalVoteElection::Against,
+        version,
+    )
+    .await;
+
+    let instruction = paladin_governance_program::instruction::switch_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        ProposalVoteElection::For,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::AccountVersionMismatch as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_vote_delegation_expired() {
+    let delegate = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let vote_delegation =
+        get_vote_delegation_address(&stake, &governance, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake(
+        &mut context,
+        &stake,
+        &Pubkey::new_unique(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_vote_delegation(
+        &mut context,
+        &vote_delegation,
+        &delegate.pubkey(),
+        NonZeroU64::new(clock.unix_timestamp as u64), // Expired.
+        &governance,
+        &stake,
+    )
+    .await;
+
+    let instruction = switch_vote_as_delegate(
+        &delegate.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &vote_delegation,
+        ProposalVoteElection::For,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::VoteDelegationExpired as u32)
+        )
+    );
+}
+
 const ACCEPTANCE_THRESHOLD: u32 = 500_000_000; // 50%
 const REJECTION_THRESHOLD: u32 = 500_000_000; // 50%
 const COOLDOWN_PERIOD_SECONDS: u64 = 100_000_000;
@@ -744,11 +918,6 @@ enum Expect {
     Terminated,
 }
 
-// TODO: Until we decide whether or not to keep the cooldown running, or to
-// reset it when acceptance dips below the threshold, the test case is missing
-// for determining, not just that a cooldown exists post-vost-switch, but that
-// it was in fact reset with a new timestamp.
-
 #[test_case(
     ProposalStarting {
         cooldown_active: false,
@@ -989,12 +1158,75 @@ enum Expect {
         new_election: ProposalVoteElection::Against,
     },
     Expect::Cast {
-        cooldown: true, // Cooldown unchanged.
+        cooldown: false, // Cooldown cleared.
         stake_for: TOTAL_STAKE / 2 - TOTAL_STAKE / 10, // 40% of total stake.
         stake_against: TOTAL_STAKE / 4 + TOTAL_STAKE / 10, // 35% of total stake.
         stake_abstained: TOTAL_STAKE / 4, // Unchanged.
     };
-    "for_to_against_below_for_threshold_deducts_stake_for_increments_stake_against"
+    "for_to_against_below_for_threshold_deducts_stake_for_increments_stake_against_clears_cooldown"
+)]
+#[test_case(
+    ProposalStarting {
+        cooldown_active: true, // Cooldown active.
+        stake_for: TOTAL_STAKE / 5 * 3, // 60% of total stake.
+        stake_against: TOTAL_STAKE / 5, // 20% of total stake.
+        stake_abstained: TOTAL_STAKE / 5, // 20% of total stake.
+    },
+    VoteSwitch {
+        previous_vote_stake: TOTAL_STAKE / 10, // 10% of total stake.
+        new_vote_stake: TOTAL_STAKE / 10, // 10% of total stake.
+        previous_election: ProposalVoteElection::For,
+        new_election: ProposalVoteElection::Against,
+    },
+    Expect::Cast {
+        cooldown: true, // Cooldown unchanged.
+        stake_for: TOTAL_STAKE / 5 * 3 - TOTAL_STAKE / 10, // 50% of total stake.
+        stake_against: TOTAL_STAKE / 5 + TOTAL_STAKE / 10, // 30% of total stake.
+        stake_abstained: TOTAL_STAKE / 5, // Unchanged.
+    };
+    "for_to_against_above_for_threshold_deducts_stake_for_increments_stake_against_keeps_cooldown"
+)]
+#[test_case(
+    ProposalStarting {
+        cooldown_active: true, // Cooldown active.
+        stake_for: TOTAL_STAKE / 2, // 50% of total stake.
+        stake_against: TOTAL_STAKE / 4, // 25% of total stake.
+        stake_abstained: TOTAL_STAKE / 4, // 25% of total stake.
+    },
+    VoteSwitch {
+        previous_vote_stake: TOTAL_STAKE / 10, // 10% of total stake.
+        new_vote_stake: TOTAL_STAKE / 10, // 10% of total stake.
+        previous_election: ProposalVoteElection::For,
+        new_election: ProposalVoteElection::DidNotVote,
+    },
+    Expect::Cast {
+        cooldown: false, // Cooldown cleared.
+        stake_for: TOTAL_STAKE / 2 - TOTAL_STAKE / 10, // 40% of total stake.
+        stake_against: TOTAL_STAKE / 4, // Unchanged.
+        stake_abstained: TOTAL_STAKE / 4 + TOTAL_STAKE / 10, // 35% of total stake.
+    };
+    "for_to_dnv_below_for_threshold_deducts_stake_for_increments_stake_abstained_clears_cooldown"
+)]
+#[test_case(
+    ProposalStarting {
+        cooldown_active: true, // Cooldown active.
+        stake_for: TOTAL_STAKE / 2, // 50% of total stake.
+        stake_against: TOTAL_STAKE / 4, // 25% of total stake.
+        stake_abstained: TOTAL_STAKE / 4, // 25% of total stake.
+    },
+    VoteSwitch {
+        previous_vote_stake: TOTAL_STAKE / 10, // 10% of total stake.
+        new_vote_stake: TOTAL_STAKE / 20, // 5% of total stake.
+        previous_election: ProposalVoteElection::For,
+        new_election: ProposalVoteElection::For,
+    },
+    Expect::Cast {
+        cooldown: false, // Cooldown cleared.
+        stake_for: TOTAL_STAKE / 2 - TOTAL_STAKE / 20, // 45% of total stake.
+        stake_against: TOTAL_STAKE / 4, // Unchanged.
+        stake_abstained: TOTAL_STAKE / 4, // Unchanged.
+    };
+    "for_to_for_less_stake_below_for_threshold_decrements_stake_for_clears_cooldown"
 )]
 #[test_case(
     ProposalStarting {
@@ -1172,10 +1404,14 @@ async fn success(proposal_starting: ProposalStarting, switch: VoteSwitch, expect
 
     let governance_config = Config::new(
         COOLDOWN_PERIOD_SECONDS,
+        /* execution_window_seconds */ 0,
         ACCEPTANCE_THRESHOLD,
+        /* proposal_minimum_stake */ 0,
         REJECTION_THRESHOLD,
+        /* quorum_threshold */ 0,
         /* signer_bump_seed */ 0,
         &stake_config,
+        /* veto_authority */ None,
         VOTING_PERIOD_SECONDS,
     );
 
@@ -1198,6 +1434,7 @@ async fn success(proposal_starting: ProposalStarting, switch: VoteSwitch, expect
         &proposal_vote,
         &proposal,
         switch.previous_vote_stake,
+        &stake,
         &stake_authority.pubkey(),
         switch.previous_election,
     )
@@ -1214,6 +1451,7 @@ async fn success(proposal_starting: ProposalStarting, switch: VoteSwitch, expect
             proposal_starting.stake_for,
             proposal_starting.stake_against,
             proposal_starting.stake_abstained,
+            /* total_stake */ TOTAL_STAKE,
             ProposalStatus::Voting,
             /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
             /* cooldown_timestamp */
@@ -1230,6 +1468,7 @@ async fn success(proposal_starting: ProposalStarting, switch: VoteSwitch, expect
             proposal_starting.stake_for,
             proposal_starting.stake_against,
             proposal_starting.stake_abstained,
+            /* total_stake */ TOTAL_STAKE,
             ProposalStatus::Voting,
             /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
         )
@@ -1265,13 +1504,18 @@ async fn success(proposal_starting: ProposalStarting, switch: VoteSwitch, expect
         .await
         .unwrap()
         .unwrap();
+    let proposal_vote_state = bytemuck::from_bytes::<ProposalVote>(&vote_account.data);
+    assert!(proposal_vote_state.vote_timestamp.is_some());
     assert_eq!(
-        bytemuck::from_bytes::<ProposalVote>(&vote_account.data),
+        proposal_vote_state,
         &ProposalVote::new(
             &proposal,
             switch.new_vote_stake,
+            &stake,
+            &stake_authority.pubkey(),
             &stake_authority.pubkey(),
-            switch.new_election
+            proposal_vote_state.vote_timestamp,
+            switch.new_election,
         )
     );
 
@@ -1329,10 +1573,14 @@ async fn success_voting_closed() {
 
     let governance_config = Config::new(
         /* cooldown_period_seconds */ 10,
+        /* execution_window_seconds */ 0,
         ACCEPTANCE_THRESHOLD,
+        /* proposal_minimum_stake */ 0,
         REJECTION_THRESHOLD,
+        /* quorum_threshold */ 0,
         /* signer_bump_seed */ 0,
         &stake_config,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 10,
     );
 
@@ -1359,6 +1607,7 @@ async fn success_voting_closed() {
         /* stake_for */ 0,
         /* stake_against */ TOTAL_STAKE,
         /* stake_abstained */ 0,
+        /* total_stake */ TOTAL_STAKE,
         ProposalStatus::Voting,
         /* voting_start_timestamp */ NonZeroU64::new(1), // Wayyy earlier.
     )
@@ -1369,6 +1618,7 @@ async fn success_voting_closed() {
         &proposal_vote,
         &proposal,
         prev_vote_stake,
+        &stake,
         &stake_authority.pubkey(),
         prev_election,
     )
@@ -1442,10 +1692,14 @@ async fn success_voting_closed_but_cooldown_active() {
 
     let governance_config = Config::new(
         /* cooldown_period_seconds */ 1_000,
+        /* execution_window_seconds */ 0,
         ACCEPTANCE_THRESHOLD,
+        /* proposal_minimum_stake */ 0,
         REJECTION_THRESHOLD,
+        /* quorum_threshold */ 0,
         /* signer_bump_seed */ 0,
         &stake_config,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 10,
     );
 
@@ -1475,6 +1729,7 @@ async fn success_voting_closed_but_cooldown_active() {
         /* stake_for */ TOTAL_STAKE,
         /* stake_against */ 0,
         /* stake_abstained */ 0,
+        /* total_stake */ TOTAL_STAKE,
         ProposalStatus::Voting,
         /* voting_start_timestamp */ NonZeroU64::new(1), // Wayyy earlier.
         /* cooldown_timestamp */
@@ -1487,6 +1742,7 @@ async fn success_voting_closed_but_cooldown_active() {
         &proposal_vote,
         &proposal,
         prev_vote_stake,
+        &stake,
         &stake_authority.pubkey(),
         prev_election,
     )
@@ -1538,6 +1794,121 @@ async fn success_voting_closed_but_cooldown_active() {
     assert_eq!(proposal_vote_state.election, new_election);
 }
 
+#[tokio::test]
+async fn success_cooldown_restarts_when_support_recovers() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let vote_stake = TOTAL_STAKE / 10;
+
+    let governance_config = Config::new(
+        COOLDOWN_PERIOD_SECONDS,
+        /* execution_window_seconds */ 0,
+        ACCEPTANCE_THRESHOLD,
+        /* proposal_minimum_stake */ 0,
+        REJECTION_THRESHOLD,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        &stake_config,
+        /* veto_authority */ None,
+        VOTING_PERIOD_SECONDS,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let original_cooldown_timestamp =
+        NonZeroU64::new(clock.unix_timestamp.saturating_sub(100) as u64);
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        vote_stake,
+    )
+    .await;
+
+    // Set up a proposal sitting exactly at the acceptance threshold, with an
+    // active cooldown period.
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        /* stake_for */ TOTAL_STAKE / 2,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ TOTAL_STAKE,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+        original_cooldown_timestamp,
+    )
+    .await;
+
+    setup_proposal_vote(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        vote_stake,
+        &stake,
+        &stake_authority.pubkey(),
+        ProposalVoteElection::For,
+    )
+    .await;
+
+    // Switch away from the vote in favor, dropping support below the
+    // acceptance threshold, then back again.
+    for election in [ProposalVoteElection::DidNotVote, ProposalVoteElection::For] {
+        let instruction = paladin_governance_program::instruction::switch_vote(
+            &stake_authority.pubkey(),
+            &stake,
+            &stake_config,
+            &proposal_vote,
+            &proposal,
+            election,
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &stake_authority],
+            context.last_blockhash,
+        );
+
+        context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+            .unwrap();
+
+        let proposal_account = context
+            .banks_client
+            .get_account(proposal)
+            .await
+            .unwrap()
+            .unwrap();
+        let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+        assert_eq!(proposal_state.status, ProposalStatus::Voting);
+
+        if election == ProposalVoteElection::DidNotVote {
+            // Assert the cooldown period was cleared.
+            assert!(proposal_state.cooldown_timestamp.is_none());
+        } else {
+            // Assert a new cooldown period began, rather than the original
+            // resuming.
+            assert!(proposal_state.cooldown_timestamp > original_cooldown_timestamp);
+        }
+    }
+}
+
 #[tokio::test]
 async fn success_cooldown_has_ended() {
     let stake_authority = Keypair::new();
@@ -1557,10 +1928,14 @@ async fn success_cooldown_has_ended() {
 
     let governance_config = Config::new(
         /* cooldown_period_seconds */ 10,
+        /* execution_window_seconds */ 1_000,
         ACCEPTANCE_THRESHOLD,
+        /* proposal_minimum_stake */ 0,
         REJECTION_THRESHOLD,
+        /* quorum_threshold */ 0,
         /* signer_bump_seed */ 0,
         &stake_config,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 1_000,
     );
 
@@ -1588,6 +1963,7 @@ async fn success_cooldown_has_ended() {
         /* stake_for */ TOTAL_STAKE,
         /* stake_against */ 0,
         /* stake_abstained */ 0,
+        /* total_stake */ TOTAL_STAKE,
         ProposalStatus::Voting,
         /* voting_start_timestamp */
         NonZeroU64::new(clock.unix_timestamp as u64),
@@ -1601,6 +1977,7 @@ async fn success_cooldown_has_ended() {
         &proposal_vote,
         &proposal,
         prev_vote_stake,
+        &stake,
         &stake_authority.pubkey(),
         prev_election,
     )
@@ -1651,3 +2028,328 @@ async fn success_cooldown_has_ended() {
     assert_eq!(proposal_vote_state.stake, prev_vote_stake);
     assert_eq!(proposal_vote_state.election, prev_election);
 }
+
+#[tokio::test]
+async fn success_as_delegate() {
+    let stake_authority = Pubkey::new_unique();
+    let delegate = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let vote_delegation =
+        get_vote_delegation_address(&stake, &governance, &paladin_governance_program::id());
+
+    let vote_stake = TOTAL_STAKE / 10; // 10% of total stake.
+
+    let governance_config = Config::new(
+        COOLDOWN_PERIOD_SECONDS,
+        /* execution_window_seconds */ 0,
+        ACCEPTANCE_THRESHOLD,
+        /* proposal_minimum_stake */ 0,
+        REJECTION_THRESHOLD,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        &stake_config,
+        /* veto_authority */ None,
+        VOTING_PERIOD_SECONDS,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority,
+        &validator_vote,
+        vote_stake,
+    )
+    .await;
+    setup_vote_delegation(
+        &mut context,
+        &vote_delegation,
+        &delegate.pubkey(),
+        None,
+        &governance,
+        &stake,
+    )
+    .await;
+    setup_proposal_vote(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        vote_stake,
+        &stake,
+        &stake_authority,
+        ProposalVoteElection::Against,
+    )
+    .await;
+    setup_proposal_with_stake(
+        &mut context,
+        &proposal,
+        &stake_authority,
+        0,
+        governance_config,
+        /* stake_for */ 0,
+        /* stake_against */ vote_stake,
+        /* stake_abstained */ 0,
+        /* total_stake */ TOTAL_STAKE,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+    )
+    .await;
+
+    let instruction = switch_vote_as_delegate(
+        &delegate.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &vote_delegation,
+        ProposalVoteElection::For,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the vote was updated, and attributed to the delegate.
+    let vote_account = context
+        .banks_client
+        .get_account(proposal_vote)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_vote_state = bytemuck::from_bytes::<ProposalVote>(&vote_account.data);
+    assert!(proposal_vote_state.vote_timestamp.is_some());
+    assert_eq!(
+        proposal_vote_state,
+        &ProposalVote::new(
+            &proposal,
+            vote_stake,
+            &stake,
+            &delegate.pubkey(),
+            &stake_authority,
+            proposal_vote_state.vote_timestamp,
+            ProposalVoteElection::For,
+        )
+    );
+
+    // Assert the proposal stake was moved.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.stake_for, vote_stake);
+    assert_eq!(proposal_state.stake_against, 0);
+}
+
+#[test_case(false; "voting_period_ended")]
+#[test_case(true; "cooldown_period_ended")]
+#[tokio::test]
+async fn success_concluded_quorum_not_met(cooldown_ended: bool) {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    // The quorum threshold exceeds the acceptance threshold, so a proposal
+    // can begin a cooldown period without enough participation.
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 10,
+        /* execution_window_seconds */ 1_000,
+        /* proposal_acceptance_threshold */ 200_000_000, // 20%
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 500_000_000, // 50%
+        /* quorum_threshold */ 600_000_000, // 60%
+        /* signer_bump_seed */ 0,
+        &stake_config,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 10,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        TOTAL_STAKE / 10,
+    )
+    .await;
+
+    // Either the cooldown period has ended with 30% of the stake in favor, or
+    // the voting period has ended with 10% of the stake in favor. Neither
+    // meets the 60% quorum.
+    let (stake_for, voting_start_timestamp, cooldown_timestamp) = if cooldown_ended {
+        (
+            TOTAL_STAKE / 10 * 3,
+            NonZeroU64::new(clock.unix_timestamp as u64),
+            NonZeroU64::new(clock.unix_timestamp.saturating_sub(10) as u64),
+        )
+    } else {
+        (TOTAL_STAKE / 10, NonZeroU64::new(1), None)
+    };
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        stake_for,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ TOTAL_STAKE,
+        ProposalStatus::Voting,
+        voting_start_timestamp,
+        cooldown_timestamp,
+    )
+    .await;
+
+    let instruction = paladin_governance_program::instruction::switch_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        ProposalVoteElection::For,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal concluded without meeting quorum.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::QuorumNotMet);
+}
+
+#[tokio::test]
+async fn success_execution_window_ended() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 10,
+        /* execution_window_seconds */ 10,
+        ACCEPTANCE_THRESHOLD,
+        /* proposal_minimum_stake */ 0,
+        REJECTION_THRESHOLD,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        &stake_config,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 1_000,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        TOTAL_STAKE / 10,
+    )
+    .await;
+
+    // Set up a proposal whose cooldown period and execution window have both
+    // ended.
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        /* stake_for */ TOTAL_STAKE,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ TOTAL_STAKE,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */
+        NonZeroU64::new(clock.unix_timestamp as u64),
+        /* cooldown_timestamp */
+        NonZeroU64::new(clock.unix_timestamp.saturating_sub(20) as u64), // Now - 20 seconds.
+    )
+    .await;
+
+    let instruction = paladin_governance_program::instruction::switch_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        ProposalVoteElection::For,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal expired rather than being accepted.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::Expired);
+}
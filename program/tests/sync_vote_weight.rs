@@ -0,0 +1,691 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::sync_vote_weight,
+        state::{
+            get_proposal_vote_address, Config, Proposal, ProposalStatus, ProposalVote,
+            ProposalVoteElection,
+        },
+    },
+    paladin_stake_program::state::find_stake_pda,
+    setup::{
+        setup, setup_legacy_proposal_vote, setup_proposal, setup_proposal_vote,
+        setup_proposal_with_stake, setup_proposal_with_stake_and_cooldown, setup_stake,
+        setup_stake_config,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        clock::Clock,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    std::num::NonZeroU64,
+    test_case::test_case,
+};
+
+const ACCEPTANCE_THRESHOLD: u32 = 500_000_000; // 50%
+const REJECTION_THRESHOLD: u32 = 500_000_000; // 50%
+const COOLDOWN_PERIOD_SECONDS: u64 = 100_000_000;
+const VOTING_PERIOD_SECONDS: u64 = 100_000_000;
+const TOTAL_STAKE: u64 = 100_000_000;
+
+fn governance_config(stake_config: &Pubkey) -> Config {
+    Config::new(
+        COOLDOWN_PERIOD_SECONDS,
+        /* execution_window_seconds */ 0,
+        ACCEPTANCE_THRESHOLD,
+        /* proposal_minimum_stake */ 0,
+        REJECTION_THRESHOLD,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        stake_config,
+        /* veto_authority */ None,
+        VOTING_PERIOD_SECONDS,
+    )
+}
+
+#[tokio::test]
+async fn fail_proposal_not_voting() {
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &Pubkey::new_unique(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        0,
+        governance_config(&stake_config),
+        ProposalStatus::Accepted, // Not voting.
+    )
+    .await;
+
+    let instruction = sync_vote_weight(&stake, &stake_config, &proposal_vote, &proposal);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::ProposalNotInVotingStage as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_vote_incorrect_address() {
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let proposal_vote = Pubkey::new_unique(); // Incorrect proposal vote address.
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &Pubkey::new_unique(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_proposal_with_stake(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        0,
+        governance_config(&stake_config),
+        /* stake_for */ 0,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ TOTAL_STAKE,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+    )
+    .await;
+
+    let instruction = sync_vote_weight(&stake, &stake_config, &proposal_vote, &proposal);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::IncorrectProposalVoteAddress as u32)
+        )
+    );
+}
+
+#[test_case(0; "v0")]
+#[test_case(1; "v1")]
+#[test_case(2; "v2")]
+#[tokio::test]
+async fn fail_proposal_vote_not_migrated(version: u8) {
+    let stake_authority = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(&mut context, &stake, &stake_authority, &validator_vote, 0).await;
+    setup_proposal_with_stake(
+        &mut context,
+        &proposal,
+        &stake_authority,
+        0,
+        governance_config(&stake_config),
+        /* stake_for */ 0,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ TOTAL_STAKE,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+    )
+    .await;
+
+    // Set up a proposal vote account with a previous layout.
+    setup_legacy_proposal_vote(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        0,
+        &stake,
+        &stake_authority,
+        ProposalVoteElection::For,
+        version,
+    )
+    .await;
+
+    let instruction = sync_vote_weight(&stake, &stake_config, &proposal_vote, &proposal);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::AccountVersionMismatch as u32)
+        )
+    );
+}
+
+struct Sync {
+    election: ProposalVoteElection,
+    previous_vote_stake: u64,
+    current_stake: u64,
+}
+struct Expect {
+    cooldown: bool,
+    status: ProposalStatus,
+    stake_for: u64,
+    stake_against: u64,
+    stake_abstained: u64,
+}
+
+#[test_case(
+    Sync {
+        election: ProposalVoteElection::For,
+        previous_vote_stake: TOTAL_STAKE / 5,
+        current_stake: TOTAL_STAKE / 10,
+    },
+    Expect {
+        cooldown: false,
+        status: ProposalStatus::Voting,
+        stake_for: TOTAL_STAKE / 10,
+        stake_against: 0,
+        stake_abstained: 0,
+    };
+    "for_stake_decreased"
+)]
+#[test_case(
+    Sync {
+        election: ProposalVoteElection::For,
+        previous_vote_stake: TOTAL_STAKE / 10,
+        current_stake: TOTAL_STAKE / 2,
+    },
+    Expect {
+        cooldown: true,
+        status: ProposalStatus::Voting,
+        stake_for: TOTAL_STAKE / 2,
+        stake_against: 0,
+        stake_abstained: 0,
+    };
+    "for_stake_increased_begins_cooldown"
+)]
+#[test_case(
+    Sync {
+        election: ProposalVoteElection::Against,
+        previous_vote_stake: TOTAL_STAKE / 5,
+        current_stake: 0,
+    },
+    Expect {
+        cooldown: false,
+        status: ProposalStatus::Voting,
+        stake_for: 0,
+        stake_against: 0,
+        stake_abstained: 0,
+    };
+    "against_stake_withdrawn"
+)]
+#[test_case(
+    Sync {
+        election: ProposalVoteElection::Against,
+        previous_vote_stake: TOTAL_STAKE / 10,
+        current_stake: TOTAL_STAKE / 2,
+    },
+    Expect {
+        cooldown: false,
+        status: ProposalStatus::Rejected,
+        stake_for: 0,
+        stake_against: TOTAL_STAKE / 2,
+        stake_abstained: 0,
+    };
+    "against_stake_increased_rejects"
+)]
+#[test_case(
+    Sync {
+        election: ProposalVoteElection::DidNotVote,
+        previous_vote_stake: TOTAL_STAKE / 5,
+        current_stake: TOTAL_STAKE / 10,
+    },
+    Expect {
+        cooldown: false,
+        status: ProposalStatus::Voting,
+        stake_for: 0,
+        stake_against: 0,
+        stake_abstained: TOTAL_STAKE / 10,
+    };
+    "did_not_vote_stake_decreased"
+)]
+#[tokio::test]
+async fn success(sync: Sync, expect: Expect) {
+    let stake_authority = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority,
+        &validator_vote,
+        sync.current_stake,
+    )
+    .await;
+
+    // Set up the proposal with only the previous vote counted.
+    let (stake_for, stake_against, stake_abstained) = match sync.election {
+        ProposalVoteElection::For => (sync.previous_vote_stake, 0, 0),
+        ProposalVoteElection::Against => (0, sync.previous_vote_stake, 0),
+        ProposalVoteElection::DidNotVote => (0, 0, sync.previous_vote_stake),
+    };
+    setup_proposal_with_stake(
+        &mut context,
+        &proposal,
+        &stake_authority,
+        0,
+        governance_config(&stake_config),
+        stake_for,
+        stake_against,
+        stake_abstained,
+        /* total_stake */ TOTAL_STAKE,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+    )
+    .await;
+    setup_proposal_vote(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        sync.previous_vote_stake,
+        &stake,
+        &stake_authority,
+        sync.election,
+    )
+    .await;
+
+    let instruction = sync_vote_weight(&stake, &stake_config, &proposal_vote, &proposal);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the vote weight was updated, and the election left unchanged.
+    let proposal_vote_account = context
+        .banks_client
+        .get_account(proposal_vote)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        bytemuck::from_bytes::<ProposalVote>(&proposal_vote_account.data),
+        &ProposalVote::new(
+            &proposal,
+            sync.current_stake,
+            &stake,
+            &stake_authority,
+            &stake_authority,
+            None,
+            sync.election,
+        )
+    );
+
+    // Assert the proposal stake matches the expected values.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, expect.status);
+    assert_eq!(proposal_state.stake_for, expect.stake_for);
+    assert_eq!(proposal_state.stake_against, expect.stake_against);
+    assert_eq!(proposal_state.stake_abstained, expect.stake_abstained);
+    assert_eq!(proposal_state.cooldown_timestamp.is_some(), expect.cooldown);
+}
+
+#[test_case(TOTAL_STAKE / 2, true; "for_stake_unchanged_keeps_cooldown")]
+#[test_case(TOTAL_STAKE / 10, false; "for_stake_decreased_below_threshold_clears_cooldown")]
+#[tokio::test]
+async fn success_cooldown_active(current_stake: u64, expect_cooldown: bool) {
+    let stake_authority = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority,
+        &validator_vote,
+        current_stake,
+    )
+    .await;
+
+    // Set up the proposal at the acceptance threshold from the vote alone,
+    // with an active cooldown period.
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &stake_authority,
+        0,
+        governance_config(&stake_config),
+        /* stake_for */ TOTAL_STAKE / 2,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ TOTAL_STAKE,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+        /* cooldown_timestamp */
+        NonZeroU64::new(clock.unix_timestamp.saturating_sub(100) as u64),
+    )
+    .await;
+    setup_proposal_vote(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        TOTAL_STAKE / 2,
+        &stake,
+        &stake_authority,
+        ProposalVoteElection::For,
+    )
+    .await;
+
+    let instruction = sync_vote_weight(&stake, &stake_config, &proposal_vote, &proposal);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the cooldown period reflects the proposal's current support.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::Voting);
+    assert_eq!(proposal_state.stake_for, current_stake);
+    assert_eq!(proposal_state.cooldown_timestamp.is_some(), expect_cooldown);
+}
+
+#[test_case(false; "voting_period_ended")]
+#[test_case(true; "cooldown_period_ended")]
+#[tokio::test]
+async fn success_concluded_quorum_not_met(cooldown_ended: bool) {
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    // The quorum threshold exceeds the acceptance threshold, so a proposal
+    // can begin a cooldown period without enough participation.
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 10,
+        /* execution_window_seconds */ 1_000,
+        /* proposal_acceptance_threshold */ 200_000_000, // 20%
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 500_000_000, // 50%
+        /* quorum_threshold */ 600_000_000, // 60%
+        /* signer_bump_seed */ 0,
+        &stake_config,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 10,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &Pubkey::new_unique(),
+        &validator_vote,
+        TOTAL_STAKE / 10,
+    )
+    .await;
+
+    // Either the cooldown period has ended with 30% of the stake in favor, or
+    // the voting period has ended with 10% of the stake in favor. Neither
+    // meets the 60% quorum.
+    let (stake_for, voting_start_timestamp, cooldown_timestamp) = if cooldown_ended {
+        (
+            TOTAL_STAKE / 10 * 3,
+            NonZeroU64::new(clock.unix_timestamp as u64),
+            NonZeroU64::new(clock.unix_timestamp.saturating_sub(10) as u64),
+        )
+    } else {
+        (TOTAL_STAKE / 10, NonZeroU64::new(1), None)
+    };
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        stake_for,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ TOTAL_STAKE,
+        ProposalStatus::Voting,
+        voting_start_timestamp,
+        cooldown_timestamp,
+    )
+    .await;
+
+    let instruction = sync_vote_weight(&stake, &stake_config, &proposal_vote, &proposal);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal concluded without meeting quorum.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::QuorumNotMet);
+}
+
+#[tokio::test]
+async fn success_execution_window_ended() {
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 10,
+        /* execution_window_seconds */ 10,
+        ACCEPTANCE_THRESHOLD,
+        /* proposal_minimum_stake */ 0,
+        REJECTION_THRESHOLD,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        &stake_config,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 1_000,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &Pubkey::new_unique(),
+        &validator_vote,
+        TOTAL_STAKE / 10,
+    )
+    .await;
+
+    // Set up a proposal whose cooldown period and execution window have both
+    // ended.
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        /* stake_for */ TOTAL_STAKE,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ TOTAL_STAKE,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */
+        NonZeroU64::new(clock.unix_timestamp as u64),
+        /* cooldown_timestamp */
+        NonZeroU64::new(clock.unix_timestamp.saturating_sub(20) as u64), // Now - 20 seconds.
+    )
+    .await;
+
+    let instruction = sync_vote_weight(&stake, &stake_config, &proposal_vote, &proposal);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal expired rather than being accepted.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::Expired);
+}
@@ -0,0 +1,417 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::{process_instruction, transfer_from_treasury, transfer_tokens_from_treasury},
+        state::{
+            get_governance_address, get_proposal_transaction_address, get_treasury_address, Config,
+            ProposalStatus, ProposalTransaction,
+        },
+    },
+    setup::{
+        setup, setup_governance, setup_mint, setup_proposal_transaction,
+        setup_proposal_with_stake_and_cooldown, setup_token_account,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        account::AccountSharedData,
+        clock::Clock,
+        instruction::{AccountMeta, Instruction, InstructionError},
+        pubkey::Pubkey,
+        signer::Signer,
+        system_program,
+        transaction::{Transaction, TransactionError},
+    },
+    spl_associated_token_account::get_associated_token_address_with_program_id,
+    spl_token_2022::{extension::StateWithExtensions, state::Account as TokenAccount},
+    std::num::NonZeroU64,
+    test_case::test_case,
+};
+
+// Stores the provided `TransferFromTreasury` instruction in the proposal's
+// transaction and executes it through `ProcessInstruction`, which signs for the
+// treasury and proposal transaction PDAs.
+async fn process_transfer_from_treasury_from_proposal(
+    context: &mut ProgramTestContext,
+    proposal: &Pubkey,
+    transfer_from_treasury_instruction: &Instruction,
+) -> Result<(), BanksClientError> {
+    let proposal_transaction =
+        get_proposal_transaction_address(proposal, &paladin_governance_program::id());
+    setup_proposal_transaction(
+        context,
+        &proposal_transaction,
+        ProposalTransaction::new(vec![transfer_from_treasury_instruction.into()]),
+    )
+    .await;
+
+    // PDAs are signed for by the program, not the transaction.
+    let mut account_metas = transfer_from_treasury_instruction
+        .accounts
+        .iter()
+        .map(|meta| AccountMeta {
+            is_signer: false,
+            ..meta.clone()
+        })
+        .collect::<Vec<_>>();
+    account_metas.push(AccountMeta::new_readonly(
+        paladin_governance_program::id(),
+        false,
+    ));
+
+    let instruction = process_instruction(proposal, &proposal_transaction, &account_metas, 0);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(transaction).await
+}
+
+// Sets up the governance config and an accepted proposal under it.
+async fn setup_accepted_proposal(
+    context: &mut ProgramTestContext,
+    stake_config: &Pubkey,
+    proposal: &Pubkey,
+    cooldown_period_seconds: u64,
+) {
+    let governance = get_governance_address(stake_config, &paladin_governance_program::id());
+    let governance_config = Config::new(
+        cooldown_period_seconds,
+        /* execution_window_seconds */ 1_000,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        stake_config,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 0,
+    );
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_governance(
+        context,
+        &governance,
+        governance_config.cooldown_period_seconds,
+        governance_config.execution_window_seconds,
+        governance_config.proposal_acceptance_threshold,
+        governance_config.proposal_minimum_stake,
+        governance_config.proposal_rejection_threshold,
+        governance_config.quorum_threshold,
+        &governance_config.stake_config_address,
+        None,
+        governance_config.voting_period_seconds,
+    )
+    .await;
+    setup_proposal_with_stake_and_cooldown(
+        context,
+        proposal,
+        &Pubkey::new_unique(),
+        0,
+        governance_config,
+        0,
+        0,
+        0,
+        /* total_stake */ 0,
+        ProposalStatus::Accepted,
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+        /* cooldown_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn fail_proposal_transaction_not_signer() {
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_accepted_proposal(&mut context, &stake_config, &proposal, 0).await;
+
+    let mut instruction = transfer_from_treasury(
+        &governance,
+        &proposal,
+        &proposal_transaction,
+        &treasury,
+        &Pubkey::new_unique(),
+        1,
+    );
+    instruction.accounts[2].is_signer = false; // Proposal transaction not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_treasury_incorrect_address() {
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let treasury = Pubkey::new_unique(); // Incorrect treasury address.
+
+    let mut context = setup().start_with_context().await;
+    setup_accepted_proposal(&mut context, &stake_config, &proposal, 0).await;
+
+    let instruction = transfer_from_treasury(
+        &governance,
+        &proposal,
+        &proposal_transaction,
+        &treasury,
+        &Pubkey::new_unique(),
+        1,
+    );
+
+    let err = process_transfer_from_treasury_from_proposal(&mut context, &proposal, &instruction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::IncorrectTreasuryAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_not_accepted() {
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    // Set up a proposal whose cooldown period has not ended.
+    setup_accepted_proposal(&mut context, &stake_config, &proposal, 100_000_000).await;
+
+    let instruction = transfer_from_treasury(
+        &governance,
+        &proposal,
+        &proposal_transaction,
+        &treasury,
+        &Pubkey::new_unique(),
+        1,
+    );
+
+    let err = process_transfer_from_treasury_from_proposal(&mut context, &proposal, &instruction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::ProposalNotAccepted as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_treasury_token_account_incorrect_address() {
+    let stake_config = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_accepted_proposal(&mut context, &stake_config, &proposal, 0).await;
+    setup_mint(&mut context, &mint, &spl_token_2022::id(), 6, 1_000).await;
+
+    let mut instruction = transfer_tokens_from_treasury(
+        &governance,
+        &proposal,
+        &proposal_transaction,
+        &treasury,
+        &Pubkey::new_unique(),
+        &mint,
+        &spl_token_2022::id(),
+        1_000,
+    );
+    instruction.accounts[6].pubkey = Pubkey::new_unique(); // Incorrect treasury token account.
+
+    let err = process_transfer_from_treasury_from_proposal(&mut context, &proposal, &instruction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                PaladinGovernanceError::IncorrectTreasuryTokenAccountAddress as u32
+            )
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let stake_config = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_accepted_proposal(&mut context, &stake_config, &proposal, 0).await;
+
+    context.set_account(
+        &treasury,
+        &AccountSharedData::new(10_000_000_000, 0, &system_program::id()),
+    );
+
+    let instruction = transfer_from_treasury(
+        &governance,
+        &proposal,
+        &proposal_transaction,
+        &treasury,
+        &destination,
+        4_000_000_000,
+    );
+
+    process_transfer_from_treasury_from_proposal(&mut context, &proposal, &instruction)
+        .await
+        .unwrap();
+
+    // Assert the lamports were moved out of the treasury.
+    assert_eq!(
+        context
+            .banks_client
+            .get_account(treasury)
+            .await
+            .unwrap()
+            .unwrap()
+            .lamports,
+        6_000_000_000
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .get_account(destination)
+            .await
+            .unwrap()
+            .unwrap()
+            .lamports,
+        4_000_000_000
+    );
+}
+
+#[test_case(spl_token::id(); "token")]
+#[test_case(spl_token_2022::id(); "token_2022")]
+#[tokio::test]
+async fn success_tokens(token_program_id: Pubkey) {
+    let stake_config = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let destination_token_account = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&stake_config, &paladin_governance_program::id());
+    let treasury_token_account =
+        get_associated_token_address_with_program_id(&treasury, &mint, &token_program_id);
+
+    let mut context = setup().start_with_context().await;
+    setup_accepted_proposal(&mut context, &stake_config, &proposal, 0).await;
+    setup_mint(&mut context, &mint, &token_program_id, 6, 1_000).await;
+    setup_token_account(
+        &mut context,
+        &treasury_token_account,
+        &token_program_id,
+        &mint,
+        &treasury,
+        1_000,
+    )
+    .await;
+    setup_token_account(
+        &mut context,
+        &destination_token_account,
+        &token_program_id,
+        &mint,
+        &Pubkey::new_unique(),
+        0,
+    )
+    .await;
+
+    let instruction = transfer_tokens_from_treasury(
+        &governance,
+        &proposal,
+        &proposal_transaction,
+        &treasury,
+        &destination_token_account,
+        &mint,
+        &token_program_id,
+        250,
+    );
+
+    process_transfer_from_treasury_from_proposal(&mut context, &proposal, &instruction)
+        .await
+        .unwrap();
+
+    // Assert the tokens were moved out of the treasury token account.
+    let treasury_token_account = context
+        .banks_client
+        .get_account(treasury_token_account)
+        .await
+        .unwrap()
+        .unwrap();
+    let treasury_token_account_state =
+        StateWithExtensions::<TokenAccount>::unpack(&treasury_token_account.data)
+            .unwrap()
+            .base;
+    assert_eq!(treasury_token_account_state.amount, 750);
+
+    let destination_token_account = context
+        .banks_client
+        .get_account(destination_token_account)
+        .await
+        .unwrap()
+        .unwrap();
+    let destination_token_account_state =
+        StateWithExtensions::<TokenAccount>::unpack(&destination_token_account.data)
+            .unwrap()
+            .base;
+    assert_eq!(destination_token_account_state.amount, 250);
+}
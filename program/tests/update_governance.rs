@@ -5,25 +5,81 @@ mod setup;
 use {
     paladin_governance_program::{
         error::PaladinGovernanceError,
-        instruction::update_governance,
-        state::{Config, Proposal, ProposalStatus},
+        instruction::{process_instruction, update_governance},
+        state::{
+            get_governance_address, get_proposal_transaction_address, Config, PeriodBounds,
+            Proposal, ProposalStatus, ProposalTransaction, DEFAULT_MAX_COOLDOWN_PERIOD_SECONDS,
+            DEFAULT_MAX_EXECUTION_WINDOW_SECONDS, DEFAULT_MAX_VOTING_PERIOD_SECONDS,
+            DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS, DEFAULT_MIN_EXECUTION_WINDOW_SECONDS,
+            DEFAULT_MIN_VOTING_PERIOD_SECONDS,
+        },
+    },
+    setup::{
+        setup, setup_governance, setup_governance_with_period_bounds, setup_proposal,
+        setup_proposal_transaction, setup_proposal_with_stake_and_cooldown,
     },
-    setup::{setup, setup_governance, setup_proposal_with_stake_and_cooldown},
     solana_program_test::*,
     solana_sdk::{
         account::AccountSharedData,
         clock::Clock,
-        instruction::InstructionError,
+        instruction::{AccountMeta, Instruction, InstructionError},
         pubkey::Pubkey,
+        signature::Keypair,
         signer::Signer,
         transaction::{Transaction, TransactionError},
     },
     std::num::NonZeroU64,
+    test_case::test_case,
 };
 
+// Stores the provided `UpdateGovernance` instruction in the proposal's
+// transaction and executes it through `ProcessInstruction`, which signs for the
+// governance config and proposal transaction PDAs.
+async fn process_update_governance_from_proposal(
+    context: &mut ProgramTestContext,
+    proposal: &Pubkey,
+    update_governance_instruction: &Instruction,
+) -> Result<(), BanksClientError> {
+    let proposal_transaction =
+        get_proposal_transaction_address(proposal, &paladin_governance_program::id());
+    setup_proposal_transaction(
+        context,
+        &proposal_transaction,
+        ProposalTransaction::new(vec![update_governance_instruction.into()]),
+    )
+    .await;
+
+    // PDAs are signed for by the program, not the transaction.
+    let mut account_metas = update_governance_instruction
+        .accounts
+        .iter()
+        .map(|meta| AccountMeta {
+            is_signer: false,
+            ..meta.clone()
+        })
+        .collect::<Vec<_>>();
+    account_metas.push(AccountMeta::new_readonly(
+        paladin_governance_program::id(),
+        false,
+    ));
+
+    let instruction = process_instruction(proposal, &proposal_transaction, &account_metas, 0);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(transaction).await
+}
+
 #[tokio::test]
 async fn fail_governance_incorrect_owner() {
     let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
     let governance = Pubkey::new_unique(); // PDA doesn't matter here.
 
     let mut context = setup().start_with_context().await;
@@ -39,14 +95,21 @@ async fn fail_governance_incorrect_owner() {
         );
     }
 
-    let instruction = update_governance(
+    let mut instruction = update_governance(
         &governance,
         &proposal,
+        &proposal_transaction,
         /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
         /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
         /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 0,
     );
+    instruction.accounts[0].is_signer = false; // Governance not signer.
+    instruction.accounts[2].is_signer = false; // Proposal transaction not signer.
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -71,6 +134,8 @@ async fn fail_governance_incorrect_owner() {
 #[tokio::test]
 async fn fail_governance_not_initialized() {
     let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
     let governance = Pubkey::new_unique(); // PDA doesn't matter here.
 
     let mut context = setup().start_with_context().await;
@@ -85,14 +150,21 @@ async fn fail_governance_not_initialized() {
         );
     }
 
-    let instruction = update_governance(
+    let mut instruction = update_governance(
         &governance,
         &proposal,
+        &proposal_transaction,
         /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
         /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
         /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 0,
     );
+    instruction.accounts[0].is_signer = false; // Governance not signer.
+    instruction.accounts[2].is_signer = false; // Proposal transaction not signer.
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -117,6 +189,8 @@ async fn fail_governance_not_initialized() {
 #[tokio::test]
 async fn fail_proposal_incorrect_owner() {
     let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
     let governance = Pubkey::new_unique(); // PDA doesn't matter here.
 
     let mut context = setup().start_with_context().await;
@@ -126,7 +200,11 @@ async fn fail_proposal_incorrect_owner() {
         0,
         0,
         0,
+        0,
+        0,
+        0,
         /* stake_config_address */ &Pubkey::new_unique(),
+        None,
         0,
     )
     .await;
@@ -142,14 +220,21 @@ async fn fail_proposal_incorrect_owner() {
         );
     }
 
-    let instruction = update_governance(
+    let mut instruction = update_governance(
         &governance,
         &proposal,
+        &proposal_transaction,
         /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
         /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
         /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 0,
     );
+    instruction.accounts[0].is_signer = false; // Governance not signer.
+    instruction.accounts[2].is_signer = false; // Proposal transaction not signer.
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -174,6 +259,8 @@ async fn fail_proposal_incorrect_owner() {
 #[tokio::test]
 async fn fail_proposal_not_initialized() {
     let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
     let governance = Pubkey::new_unique(); // PDA doesn't matter here.
 
     let mut context = setup().start_with_context().await;
@@ -183,7 +270,11 @@ async fn fail_proposal_not_initialized() {
         0,
         0,
         0,
+        0,
+        0,
+        0,
         /* stake_config_address */ &Pubkey::new_unique(),
+        None,
         0,
     )
     .await;
@@ -199,14 +290,21 @@ async fn fail_proposal_not_initialized() {
         );
     }
 
-    let instruction = update_governance(
+    let mut instruction = update_governance(
         &governance,
         &proposal,
+        &proposal_transaction,
         /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
         /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
         /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 0,
     );
+    instruction.accounts[0].is_signer = false; // Governance not signer.
+    instruction.accounts[2].is_signer = false; // Proposal transaction not signer.
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -228,17 +326,263 @@ async fn fail_proposal_not_initialized() {
     );
 }
 
+#[tokio::test]
+async fn fail_governance_not_signer() {
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let stake_config_address = Pubkey::new_unique();
+    let governance =
+        get_governance_address(&stake_config_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(
+        &mut context,
+        &governance,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        &stake_config_address,
+        None,
+        0,
+    )
+    .await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        0,
+        Config::default(),
+        ProposalStatus::Accepted,
+    )
+    .await;
+
+    let mut instruction = update_governance(
+        &governance,
+        &proposal,
+        &proposal_transaction,
+        /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 0,
+    );
+    instruction.accounts[0].is_signer = false; // Governance not signer.
+    instruction.accounts[2].is_signer = false; // Proposal transaction not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Governance not signer.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_governance_incorrect_address() {
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    // Use a keypair, rather than the PDA, so the governance account can sign.
+    let governance = Keypair::new();
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(
+        &mut context,
+        &governance.pubkey(),
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        /* stake_config_address */ &Pubkey::new_unique(),
+        None,
+        0,
+    )
+    .await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        0,
+        Config::default(),
+        ProposalStatus::Accepted,
+    )
+    .await;
+
+    let mut instruction = update_governance(
+        &governance.pubkey(),
+        &proposal,
+        &proposal_transaction,
+        /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 0,
+    );
+    instruction.accounts[2].is_signer = false; // Proposal transaction not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &governance],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                PaladinGovernanceError::IncorrectGovernanceConfigAddress as u32
+            )
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_not_executing() {
+    let executing_proposal = Pubkey::new_unique();
+    let executing_proposal_transaction =
+        get_proposal_transaction_address(&executing_proposal, &paladin_governance_program::id());
+    let other_proposal = Pubkey::new_unique();
+
+    let stake_config_address = Pubkey::new_unique();
+    let governance =
+        get_governance_address(&stake_config_address, &paladin_governance_program::id());
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 1_000,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        /* stake_config_address */ &stake_config_address,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 0,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_governance(
+        &mut context,
+        &governance,
+        governance_config.cooldown_period_seconds,
+        governance_config.execution_window_seconds,
+        governance_config.proposal_acceptance_threshold,
+        governance_config.proposal_minimum_stake,
+        governance_config.proposal_rejection_threshold,
+        governance_config.quorum_threshold,
+        &governance_config.stake_config_address,
+        None,
+        governance_config.voting_period_seconds,
+    )
+    .await;
+    for proposal in [&executing_proposal, &other_proposal] {
+        setup_proposal_with_stake_and_cooldown(
+            &mut context,
+            proposal,
+            &Pubkey::new_unique(),
+            0,
+            governance_config,
+            0,
+            0,
+            0,
+            /* total_stake */ 0,
+            ProposalStatus::Accepted,
+            /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+            /* cooldown_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+        )
+        .await;
+    }
+
+    // The executing proposal attempts to pass off another accepted proposal as
+    // the one being executed.
+    let instruction = update_governance(
+        &governance,
+        &other_proposal,
+        &executing_proposal_transaction,
+        /* cooldown_period_seconds */ 1,
+        /* execution_window_seconds */ 0,
+        /* proposal_acceptance_threshold */ 2,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 3,
+        /* quorum_threshold */ 0,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 4,
+    );
+
+    let err =
+        process_update_governance_from_proposal(&mut context, &executing_proposal, &instruction)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                PaladinGovernanceError::IncorrectProposalTransactionAddress as u32
+            )
+        )
+    );
+}
+
 #[tokio::test]
 async fn fail_proposal_cooldown_still_active() {
     let proposal = Pubkey::new_unique();
-    let governance = Pubkey::new_unique(); // PDA doesn't matter here.
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let stake_config_address = Pubkey::new_unique();
+    let governance =
+        get_governance_address(&stake_config_address, &paladin_governance_program::id());
 
     let governance_config = Config::new(
         /* cooldown_period_seconds */ 100_000_000,
+        /* execution_window_seconds */ 1_000,
         /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
         /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
         /* signer_bump_seed */ 0,
-        /* stake_config_address */ &Pubkey::new_unique(), // Doesn't matter here.
+        /* stake_config_address */ &stake_config_address,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 0,
     );
 
@@ -252,9 +596,13 @@ async fn fail_proposal_cooldown_still_active() {
         &mut context,
         &governance,
         governance_config.cooldown_period_seconds,
+        governance_config.execution_window_seconds,
         governance_config.proposal_acceptance_threshold,
+        governance_config.proposal_minimum_stake,
         governance_config.proposal_rejection_threshold,
+        governance_config.quorum_threshold,
         &governance_config.stake_config_address,
+        None,
         governance_config.voting_period_seconds,
     )
     .await;
@@ -267,31 +615,28 @@ async fn fail_proposal_cooldown_still_active() {
         0,
         0,
         0,
+        /* total_stake */ 0,
         ProposalStatus::Accepted,
         /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
-        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+        /* cooldown_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
     )
     .await;
 
     let instruction = update_governance(
         &governance,
         &proposal,
+        &proposal_transaction,
         /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
         /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
         /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 0,
     );
 
-    let transaction = Transaction::new_signed_with_payer(
-        &[instruction],
-        Some(&context.payer.pubkey()),
-        &[&context.payer],
-        context.last_blockhash,
-    );
-
-    let err = context
-        .banks_client
-        .process_transaction(transaction)
+    let err = process_update_governance_from_proposal(&mut context, &proposal, &instruction)
         .await
         .unwrap_err()
         .unwrap();
@@ -305,19 +650,144 @@ async fn fail_proposal_cooldown_still_active() {
     );
 }
 
+#[test_case(
+    DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS,
+    DEFAULT_MIN_EXECUTION_WINDOW_SECONDS,
+    0,
+    500_000_000,
+    0,
+    DEFAULT_MIN_VOTING_PERIOD_SECONDS,
+    PaladinGovernanceError::InvalidProposalAcceptanceThreshold;
+    "acceptance_threshold_zero"
+)]
+#[test_case(
+    DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS,
+    DEFAULT_MIN_EXECUTION_WINDOW_SECONDS,
+    1_000_000_001,
+    500_000_000,
+    0,
+    DEFAULT_MIN_VOTING_PERIOD_SECONDS,
+    PaladinGovernanceError::InvalidProposalAcceptanceThreshold;
+    "acceptance_threshold_above_scale"
+)]
+#[test_case(
+    DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS,
+    DEFAULT_MIN_EXECUTION_WINDOW_SECONDS,
+    500_000_000,
+    0,
+    0,
+    DEFAULT_MIN_VOTING_PERIOD_SECONDS,
+    PaladinGovernanceError::InvalidProposalRejectionThreshold;
+    "rejection_threshold_zero"
+)]
+#[test_case(
+    DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS,
+    DEFAULT_MIN_EXECUTION_WINDOW_SECONDS,
+    500_000_000,
+    1_000_000_001,
+    0,
+    DEFAULT_MIN_VOTING_PERIOD_SECONDS,
+    PaladinGovernanceError::InvalidProposalRejectionThreshold;
+    "rejection_threshold_above_scale"
+)]
+#[test_case(
+    DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS,
+    DEFAULT_MIN_EXECUTION_WINDOW_SECONDS,
+    500_000_000,
+    500_000_000,
+    1_000_000_001,
+    DEFAULT_MIN_VOTING_PERIOD_SECONDS,
+    PaladinGovernanceError::InvalidQuorumThreshold;
+    "quorum_threshold_above_scale"
+)]
+#[test_case(
+    DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS - 1,
+    DEFAULT_MIN_EXECUTION_WINDOW_SECONDS,
+    500_000_000,
+    500_000_000,
+    0,
+    DEFAULT_MIN_VOTING_PERIOD_SECONDS,
+    PaladinGovernanceError::InvalidCooldownPeriod;
+    "cooldown_period_too_short"
+)]
+#[test_case(
+    DEFAULT_MAX_COOLDOWN_PERIOD_SECONDS + 1,
+    DEFAULT_MIN_EXECUTION_WINDOW_SECONDS,
+    500_000_000,
+    500_000_000,
+    0,
+    DEFAULT_MIN_VOTING_PERIOD_SECONDS,
+    PaladinGovernanceError::InvalidCooldownPeriod;
+    "cooldown_period_too_long"
+)]
+#[test_case(
+    DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS,
+    DEFAULT_MIN_EXECUTION_WINDOW_SECONDS - 1,
+    500_000_000,
+    500_000_000,
+    0,
+    DEFAULT_MIN_VOTING_PERIOD_SECONDS,
+    PaladinGovernanceError::InvalidExecutionWindow;
+    "execution_window_too_short"
+)]
+#[test_case(
+    DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS,
+    DEFAULT_MAX_EXECUTION_WINDOW_SECONDS + 1,
+    500_000_000,
+    500_000_000,
+    0,
+    DEFAULT_MIN_VOTING_PERIOD_SECONDS,
+    PaladinGovernanceError::InvalidExecutionWindow;
+    "execution_window_too_long"
+)]
+#[test_case(
+    DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS,
+    DEFAULT_MIN_EXECUTION_WINDOW_SECONDS,
+    500_000_000,
+    500_000_000,
+    0,
+    DEFAULT_MIN_VOTING_PERIOD_SECONDS - 1,
+    PaladinGovernanceError::InvalidVotingPeriod;
+    "voting_period_too_short"
+)]
+#[test_case(
+    DEFAULT_MIN_COOLDOWN_PERIOD_SECONDS,
+    DEFAULT_MIN_EXECUTION_WINDOW_SECONDS,
+    500_000_000,
+    500_000_000,
+    0,
+    DEFAULT_MAX_VOTING_PERIOD_SECONDS + 1,
+    PaladinGovernanceError::InvalidVotingPeriod;
+    "voting_period_too_long"
+)]
 #[tokio::test]
-async fn success() {
+async fn fail_invalid_params(
+    cooldown_period_seconds: u64,
+    execution_window_seconds: u64,
+    proposal_acceptance_threshold: u32,
+    proposal_rejection_threshold: u32,
+    quorum_threshold: u32,
+    voting_period_seconds: u64,
+    error: PaladinGovernanceError,
+) {
     let proposal = Pubkey::new_unique();
-    let governance = Pubkey::new_unique(); // PDA doesn't matter here.
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
 
     let stake_config_address = Pubkey::new_unique();
+    let governance =
+        get_governance_address(&stake_config_address, &paladin_governance_program::id());
 
     let governance_config = Config::new(
         /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 1_000,
         /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
         /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
         /* signer_bump_seed */ 0,
         /* stake_config_address */ &stake_config_address,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 0,
     );
 
@@ -328,9 +798,13 @@ async fn success() {
         &mut context,
         &governance,
         governance_config.cooldown_period_seconds,
+        governance_config.execution_window_seconds,
         governance_config.proposal_acceptance_threshold,
+        governance_config.proposal_minimum_stake,
         governance_config.proposal_rejection_threshold,
+        governance_config.quorum_threshold,
         &governance_config.stake_config_address,
+        None,
         governance_config.voting_period_seconds,
     )
     .await;
@@ -343,31 +817,110 @@ async fn success() {
         0,
         0,
         0,
+        /* total_stake */ 0,
         ProposalStatus::Accepted,
         /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
-        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+        /* cooldown_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
     )
     .await;
 
     let instruction = update_governance(
         &governance,
         &proposal,
-        /* cooldown_period_seconds */ 1,
-        /* proposal_acceptance_threshold */ 2,
-        /* proposal_rejection_threshold */ 3,
-        /* voting_period_seconds */ 4,
+        &proposal_transaction,
+        cooldown_period_seconds,
+        execution_window_seconds,
+        proposal_acceptance_threshold,
+        /* proposal_minimum_stake */ 0,
+        proposal_rejection_threshold,
+        quorum_threshold,
+        /* veto_authority */ None,
+        voting_period_seconds,
     );
 
-    let transaction = Transaction::new_signed_with_payer(
-        &[instruction],
-        Some(&context.payer.pubkey()),
-        &[&context.payer],
-        context.last_blockhash,
+    let err = process_update_governance_from_proposal(&mut context, &proposal, &instruction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(error as u32))
     );
+}
 
-    context
-        .banks_client
-        .process_transaction(transaction)
+#[tokio::test]
+async fn success() {
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let stake_config_address = Pubkey::new_unique();
+    let veto_authority = Pubkey::new_unique();
+    let governance =
+        get_governance_address(&stake_config_address, &paladin_governance_program::id());
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 1_000,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        /* stake_config_address */ &stake_config_address,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 0,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_governance(
+        &mut context,
+        &governance,
+        governance_config.cooldown_period_seconds,
+        governance_config.execution_window_seconds,
+        governance_config.proposal_acceptance_threshold,
+        governance_config.proposal_minimum_stake,
+        governance_config.proposal_rejection_threshold,
+        governance_config.quorum_threshold,
+        &governance_config.stake_config_address,
+        None,
+        governance_config.voting_period_seconds,
+    )
+    .await;
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        0,
+        governance_config,
+        0,
+        0,
+        0,
+        /* total_stake */ 0,
+        ProposalStatus::Accepted,
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+        /* cooldown_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+    )
+    .await;
+
+    let instruction = update_governance(
+        &governance,
+        &proposal,
+        &proposal_transaction,
+        /* cooldown_period_seconds */ 86_400, // 1 day
+        /* execution_window_seconds */ 604_800, // 7 days
+        /* proposal_acceptance_threshold */ 500_000_000, // 50%
+        /* proposal_minimum_stake */ 1_000_000,
+        /* proposal_rejection_threshold */ 400_000_000, // 40%
+        /* quorum_threshold */ 300_000_000, // 30%
+        /* veto_authority */ Some(&veto_authority),
+        /* voting_period_seconds */ 172_800, // 2 days
+    );
+
+    process_update_governance_from_proposal(&mut context, &proposal, &instruction)
         .await
         .unwrap();
 
@@ -379,8 +932,190 @@ async fn success() {
         .unwrap()
         .unwrap();
     let governance_state = bytemuck::from_bytes::<Config>(&governance_account.data);
-    assert_eq!(governance_state.cooldown_period_seconds, 1);
-    assert_eq!(governance_state.proposal_acceptance_threshold, 2);
-    assert_eq!(governance_state.proposal_rejection_threshold, 3);
+    assert_eq!(governance_state.cooldown_period_seconds, 86_400);
+    assert_eq!(governance_state.execution_window_seconds, 604_800);
+    assert_eq!(governance_state.proposal_acceptance_threshold, 500_000_000);
+    assert_eq!(governance_state.proposal_minimum_stake, 1_000_000);
+    assert_eq!(governance_state.proposal_rejection_threshold, 400_000_000);
+    assert_eq!(governance_state.quorum_threshold, 300_000_000);
     assert_eq!(governance_state.stake_config_address, stake_config_address);
+    assert_eq!(
+        Option::<Pubkey>::from(governance_state.veto_authority),
+        Some(veto_authority)
+    );
+    assert_eq!(governance_state.voting_period_seconds, 172_800);
+    assert_eq!(governance_state.period_bounds, PeriodBounds::default());
+}
+
+// Sets up a governance config bounding every period between one minute and
+// ten minutes, along with an accepted proposal under it.
+async fn setup_governance_with_short_period_bounds(
+    context: &mut ProgramTestContext,
+    governance: &Pubkey,
+    stake_config_address: &Pubkey,
+    proposal: &Pubkey,
+) -> PeriodBounds {
+    let period_bounds = PeriodBounds {
+        min_cooldown_period_seconds: 60,
+        max_cooldown_period_seconds: 600,
+        min_execution_window_seconds: 60,
+        max_execution_window_seconds: 600,
+        min_voting_period_seconds: 60,
+        max_voting_period_seconds: 600,
+    };
+
+    let mut governance_config = Config::new(
+        /* cooldown_period_seconds */ 60,
+        /* execution_window_seconds */ 600,
+        /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        /* stake_config_address */ stake_config_address,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 60,
+    );
+    governance_config.period_bounds = period_bounds;
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_governance_with_period_bounds(
+        context,
+        governance,
+        governance_config.cooldown_period_seconds,
+        governance_config.execution_window_seconds,
+        governance_config.proposal_acceptance_threshold,
+        governance_config.proposal_minimum_stake,
+        governance_config.proposal_rejection_threshold,
+        governance_config.quorum_threshold,
+        &governance_config.stake_config_address,
+        None,
+        governance_config.voting_period_seconds,
+        period_bounds,
+    )
+    .await;
+    setup_proposal_with_stake_and_cooldown(
+        context,
+        proposal,
+        &Pubkey::new_unique(),
+        0,
+        governance_config,
+        0,
+        0,
+        0,
+        /* total_stake */ 0,
+        ProposalStatus::Accepted,
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+        /* cooldown_timestamp */
+        NonZeroU64::new(clock.unix_timestamp.saturating_sub(60) as u64), // Now - 60 seconds.
+    )
+    .await;
+
+    period_bounds
+}
+
+#[test_case(601, 600, 60, PaladinGovernanceError::InvalidCooldownPeriod; "cooldown_period_too_long")]
+#[test_case(60, 59, 60, PaladinGovernanceError::InvalidExecutionWindow; "execution_window_too_short")]
+#[test_case(60, 600, 86_400, PaladinGovernanceError::InvalidVotingPeriod; "voting_period_too_long")]
+#[tokio::test]
+async fn fail_outside_stored_period_bounds(
+    cooldown_period_seconds: u64,
+    execution_window_seconds: u64,
+    voting_period_seconds: u64,
+    error: PaladinGovernanceError,
+) {
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let stake_config_address = Pubkey::new_unique();
+    let governance =
+        get_governance_address(&stake_config_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_governance_with_short_period_bounds(
+        &mut context,
+        &governance,
+        &stake_config_address,
+        &proposal,
+    )
+    .await;
+
+    let instruction = update_governance(
+        &governance,
+        &proposal,
+        &proposal_transaction,
+        cooldown_period_seconds,
+        execution_window_seconds,
+        /* proposal_acceptance_threshold */ 500_000_000,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 500_000_000,
+        /* quorum_threshold */ 0,
+        /* veto_authority */ None,
+        voting_period_seconds,
+    );
+
+    let err = process_update_governance_from_proposal(&mut context, &proposal, &instruction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(error as u32))
+    );
+}
+
+#[tokio::test]
+async fn success_within_stored_period_bounds() {
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let stake_config_address = Pubkey::new_unique();
+    let governance =
+        get_governance_address(&stake_config_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    let period_bounds = setup_governance_with_short_period_bounds(
+        &mut context,
+        &governance,
+        &stake_config_address,
+        &proposal,
+    )
+    .await;
+
+    // Periods shorter than the default minimums are accepted within the
+    // stored bounds.
+    let instruction = update_governance(
+        &governance,
+        &proposal,
+        &proposal_transaction,
+        /* cooldown_period_seconds */ 120,
+        /* execution_window_seconds */ 300,
+        /* proposal_acceptance_threshold */ 500_000_000,
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 500_000_000,
+        /* quorum_threshold */ 0,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 600,
+    );
+
+    process_update_governance_from_proposal(&mut context, &proposal, &instruction)
+        .await
+        .unwrap();
+
+    // Assert the periods were updated and the bounds were kept.
+    let governance_account = context
+        .banks_client
+        .get_account(governance)
+        .await
+        .unwrap()
+        .unwrap();
+    let governance_state = bytemuck::from_bytes::<Config>(&governance_account.data);
+    assert_eq!(governance_state.cooldown_period_seconds, 120);
+    assert_eq!(governance_state.execution_window_seconds, 300);
+    assert_eq!(governance_state.voting_period_seconds, 600);
+    assert_eq!(governance_state.period_bounds, period_bounds);
 }
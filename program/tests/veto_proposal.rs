@@ -0,0 +1,427 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::veto_proposal,
+        state::{get_governance_address, Config, Proposal, ProposalStatus},
+    },
+    setup::{setup, setup_governance, setup_proposal, setup_proposal_with_stake_and_cooldown},
+    solana_program_test::*,
+    solana_sdk::{
+        clock::Clock,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    std::num::NonZeroU64,
+    test_case::test_case,
+};
+
+#[tokio::test]
+async fn fail_veto_authority_not_signer() {
+    let veto_authority = Keypair::new();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    let mut instruction = veto_proposal(&veto_authority.pubkey(), &governance, &proposal);
+    instruction.accounts[0].is_signer = false; // Veto authority not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Veto authority not signer.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_governance_incorrect_address() {
+    let veto_authority = Keypair::new();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let governance = Pubkey::new_unique(); // Incorrect governance address.
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(
+        &mut context,
+        &governance,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        &stake_config,
+        Some(&veto_authority.pubkey()),
+        0,
+    )
+    .await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        0,
+        Config::default(),
+        ProposalStatus::Accepted,
+    )
+    .await;
+
+    let instruction = veto_proposal(&veto_authority.pubkey(), &governance, &proposal);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &veto_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                PaladinGovernanceError::IncorrectGovernanceConfigAddress as u32
+            )
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_veto_authority_not_set() {
+    let veto_authority = Keypair::new();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(
+        &mut context,
+        &governance,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        &stake_config,
+        None, // No veto authority.
+        0,
+    )
+    .await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        0,
+        Config::default(),
+        ProposalStatus::Accepted,
+    )
+    .await;
+
+    let instruction = veto_proposal(&veto_authority.pubkey(), &governance, &proposal);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &veto_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::VetoAuthorityNotSet as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_incorrect_veto_authority() {
+    let veto_authority = Keypair::new();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(
+        &mut context,
+        &governance,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        &stake_config,
+        Some(&Pubkey::new_unique()), // Incorrect veto authority.
+        0,
+    )
+    .await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        0,
+        Config::default(),
+        ProposalStatus::Accepted,
+    )
+    .await;
+
+    let instruction = veto_proposal(&veto_authority.pubkey(), &governance, &proposal);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &veto_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_incorrect_stake_config() {
+    let veto_authority = Keypair::new();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(
+        &mut context,
+        &governance,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        &stake_config,
+        Some(&veto_authority.pubkey()),
+        0,
+    )
+    .await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        0,
+        Config::default(), // Proposal created under a different stake config.
+        ProposalStatus::Accepted,
+    )
+    .await;
+
+    let instruction = veto_proposal(&veto_authority.pubkey(), &governance, &proposal);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &veto_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::IncorrectStakeConfig as u32)
+        )
+    );
+}
+
+#[test_case(ProposalStatus::Draft)]
+#[test_case(ProposalStatus::Voting)]
+#[test_case(ProposalStatus::Cancelled)]
+#[test_case(ProposalStatus::Rejected)]
+#[test_case(ProposalStatus::Processed)]
+#[test_case(ProposalStatus::QuorumNotMet)]
+#[test_case(ProposalStatus::Expired)]
+#[test_case(ProposalStatus::Vetoed)]
+#[tokio::test]
+async fn fail_proposal_not_vetoable(status: ProposalStatus) {
+    let veto_authority = Keypair::new();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let mut governance_config = Config::default();
+    governance_config.stake_config_address = stake_config;
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(
+        &mut context,
+        &governance,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        &stake_config,
+        Some(&veto_authority.pubkey()),
+        0,
+    )
+    .await;
+    // A proposal still voting has no cooldown timestamp.
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        0,
+        governance_config,
+        status,
+    )
+    .await;
+
+    let instruction = veto_proposal(&veto_authority.pubkey(), &governance, &proposal);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &veto_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::ProposalNotVetoable as u32)
+        )
+    );
+}
+
+#[test_case(ProposalStatus::Voting; "cooldown")]
+#[test_case(ProposalStatus::Accepted; "accepted")]
+#[tokio::test]
+async fn success(status: ProposalStatus) {
+    let veto_authority = Keypair::new();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let mut governance_config = Config::default();
+    governance_config.stake_config_address = stake_config;
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_governance(
+        &mut context,
+        &governance,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        &stake_config,
+        Some(&veto_authority.pubkey()),
+        0,
+    )
+    .await;
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        0,
+        governance_config,
+        /* stake_for */ 100_000_000,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ 100_000_000,
+        status,
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+        /* cooldown_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+    )
+    .await;
+
+    let instruction = veto_proposal(&veto_authority.pubkey(), &governance, &proposal);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &veto_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal was vetoed.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::Vetoed);
+}
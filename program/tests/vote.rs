@@ -5,15 +5,17 @@ mod setup;
 use {
     paladin_governance_program::{
         error::PaladinGovernanceError,
+        instruction::vote_as_delegate,
         state::{
-            get_proposal_vote_address, Config, Proposal, ProposalStatus, ProposalVote,
-            ProposalVoteElection,
+            get_governance_address, get_proposal_vote_address, get_vote_delegation_address, Config,
+            Proposal, ProposalStatus, ProposalVote, ProposalVoteElection,
         },
     },
     paladin_stake_program::state::{find_stake_pda, Config as StakeConfig, Stake},
     setup::{
         setup, setup_proposal, setup_proposal_vote, setup_proposal_with_stake,
         setup_proposal_with_stake_and_cooldown, setup_stake, setup_stake_config,
+        setup_vote_delegation,
     },
     solana_program_test::*,
     solana_sdk::{
@@ -49,6 +51,7 @@ async fn fail_stake_authority_not_signer() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &context.payer.pubkey(),
         ProposalVoteElection::For,
     );
     instruction.accounts[0].is_signer = false; // Stake authority not signer.
@@ -73,6 +76,51 @@ async fn fail_stake_authority_not_signer() {
     );
 }
 
+#[tokio::test]
+async fn fail_payer_not_signer() {
+    let stake_authority = Keypair::new();
+    let payer = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    let mut instruction = paladin_governance_program::instruction::vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &payer.pubkey(),
+        ProposalVoteElection::For,
+    );
+    instruction.accounts[5].is_signer = false; // Payer not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority], // Payer not signer.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
 #[tokio::test]
 async fn fail_stake_incorrect_owner() {
     let stake_authority = Keypair::new();
@@ -103,6 +151,7 @@ async fn fail_stake_incorrect_owner() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &context.payer.pubkey(),
         ProposalVoteElection::For,
     );
 
@@ -156,6 +205,7 @@ async fn fail_stake_not_initialized() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &context.payer.pubkey(),
         ProposalVoteElection::For,
     );
 
@@ -208,6 +258,7 @@ async fn fail_stake_incorrect_stake_authority() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &context.payer.pubkey(),
         ProposalVoteElection::For,
     );
 
@@ -278,6 +329,7 @@ async fn fail_stake_config_incorrect_owner() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &context.payer.pubkey(),
         ProposalVoteElection::For,
     );
 
@@ -348,6 +400,7 @@ async fn fail_stake_config_not_initialized() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &context.payer.pubkey(),
         ProposalVoteElection::For,
     );
 
@@ -410,6 +463,7 @@ async fn fail_proposal_incorrect_owner() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &context.payer.pubkey(),
         ProposalVoteElection::For,
     );
 
@@ -472,6 +526,7 @@ async fn fail_proposal_not_initialized() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &context.payer.pubkey(),
         ProposalVoteElection::For,
     );
 
@@ -508,10 +563,14 @@ async fn fail_proposal_not_voting() {
 
     let governance_config = Config::new(
         /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
         /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
         /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
         /* signer_bump_seed */ 0,
         /* stake_config_address */ &stake_config,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 0,
     );
 
@@ -541,6 +600,7 @@ async fn fail_proposal_not_voting() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &context.payer.pubkey(),
         ProposalVoteElection::For,
     );
 
@@ -579,10 +639,14 @@ async fn fail_proposal_vote_incorrect_address() {
 
     let governance_config = Config::new(
         /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
         /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
         /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
         /* signer_bump_seed */ 0,
         /* stake_config_address */ &stake_config,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 0,
     );
 
@@ -612,6 +676,7 @@ async fn fail_proposal_vote_incorrect_address() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &context.payer.pubkey(),
         ProposalVoteElection::For,
     );
 
@@ -651,10 +716,14 @@ async fn fail_proposal_vote_already_initialized() {
 
     let governance_config = Config::new(
         /* cooldown_period_seconds */ 0,
+        /* execution_window_seconds */ 0,
         /* proposal_acceptance_threshold */ 0,
+        /* proposal_minimum_stake */ 0,
         /* proposal_rejection_threshold */ 0,
+        /* quorum_threshold */ 0,
         /* signer_bump_seed */ 0,
         /* stake_config_address */ &stake_config,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 0,
     );
 
@@ -684,6 +753,7 @@ async fn fail_proposal_vote_already_initialized() {
         &proposal_vote,
         &proposal,
         0,
+        &stake,
         &stake_authority.pubkey(),
         ProposalVoteElection::For,
     )
@@ -695,6 +765,7 @@ async fn fail_proposal_vote_already_initialized() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &context.payer.pubkey(),
         ProposalVoteElection::For,
     );
 
@@ -718,6 +789,195 @@ async fn fail_proposal_vote_already_initialized() {
     );
 }
 
+#[tokio::test]
+async fn fail_vote_delegation_incorrect_address() {
+    let delegate = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let vote_delegation = Pubkey::new_unique(); // Incorrect vote delegation address.
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &Pubkey::new_unique(),
+        &validator_vote,
+        0,
+    )
+    .await;
+
+    let instruction = vote_as_delegate(
+        &delegate.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &context.payer.pubkey(),
+        &vote_delegation,
+        ProposalVoteElection::For,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::IncorrectVoteDelegationAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_vote_delegation_incorrect_delegate() {
+    let delegate = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let vote_delegation =
+        get_vote_delegation_address(&stake, &governance, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &Pubkey::new_unique(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_vote_delegation(
+        &mut context,
+        &vote_delegation,
+        &Pubkey::new_unique(), // Incorrect delegate.
+        None,
+        &governance,
+        &stake,
+    )
+    .await;
+
+    let instruction = vote_as_delegate(
+        &delegate.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &context.payer.pubkey(),
+        &vote_delegation,
+        ProposalVoteElection::For,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+}
+
+#[tokio::test]
+async fn fail_vote_delegation_expired() {
+    let delegate = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let vote_delegation =
+        get_vote_delegation_address(&stake, &governance, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake(
+        &mut context,
+        &stake,
+        &Pubkey::new_unique(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_vote_delegation(
+        &mut context,
+        &vote_delegation,
+        &delegate.pubkey(),
+        NonZeroU64::new(clock.unix_timestamp as u64), // Expired.
+        &governance,
+        &stake,
+    )
+    .await;
+
+    let instruction = vote_as_delegate(
+        &delegate.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &context.payer.pubkey(),
+        &vote_delegation,
+        ProposalVoteElection::For,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::VoteDelegationExpired as u32)
+        )
+    );
+}
+
 const ACCEPTANCE_THRESHOLD: u32 = 500_000_000; // 50%
 const REJECTION_THRESHOLD: u32 = 500_000_000; // 50%
 const COOLDOWN_PERIOD_SECONDS: u64 = 100_000_000;
@@ -820,10 +1080,14 @@ async fn success(vote: Vote, expect: Expect) {
 
     let governance_config = Config::new(
         COOLDOWN_PERIOD_SECONDS,
+        /* execution_window_seconds */ 0,
         ACCEPTANCE_THRESHOLD,
+        /* proposal_minimum_stake */ 0,
         REJECTION_THRESHOLD,
+        /* quorum_threshold */ 0,
         /* signer_bump_seed */ 0,
         &stake_config,
+        /* veto_authority */ None,
         VOTING_PERIOD_SECONDS,
     );
 
@@ -848,20 +1112,13 @@ async fn success(vote: Vote, expect: Expect) {
         PROPOSAL_STARTING_STAKE_FOR,
         PROPOSAL_STARTING_STAKE_AGAINST,
         PROPOSAL_STARTING_STAKE_ABSTAINED,
+        /* total_stake */ TOTAL_STAKE,
         ProposalStatus::Voting,
         /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
     )
     .await;
 
-    // Fund the proposal vote account.
-    {
-        let rent = context.banks_client.get_rent().await.unwrap();
-        let lamports = rent.minimum_balance(std::mem::size_of::<ProposalVote>());
-        context.set_account(
-            &proposal_vote,
-            &AccountSharedData::new(lamports, 0, &system_program::id()),
-        );
-    }
+    let rent = context.banks_client.get_rent().await.unwrap();
 
     let instruction = paladin_governance_program::instruction::vote(
         &stake_authority.pubkey(),
@@ -869,6 +1126,7 @@ async fn success(vote: Vote, expect: Expect) {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &context.payer.pubkey(),
         election,
     );
 
@@ -892,9 +1150,25 @@ async fn success(vote: Vote, expect: Expect) {
         .await
         .unwrap()
         .unwrap();
+    let proposal_vote_state = bytemuck::from_bytes::<ProposalVote>(&proposal_vote_account.data);
+    assert!(proposal_vote_state.vote_timestamp.is_some());
+    assert_eq!(
+        proposal_vote_state,
+        &ProposalVote::new(
+            &proposal,
+            vote_stake,
+            &stake,
+            &stake_authority.pubkey(),
+            &context.payer.pubkey(),
+            proposal_vote_state.vote_timestamp,
+            election,
+        )
+    );
+
+    // Assert the payer funded exactly the proposal vote's rent-exemption.
     assert_eq!(
-        bytemuck::from_bytes::<ProposalVote>(&proposal_vote_account.data),
-        &ProposalVote::new(&proposal, vote_stake, &stake_authority.pubkey(), election)
+        proposal_vote_account.lamports,
+        rent.minimum_balance(std::mem::size_of::<ProposalVote>())
     );
 
     let proposal_account = context
@@ -933,7 +1207,7 @@ async fn success(vote: Vote, expect: Expect) {
 }
 
 #[tokio::test]
-async fn success_voting_closed() {
+async fn success_threshold_uses_total_stake_snapshot() {
     let stake_authority = Keypair::new();
     let validator_vote = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
@@ -943,21 +1217,27 @@ async fn success_voting_closed() {
     let proposal_vote =
         get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
 
-    let vote_stake = TOTAL_STAKE / 10;
-    let election = ProposalVoteElection::Against;
+    let vote_stake = TOTAL_STAKE / 2; // 50% of the snapshot.
+    let election = ProposalVoteElection::For;
 
     let governance_config = Config::new(
-        /* cooldown_period_seconds */ 10,
+        COOLDOWN_PERIOD_SECONDS,
+        /* execution_window_seconds */ 0,
         ACCEPTANCE_THRESHOLD,
+        /* proposal_minimum_stake */ 0,
         REJECTION_THRESHOLD,
+        /* quorum_threshold */ 0,
         /* signer_bump_seed */ 0,
         &stake_config,
-        /* voting_period_seconds */ 10,
+        /* veto_authority */ None,
+        VOTING_PERIOD_SECONDS,
     );
 
     let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
 
-    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    // Delegated stake has grown tenfold since voting began.
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE * 10).await;
     setup_stake(
         &mut context,
         &stake,
@@ -966,20 +1246,18 @@ async fn success_voting_closed() {
         vote_stake,
     )
     .await;
-
-    // Set up a proposal with stake against > threshold and a voting period
-    // that began very long ago (expired).
     setup_proposal_with_stake(
         &mut context,
         &proposal,
         &stake_authority.pubkey(),
-        /* creation_timestamp */ 0,
+        0,
         governance_config,
-        /* stake_for */ 0,
-        /* stake_against */ TOTAL_STAKE,
-        /* stake_abstained */ 0,
+        PROPOSAL_STARTING_STAKE_FOR,
+        PROPOSAL_STARTING_STAKE_AGAINST,
+        PROPOSAL_STARTING_STAKE_ABSTAINED,
+        /* total_stake */ TOTAL_STAKE,
         ProposalStatus::Voting,
-        /* voting_start_timestamp */ NonZeroU64::new(1), // Wayyy earlier.
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
     )
     .await;
 
@@ -999,6 +1277,7 @@ async fn success_voting_closed() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &context.payer.pubkey(),
         election,
     );
 
@@ -1021,28 +1300,16 @@ async fn success_voting_closed() {
         .await
         .unwrap()
         .unwrap();
-
-    // Assert the proposal was marked as rejected.
     let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
-    assert_eq!(proposal_state.status, ProposalStatus::Rejected);
-
-    let proposal_vote_account = context
-        .banks_client
-        .get_account(proposal_vote)
-        .await
-        .unwrap()
-        .unwrap();
 
-    // Assert the proposal vote was _not_ initialized.
-    assert_eq!(proposal_vote_account.data.len(), 0);
-    assert_eq!(proposal_vote_account.owner, system_program::id());
+    // Assert the threshold was evaluated against the snapshot, not the live
+    // delegated stake, and the cooldown period began.
+    assert_eq!(proposal_state.stake_for, vote_stake);
+    assert!(proposal_state.cooldown_timestamp.is_some());
 }
 
 #[tokio::test]
-async fn success_voting_closed_but_cooldown_active() {
-    // Here we're testing the case where a proposal's voting period has
-    // expired, but since there's an active cooldown period, it doesn'
-    // actually disable voting, and instead lets the cooldown period expire.
+async fn success_voting_closed() {
     let stake_authority = Keypair::new();
     let validator_vote = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
@@ -1056,16 +1323,19 @@ async fn success_voting_closed_but_cooldown_active() {
     let election = ProposalVoteElection::Against;
 
     let governance_config = Config::new(
-        /* cooldown_period_seconds */ 1_000,
+        /* cooldown_period_seconds */ 10,
+        /* execution_window_seconds */ 0,
         ACCEPTANCE_THRESHOLD,
+        /* proposal_minimum_stake */ 0,
         REJECTION_THRESHOLD,
+        /* quorum_threshold */ 0,
         /* signer_bump_seed */ 0,
         &stake_config,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 10,
     );
 
     let mut context = setup().start_with_context().await;
-    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
 
     setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
     setup_stake(
@@ -1077,19 +1347,136 @@ async fn success_voting_closed_but_cooldown_active() {
     )
     .await;
 
-    // Set up a proposal with stake for > threshold and an active cooldown
-    // period.
-    // The cooldown period is scheduled to expire _after_ the voting period
-    // expires.
-    setup_proposal_with_stake_and_cooldown(
+    // Set up a proposal with stake against > threshold and a voting period
+    // that began very long ago (expired).
+    setup_proposal_with_stake(
         &mut context,
         &proposal,
         &stake_authority.pubkey(),
         /* creation_timestamp */ 0,
         governance_config,
-        /* stake_for */ TOTAL_STAKE,
-        /* stake_against */ 0,
-        /* stake_abstained */ 0,
+        /* stake_for */ 0,
+        /* stake_against */ TOTAL_STAKE,
+        /* stake_abstained */ 0,
+        /* total_stake */ TOTAL_STAKE,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(1), // Wayyy earlier.
+    )
+    .await;
+
+    // Fund the proposal vote account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(std::mem::size_of::<ProposalVote>());
+        context.set_account(
+            &proposal_vote,
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
+        );
+    }
+
+    let instruction = paladin_governance_program::instruction::vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &context.payer.pubkey(),
+        election,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Assert the proposal was marked as rejected.
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::Rejected);
+
+    let proposal_vote_account = context
+        .banks_client
+        .get_account(proposal_vote)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Assert the proposal vote was _not_ initialized.
+    assert_eq!(proposal_vote_account.data.len(), 0);
+    assert_eq!(proposal_vote_account.owner, system_program::id());
+}
+
+#[tokio::test]
+async fn success_voting_closed_but_cooldown_active() {
+    // Here we're testing the case where a proposal's voting period has
+    // expired, but since there's an active cooldown period, it doesn'
+    // actually disable voting, and instead lets the cooldown period expire.
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let vote_stake = TOTAL_STAKE / 10;
+    let election = ProposalVoteElection::Against;
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 1_000,
+        /* execution_window_seconds */ 0,
+        ACCEPTANCE_THRESHOLD,
+        /* proposal_minimum_stake */ 0,
+        REJECTION_THRESHOLD,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        &stake_config,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 10,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        vote_stake,
+    )
+    .await;
+
+    // Set up a proposal with stake for > threshold and an active cooldown
+    // period.
+    // The cooldown period is scheduled to expire _after_ the voting period
+    // expires.
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        /* stake_for */ TOTAL_STAKE,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ TOTAL_STAKE,
         ProposalStatus::Voting,
         /* voting_start_timestamp */ NonZeroU64::new(1), // Wayyy earlier.
         /* cooldown_timestamp */
@@ -1113,6 +1500,7 @@ async fn success_voting_closed_but_cooldown_active() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &context.payer.pubkey(),
         election,
     );
 
@@ -1147,9 +1535,19 @@ async fn success_voting_closed_but_cooldown_active() {
         .await
         .unwrap()
         .unwrap();
+    let proposal_vote_state = bytemuck::from_bytes::<ProposalVote>(&proposal_vote_account.data);
+    assert!(proposal_vote_state.vote_timestamp.is_some());
     assert_eq!(
-        bytemuck::from_bytes::<ProposalVote>(&proposal_vote_account.data),
-        &ProposalVote::new(&proposal, vote_stake, &stake_authority.pubkey(), election)
+        proposal_vote_state,
+        &ProposalVote::new(
+            &proposal,
+            vote_stake,
+            &stake,
+            &stake_authority.pubkey(),
+            &context.payer.pubkey(),
+            proposal_vote_state.vote_timestamp,
+            election,
+        )
     );
 }
 
@@ -1169,10 +1567,14 @@ async fn success_cooldown_has_ended() {
 
     let governance_config = Config::new(
         /* cooldown_period_seconds */ 10,
+        /* execution_window_seconds */ 1_000,
         ACCEPTANCE_THRESHOLD,
+        /* proposal_minimum_stake */ 0,
         REJECTION_THRESHOLD,
+        /* quorum_threshold */ 0,
         /* signer_bump_seed */ 0,
         &stake_config,
+        /* veto_authority */ None,
         /* voting_period_seconds */ 1_000,
     );
 
@@ -1200,6 +1602,7 @@ async fn success_cooldown_has_ended() {
         /* stake_for */ TOTAL_STAKE,
         /* stake_against */ 0,
         /* stake_abstained */ 0,
+        /* total_stake */ TOTAL_STAKE,
         ProposalStatus::Voting,
         /* voting_start_timestamp */
         NonZeroU64::new(clock.unix_timestamp as u64),
@@ -1224,6 +1627,7 @@ async fn success_cooldown_has_ended() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &context.payer.pubkey(),
         election,
     );
 
@@ -1262,3 +1666,331 @@ async fn success_cooldown_has_ended() {
     assert_eq!(proposal_vote_account.data.len(), 0);
     assert_eq!(proposal_vote_account.owner, system_program::id());
 }
+
+#[tokio::test]
+async fn success_as_delegate() {
+    let stake_authority = Pubkey::new_unique();
+    let delegate = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let vote_delegation =
+        get_vote_delegation_address(&stake, &governance, &paladin_governance_program::id());
+
+    let vote_stake = TOTAL_STAKE / 10; // 10% of total stake.
+    let election = ProposalVoteElection::For;
+
+    let governance_config = Config::new(
+        COOLDOWN_PERIOD_SECONDS,
+        /* execution_window_seconds */ 0,
+        ACCEPTANCE_THRESHOLD,
+        /* proposal_minimum_stake */ 0,
+        REJECTION_THRESHOLD,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        &stake_config,
+        /* veto_authority */ None,
+        VOTING_PERIOD_SECONDS,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority,
+        &validator_vote,
+        vote_stake,
+    )
+    .await;
+    setup_vote_delegation(
+        &mut context,
+        &vote_delegation,
+        &delegate.pubkey(),
+        NonZeroU64::new((clock.unix_timestamp as u64).saturating_add(86_400)),
+        &governance,
+        &stake,
+    )
+    .await;
+    setup_proposal_with_stake(
+        &mut context,
+        &proposal,
+        &stake_authority,
+        0,
+        governance_config,
+        PROPOSAL_STARTING_STAKE_FOR,
+        PROPOSAL_STARTING_STAKE_AGAINST,
+        PROPOSAL_STARTING_STAKE_ABSTAINED,
+        /* total_stake */ TOTAL_STAKE,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+    )
+    .await;
+
+    // Fund the proposal vote account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(std::mem::size_of::<ProposalVote>());
+        context.set_account(
+            &proposal_vote,
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
+        );
+    }
+
+    let instruction = vote_as_delegate(
+        &delegate.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &context.payer.pubkey(),
+        &vote_delegation,
+        election,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal vote was created with the delegated stake.
+    let proposal_vote_account = context
+        .banks_client
+        .get_account(proposal_vote)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_vote_state = bytemuck::from_bytes::<ProposalVote>(&proposal_vote_account.data);
+    assert!(proposal_vote_state.vote_timestamp.is_some());
+    assert_eq!(
+        proposal_vote_state,
+        &ProposalVote::new(
+            &proposal,
+            vote_stake,
+            &stake,
+            &delegate.pubkey(),
+            &context.payer.pubkey(),
+            proposal_vote_state.vote_timestamp,
+            election,
+        )
+    );
+
+    // Assert the proposal stake was updated.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.stake_for, vote_stake);
+}
+
+#[test_case(false; "voting_period_ended")]
+#[test_case(true; "cooldown_period_ended")]
+#[tokio::test]
+async fn success_concluded_quorum_not_met(cooldown_ended: bool) {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    // The quorum threshold exceeds the acceptance threshold, so a proposal
+    // can begin a cooldown period without enough participation.
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 10,
+        /* execution_window_seconds */ 1_000,
+        /* proposal_acceptance_threshold */ 200_000_000, // 20%
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 500_000_000, // 50%
+        /* quorum_threshold */ 600_000_000, // 60%
+        /* signer_bump_seed */ 0,
+        &stake_config,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 10,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        TOTAL_STAKE / 10,
+    )
+    .await;
+
+    // Either the cooldown period has ended with 30% of the stake in favor, or
+    // the voting period has ended with 10% of the stake in favor. Neither
+    // meets the 60% quorum.
+    let (stake_for, voting_start_timestamp, cooldown_timestamp) = if cooldown_ended {
+        (
+            TOTAL_STAKE / 10 * 3,
+            NonZeroU64::new(clock.unix_timestamp as u64),
+            NonZeroU64::new(clock.unix_timestamp.saturating_sub(10) as u64),
+        )
+    } else {
+        (TOTAL_STAKE / 10, NonZeroU64::new(1), None)
+    };
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        stake_for,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ TOTAL_STAKE,
+        ProposalStatus::Voting,
+        voting_start_timestamp,
+        cooldown_timestamp,
+    )
+    .await;
+
+    let instruction = paladin_governance_program::instruction::vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &context.payer.pubkey(),
+        ProposalVoteElection::For,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal concluded without meeting quorum.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::QuorumNotMet);
+}
+
+#[tokio::test]
+async fn success_execution_window_ended() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 10,
+        /* execution_window_seconds */ 10,
+        ACCEPTANCE_THRESHOLD,
+        /* proposal_minimum_stake */ 0,
+        REJECTION_THRESHOLD,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        &stake_config,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 1_000,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        TOTAL_STAKE / 10,
+    )
+    .await;
+
+    // Set up a proposal whose cooldown period and execution window have both
+    // ended.
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        /* stake_for */ TOTAL_STAKE,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ TOTAL_STAKE,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */
+        NonZeroU64::new(clock.unix_timestamp as u64),
+        /* cooldown_timestamp */
+        NonZeroU64::new(clock.unix_timestamp.saturating_sub(20) as u64), // Now - 20 seconds.
+    )
+    .await;
+
+    let instruction = paladin_governance_program::instruction::vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &context.payer.pubkey(),
+        ProposalVoteElection::For,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal expired rather than being accepted.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::Expired);
+}
@@ -0,0 +1,907 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::{withdraw_vote, withdraw_vote_as_delegate},
+        state::{
+            get_governance_address, get_proposal_vote_address, get_vote_delegation_address, Config,
+            Proposal, ProposalStatus, ProposalVoteElection,
+        },
+    },
+    paladin_stake_program::state::find_stake_pda,
+    setup::{
+        setup, setup_legacy_proposal_vote, setup_proposal, setup_proposal_vote,
+        setup_proposal_vote_with_payer, setup_proposal_with_stake,
+        setup_proposal_with_stake_and_cooldown, setup_stake, setup_stake_config,
+        setup_vote_delegation,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        clock::Clock,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    std::num::NonZeroU64,
+    test_case::test_case,
+};
+
+const ACCEPTANCE_THRESHOLD: u32 = 500_000_000; // 50%
+const REJECTION_THRESHOLD: u32 = 500_000_000; // 50%
+const COOLDOWN_PERIOD_SECONDS: u64 = 100_000_000;
+const VOTING_PERIOD_SECONDS: u64 = 100_000_000;
+const TOTAL_STAKE: u64 = 100_000_000;
+
+fn governance_config(stake_config: &Pubkey) -> Config {
+    Config::new(
+        COOLDOWN_PERIOD_SECONDS,
+        /* execution_window_seconds */ 0,
+        ACCEPTANCE_THRESHOLD,
+        /* proposal_minimum_stake */ 0,
+        REJECTION_THRESHOLD,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        stake_config,
+        /* veto_authority */ None,
+        VOTING_PERIOD_SECONDS,
+    )
+}
+
+#[tokio::test]
+async fn fail_stake_authority_not_signer() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    let mut instruction = withdraw_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &stake_authority.pubkey(),
+    );
+    instruction.accounts[0].is_signer = false; // Stake authority not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Stake authority not signer.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_stake_incorrect_stake_authority() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    // Set up a stake account with the wrong stake authority address.
+    setup_stake(
+        &mut context,
+        &stake,
+        &Pubkey::new_unique(), // Incorrect stake authority.
+        &validator_vote,
+        0,
+    )
+    .await;
+
+    let instruction = withdraw_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &stake_authority.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_not_voting() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        governance_config(&stake_config),
+        ProposalStatus::Accepted, // Not voting.
+    )
+    .await;
+
+    let instruction = withdraw_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &stake_authority.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::ProposalNotInVotingStage as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_vote_incorrect_address() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let proposal_vote = Pubkey::new_unique(); // Incorrect proposal vote address.
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_proposal_with_stake(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        governance_config(&stake_config),
+        /* stake_for */ 0,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ TOTAL_STAKE,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+    )
+    .await;
+
+    let instruction = withdraw_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &stake_authority.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::IncorrectProposalVoteAddress as u32)
+        )
+    );
+}
+
+#[test_case(0; "v0")]
+#[test_case(1; "v1")]
+#[test_case(2; "v2")]
+#[tokio::test]
+async fn fail_proposal_vote_not_migrated(version: u8) {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        0,
+    )
+    .await;
+    setup_proposal_with_stake(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        governance_config(&stake_config),
+        /* stake_for */ 0,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ TOTAL_STAKE,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+    )
+    .await;
+
+    // Set up a proposal vote account with a previous layout.
+    setup_legacy_proposal_vote(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        0,
+        &stake,
+        &stake_authority.pubkey(),
+        ProposalVoteElection::For,
+        version,
+    )
+    .await;
+
+    let instruction = withdraw_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &stake_authority.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::AccountVersionMismatch as u32)
+        )
+    );
+}
+
+#[test_case(ProposalVoteElection::For; "for")]
+#[test_case(ProposalVoteElection::Against; "against")]
+#[test_case(ProposalVoteElection::DidNotVote; "did_not_vote")]
+#[tokio::test]
+async fn success(election: ProposalVoteElection) {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let vote_stake = TOTAL_STAKE / 10; // 10% of total stake.
+    let other_stake = TOTAL_STAKE / 5; // 20% of total stake, from other votes.
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        vote_stake,
+    )
+    .await;
+
+    // Set up the proposal with the vote counted alongside other votes.
+    let (stake_for, stake_against, stake_abstained) = match election {
+        ProposalVoteElection::For => (vote_stake + other_stake, other_stake, other_stake),
+        ProposalVoteElection::Against => (other_stake, vote_stake + other_stake, other_stake),
+        ProposalVoteElection::DidNotVote => (other_stake, other_stake, vote_stake + other_stake),
+    };
+    setup_proposal_with_stake(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        governance_config(&stake_config),
+        stake_for,
+        stake_against,
+        stake_abstained,
+        /* total_stake */ TOTAL_STAKE,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+    )
+    .await;
+    setup_proposal_vote(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        vote_stake,
+        &stake,
+        &stake_authority.pubkey(),
+        election,
+    )
+    .await;
+
+    let proposal_vote_lamports = context
+        .banks_client
+        .get_account(proposal_vote)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction = withdraw_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &stake_authority.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the vote's stake was removed from the proposal.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::Voting);
+    assert_eq!(proposal_state.stake_for, other_stake);
+    assert_eq!(proposal_state.stake_against, other_stake);
+    assert_eq!(proposal_state.stake_abstained, other_stake);
+
+    // Assert the proposal vote account was closed.
+    assert!(context
+        .banks_client
+        .get_account(proposal_vote)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Assert the stake authority received the proposal vote's lamports.
+    assert_eq!(
+        context
+            .banks_client
+            .get_account(stake_authority.pubkey())
+            .await
+            .unwrap()
+            .unwrap()
+            .lamports,
+        proposal_vote_lamports
+    );
+}
+
+#[test_case(TOTAL_STAKE / 5 * 3, true; "above_threshold_keeps_cooldown")]
+#[test_case(TOTAL_STAKE / 2, false; "below_threshold_clears_cooldown")]
+#[tokio::test]
+async fn success_cooldown_active(starting_stake_for: u64, expect_cooldown: bool) {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let vote_stake = TOTAL_STAKE / 10; // 10% of total stake.
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        vote_stake,
+    )
+    .await;
+
+    // Set up the proposal with the vote in favor counted and an active
+    // cooldown period.
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        governance_config(&stake_config),
+        starting_stake_for,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ TOTAL_STAKE,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+        /* cooldown_timestamp */
+        NonZeroU64::new(clock.unix_timestamp.saturating_sub(100) as u64),
+    )
+    .await;
+    setup_proposal_vote(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        vote_stake,
+        &stake,
+        &stake_authority.pubkey(),
+        ProposalVoteElection::For,
+    )
+    .await;
+
+    let instruction = withdraw_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &stake_authority.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the cooldown period reflects the proposal's current support.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::Voting);
+    assert_eq!(proposal_state.stake_for, starting_stake_for - vote_stake);
+    assert_eq!(proposal_state.cooldown_timestamp.is_some(), expect_cooldown);
+}
+
+#[tokio::test]
+async fn success_as_delegate() {
+    let stake_authority = Pubkey::new_unique();
+    let delegate = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+    let governance = get_governance_address(&stake_config, &paladin_governance_program::id());
+    let vote_delegation =
+        get_vote_delegation_address(&stake, &governance, &paladin_governance_program::id());
+
+    let vote_stake = TOTAL_STAKE / 10; // 10% of total stake.
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority,
+        &validator_vote,
+        vote_stake,
+    )
+    .await;
+    setup_vote_delegation(
+        &mut context,
+        &vote_delegation,
+        &delegate.pubkey(),
+        None,
+        &governance,
+        &stake,
+    )
+    .await;
+    setup_proposal_with_stake(
+        &mut context,
+        &proposal,
+        &stake_authority,
+        0,
+        governance_config(&stake_config),
+        /* stake_for */ vote_stake,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ TOTAL_STAKE,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+    )
+    .await;
+    setup_proposal_vote_with_payer(
+        &mut context,
+        &proposal_vote,
+        &proposal,
+        vote_stake,
+        &stake,
+        &delegate.pubkey(),
+        /* payer */ &stake_authority,
+        ProposalVoteElection::For,
+    )
+    .await;
+
+    let proposal_vote_lamports = context
+        .banks_client
+        .get_account(proposal_vote)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction = withdraw_vote_as_delegate(
+        &delegate.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &stake_authority,
+        &vote_delegation,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the vote's stake was removed from the proposal.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.stake_for, 0);
+
+    // Assert the proposal vote account was closed.
+    assert!(context
+        .banks_client
+        .get_account(proposal_vote)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Assert the payer that funded the vote, not the delegate, was refunded.
+    assert_eq!(
+        context
+            .banks_client
+            .get_account(stake_authority)
+            .await
+            .unwrap()
+            .unwrap()
+            .lamports,
+        proposal_vote_lamports
+    );
+    assert!(context
+        .banks_client
+        .get_account(delegate.pubkey())
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[test_case(false; "voting_period_ended")]
+#[test_case(true; "cooldown_period_ended")]
+#[tokio::test]
+async fn success_concluded_quorum_not_met(cooldown_ended: bool) {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    // The quorum threshold exceeds the acceptance threshold, so a proposal
+    // can begin a cooldown period without enough participation.
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 10,
+        /* execution_window_seconds */ 1_000,
+        /* proposal_acceptance_threshold */ 200_000_000, // 20%
+        /* proposal_minimum_stake */ 0,
+        /* proposal_rejection_threshold */ 500_000_000, // 50%
+        /* quorum_threshold */ 600_000_000, // 60%
+        /* signer_bump_seed */ 0,
+        &stake_config,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 10,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        TOTAL_STAKE / 10,
+    )
+    .await;
+
+    // Either the cooldown period has ended with 30% of the stake in favor, or
+    // the voting period has ended with 10% of the stake in favor. Neither
+    // meets the 60% quorum.
+    let (stake_for, voting_start_timestamp, cooldown_timestamp) = if cooldown_ended {
+        (
+            TOTAL_STAKE / 10 * 3,
+            NonZeroU64::new(clock.unix_timestamp as u64),
+            NonZeroU64::new(clock.unix_timestamp.saturating_sub(10) as u64),
+        )
+    } else {
+        (TOTAL_STAKE / 10, NonZeroU64::new(1), None)
+    };
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        stake_for,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ TOTAL_STAKE,
+        ProposalStatus::Voting,
+        voting_start_timestamp,
+        cooldown_timestamp,
+    )
+    .await;
+
+    let instruction = withdraw_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &stake_authority.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal concluded without meeting quorum.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::QuorumNotMet);
+}
+
+#[tokio::test]
+async fn success_execution_window_ended() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let stake = find_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let governance_config = Config::new(
+        /* cooldown_period_seconds */ 10,
+        /* execution_window_seconds */ 10,
+        ACCEPTANCE_THRESHOLD,
+        /* proposal_minimum_stake */ 0,
+        REJECTION_THRESHOLD,
+        /* quorum_threshold */ 0,
+        /* signer_bump_seed */ 0,
+        &stake_config,
+        /* veto_authority */ None,
+        /* voting_period_seconds */ 1_000,
+    );
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        &stake_authority.pubkey(),
+        &validator_vote,
+        TOTAL_STAKE / 10,
+    )
+    .await;
+
+    // Set up a proposal whose cooldown period and execution window have both
+    // ended.
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        /* stake_for */ TOTAL_STAKE,
+        /* stake_against */ 0,
+        /* stake_abstained */ 0,
+        /* total_stake */ TOTAL_STAKE,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */
+        NonZeroU64::new(clock.unix_timestamp as u64),
+        /* cooldown_timestamp */
+        NonZeroU64::new(clock.unix_timestamp.saturating_sub(20) as u64), // Now - 20 seconds.
+    )
+    .await;
+
+    let instruction = withdraw_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &stake_authority.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal expired rather than being accepted.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::Expired);
+}
@@ -0,0 +1,419 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    borsh::BorshDeserialize,
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::write_instruction_slot,
+        state::{
+            get_proposal_transaction_address, Config, ProposalInstructionSlot, ProposalStatus,
+            ProposalTransaction,
+        },
+    },
+    setup::{setup, setup_proposal, setup_proposal_transaction},
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    test_case::test_case,
+};
+
+fn proposal_transaction_with_slot(instruction_len: u32) -> ProposalTransaction {
+    let mut proposal_transaction = ProposalTransaction::default();
+    proposal_transaction.instruction_slot = Some(ProposalInstructionSlot::new(instruction_len));
+    proposal_transaction
+}
+
+#[tokio::test]
+async fn fail_stake_authority_not_signer() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    let mut instruction = write_instruction_slot(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+        &context.payer.pubkey(),
+        0,
+        vec![1; 8],
+    );
+    instruction.accounts[0].is_signer = false; // Stake authority not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Missing stake authority.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_payer_not_signer() {
+    let stake_authority = Keypair::new();
+    let payer = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    let mut instruction = write_instruction_slot(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+        &payer.pubkey(),
+        0,
+        vec![1; 8],
+    );
+    instruction.accounts[3].is_signer = false; // Payer not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority], // Payer not signer.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_stake_authority_not_author() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &Pubkey::new_unique(), // Stake authority not author.
+        0,
+        Config::default(),
+        ProposalStatus::Draft,
+    )
+    .await;
+
+    let instruction = write_instruction_slot(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+        &context.payer.pubkey(),
+        0,
+        vec![1; 8],
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_not_in_draft_stage() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &stake_authority.pubkey(),
+        0,
+        Config::default(),
+        ProposalStatus::Voting, // Not in draft stage.
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        proposal_transaction_with_slot(8),
+    )
+    .await;
+
+    let instruction = write_instruction_slot(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+        &context.payer.pubkey(),
+        0,
+        vec![1; 8],
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::ProposalIsImmutable as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_instruction_slot_not_created() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &stake_authority.pubkey(),
+        0,
+        Config::default(),
+        ProposalStatus::Draft,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        ProposalTransaction::default(), // No instruction slot.
+    )
+    .await;
+
+    let instruction = write_instruction_slot(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+        &context.payer.pubkey(),
+        0,
+        vec![1; 8],
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::InstructionSlotNotCreated as u32)
+        )
+    );
+}
+
+#[test_case(0, 65; "chunk exceeds length")]
+#[test_case(60, 5; "chunk ends past length")]
+#[test_case(64, 1; "offset at length")]
+#[test_case(u32::MAX, 1; "offset overflows")]
+#[tokio::test]
+async fn fail_write_out_of_bounds(offset: u32, chunk_len: usize) {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &stake_authority.pubkey(),
+        0,
+        Config::default(),
+        ProposalStatus::Draft,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        proposal_transaction_with_slot(64),
+    )
+    .await;
+
+    let instruction = write_instruction_slot(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+        &context.payer.pubkey(),
+        offset,
+        vec![1; chunk_len],
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                PaladinGovernanceError::InstructionSlotWriteOutOfBounds as u32
+            )
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &stake_authority.pubkey(),
+        0,
+        Config::default(),
+        ProposalStatus::Draft,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        proposal_transaction_with_slot(1_024),
+    )
+    .await;
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    // Write the second chunk first, then the first.
+    for (offset, data) in [(512, vec![2; 512]), (0, vec![1; 512])] {
+        let instruction = write_instruction_slot(
+            &stake_authority.pubkey(),
+            &proposal_address,
+            &proposal_transaction_address,
+            &context.payer.pubkey(),
+            offset,
+            data,
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &stake_authority],
+            context.last_blockhash,
+        );
+
+        context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+            .unwrap();
+
+        // Assert the payer topped up the account to exactly its new
+        // rent-exemption.
+        let proposal_transaction_account = context
+            .banks_client
+            .get_account(proposal_transaction_address)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            proposal_transaction_account.lamports,
+            rent.minimum_balance(proposal_transaction_account.data.len())
+        );
+    }
+
+    // Assert the slot holds both chunks.
+    let proposal_transaction_account = context
+        .banks_client
+        .get_account(proposal_transaction_address)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_transaction_state =
+        ProposalTransaction::try_from_slice(&proposal_transaction_account.data).unwrap();
+
+    let instruction_slot = proposal_transaction_state.instruction_slot.unwrap();
+    assert_eq!(instruction_slot.instruction_len, 1_024);
+    assert_eq!(instruction_slot.data[..512], [1; 512]);
+    assert_eq!(instruction_slot.data[512..], [2; 512]);
+    assert!(proposal_transaction_state.instructions.is_empty());
+}